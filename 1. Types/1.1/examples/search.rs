@@ -0,0 +1,36 @@
+// Print every match of a pattern in a file, with its line and column:
+//
+//     cargo run -p item-1-1 --example search -- FILE PATTERN [--ignore-case] [--overlapping]
+
+use item_1_1::search::{find_all_with, SearchOptions};
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: search FILE PATTERN [--ignore-case] [--overlapping]";
+
+fn main() -> ExitCode {
+    let mut options = SearchOptions::default();
+    let mut positional = Vec::new();
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--ignore-case" => options.case_insensitive = true,
+            "--overlapping" => options.overlapping = true,
+            _ => positional.push(arg),
+        }
+    }
+    let [path, pattern] = &positional[..] else {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    };
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("can't read {path}: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    for m in find_all_with(&text, pattern, &options) {
+        let (line, col) = m.line_col(&text);
+        println!("{path}:{line}:{col}: {}", m.1);
+    }
+    ExitCode::SUCCESS
+}
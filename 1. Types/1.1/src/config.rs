@@ -0,0 +1,253 @@
+//! Display and printer settings from a TOML file, parsed rather than validated: the file is
+//! read into a `RawConfig`, which holds whatever it said, and `Config::try_from` turns that into
+//! the types the rest of the code uses, or says everything that is wrong with it. Nothing past
+//! that point has to check the settings again, because a `Config` can't hold bad ones.
+//!
+//! A config file looks like this; only `color` may be left out, for a monochrome display.
+//!
+//! ```toml
+//! [display]
+//! x = 640
+//! y = 480
+//! color = "#ff8800"
+//!
+//! [printer]
+//! sides = "both"
+//! output = "black_and_white"
+//! ```
+//!
+//! A key the file shouldn't have is an error too, so that a misspelt `colour` isn't quietly
+//! ignored in favour of the default.
+
+use crate::display::{Color, DisplayProps, RgbColor, DEFAULT_MAX_RESOLUTION};
+use crate::printing::{Output, Sides};
+use serde::Deserialize;
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+/// A config file as written, before anything in it is checked.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RawConfig {
+    #[serde(default)]
+    pub display: RawDisplay,
+    #[serde(default)]
+    pub printer: RawPrinter,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RawDisplay {
+    // As TOML has them: signed, and as large as they like.
+    pub x: Option<i64>,
+    pub y: Option<i64>,
+    /// `#rrggbb`, or `monochrome`.
+    pub color: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RawPrinter {
+    /// `both` or `single`.
+    pub sides: Option<String>,
+    /// `color` or `black_and_white`.
+    pub output: Option<String>,
+}
+
+/// Settings that have been checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub display: DisplayProps,
+    pub sides: Sides,
+    pub output: Output,
+}
+
+/// One thing wrong with a config file. Keys are given in full, as `display.x`.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The file couldn't be read.
+    Io(io::Error),
+    /// The file isn't TOML, or has a key it shouldn't, or a value of the wrong type.
+    Toml(toml::de::Error),
+    MissingKey(&'static str),
+    /// A color that isn't `#` and six hex digits.
+    BadColor {
+        key: &'static str,
+        value: String,
+    },
+    /// A coordinate outside `0..max`.
+    OutOfRange {
+        key: &'static str,
+        value: i64,
+        max: u32,
+    },
+    /// A string that isn't one of the `expected` ones.
+    UnknownValue {
+        key: &'static str,
+        value: String,
+        expected: &'static [&'static str],
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "failed to read config file: {e}"),
+            ConfigError::Toml(e) => write!(f, "invalid config file: {}", e.message()),
+            ConfigError::MissingKey(key) => write!(f, "{key} is required"),
+            ConfigError::BadColor { key, value } => {
+                write!(f, "{key} {value:?} is not a color like \"#ff8800\"")
+            }
+            ConfigError::OutOfRange { key, value, max } => {
+                write!(
+                    f,
+                    "{key} {value} is off the screen (must be 0 to {})",
+                    max - 1
+                )
+            }
+            ConfigError::UnknownValue {
+                key,
+                value,
+                expected,
+            } => write!(f, "{key} {value:?} is not one of {}", expected.join(", ")),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::Io(e) => Some(e),
+            ConfigError::Toml(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Everything wrong with a config file, in the order the keys are listed above. Never empty.
+#[derive(Debug)]
+pub struct ConfigErrors(pub Vec<ConfigError>);
+
+impl fmt::Display for ConfigErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, e) in self.0.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{e}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigErrors {}
+
+impl From<ConfigError> for ConfigErrors {
+    fn from(e: ConfigError) -> Self {
+        ConfigErrors(vec![e])
+    }
+}
+
+impl RawConfig {
+    pub fn parse(text: &str) -> Result<RawConfig, ConfigError> {
+        toml::from_str(text).map_err(ConfigError::Toml)
+    }
+}
+
+impl Config {
+    /// The settings in the TOML file at `path`.
+    pub fn load(path: &Path) -> Result<Config, ConfigErrors> {
+        let text = std::fs::read_to_string(path).map_err(ConfigError::Io)?;
+        Config::parse(&text)
+    }
+
+    pub fn parse(text: &str) -> Result<Config, ConfigErrors> {
+        Config::try_from(RawConfig::parse(text)?)
+    }
+}
+
+impl TryFrom<RawConfig> for Config {
+    type Error = ConfigErrors;
+
+    /// Checks every key, rather than stopping at the first bad one, so that one attempt is
+    /// enough to find out all that needs fixing.
+    fn try_from(raw: RawConfig) -> Result<Self, Self::Error> {
+        let mut errors = Vec::new();
+        let mut check = |e| errors.push(e);
+        let (max_x, max_y) = DEFAULT_MAX_RESOLUTION;
+        let x = coordinate("display.x", raw.display.x, max_x).map_err(&mut check);
+        let y = coordinate("display.y", raw.display.y, max_y).map_err(&mut check);
+        let color = color("display.color", raw.display.color.as_deref()).map_err(&mut check);
+        let sides = choice(
+            "printer.sides",
+            raw.printer.sides.as_deref(),
+            &["both", "single"],
+        )
+        .map(|i| [Sides::Both, Sides::Single][i])
+        .map_err(&mut check);
+        let output = choice(
+            "printer.output",
+            raw.printer.output.as_deref(),
+            &["color", "black_and_white"],
+        )
+        .map(|i| [Output::Color, Output::BlackAndWhite][i])
+        .map_err(&mut check);
+        match (x, y, color, sides, output) {
+            (Ok(x), Ok(y), Ok(color), Ok(sides), Ok(output)) => Ok(Config {
+                display: DisplayProps { x, y, color },
+                sides,
+                output,
+            }),
+            _ => Err(ConfigErrors(errors)),
+        }
+    }
+}
+
+fn coordinate(key: &'static str, value: Option<i64>, max: u32) -> Result<u32, ConfigError> {
+    let value = value.ok_or(ConfigError::MissingKey(key))?;
+    u32::try_from(value)
+        .ok()
+        .filter(|&coordinate| coordinate < max)
+        .ok_or(ConfigError::OutOfRange { key, value, max })
+}
+
+// Left out, the color is monochrome.
+fn color(key: &'static str, value: Option<&str>) -> Result<Color, ConfigError> {
+    match value {
+        None | Some("monochrome") => Ok(Color::Monochrome),
+        Some(hex) => parse_hex(hex)
+            .map(Color::Foreground)
+            .ok_or_else(|| ConfigError::BadColor {
+                key,
+                value: hex.to_owned(),
+            }),
+    }
+}
+
+// `#rrggbb`, in either case.
+fn parse_hex(text: &str) -> Option<RgbColor> {
+    let digits = text.strip_prefix('#')?;
+    if digits.len() != 6 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let component = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).ok();
+    Some(RgbColor(component(0)?, component(2)?, component(4)?))
+}
+
+// Which of `names` the value is, by position.
+fn choice(
+    key: &'static str,
+    value: Option<&str>,
+    names: &'static [&'static str],
+) -> Result<usize, ConfigError> {
+    let value = value.ok_or(ConfigError::MissingKey(key))?;
+    names
+        .iter()
+        .position(|name| *name == value)
+        .ok_or_else(|| ConfigError::UnknownValue {
+            key,
+            value: value.to_owned(),
+            expected: names,
+        })
+}
@@ -0,0 +1,57 @@
+//! Conversions the compiler refuses, kept as `compile_fail` doctests so that `cargo test`
+//! checks they still fail, and with the error they fail with. Each is followed by the fix,
+//! which is tested to compile and work.
+//!
+//! Putting a larger integer type (`i32`) into a smaller one (`i16`) is a mismatched type:
+//!
+//! ```compile_fail,E0308
+//! let x: i32 = 42;
+//! let y: i16 = x;
+//! ```
+//!
+//! The conversion can fail, so it has to be asked for, and the failure dealt with:
+//!
+//! ```
+//! let x: i32 = 42;
+//! let y: i16 = x.try_into().unwrap();
+//! assert_eq!(y, 42);
+//! assert!(i16::try_from(70_000i32).is_err());
+//! ```
+//!
+//! Rust doesn't allow some things that appear "safe" either, such as putting a value of a
+//! smaller integer type into a larger one:
+//!
+//! ```compile_fail,E0308
+//! let x = 42i32; // Integer literal with type suffix
+//! let y: i64 = x;
+//! ```
+//!
+//! This conversion can't fail, so `From`/`Into` provide it:
+//!
+//! ```
+//! let x = 42i32;
+//! let y: i64 = x.into();
+//! assert_eq!(y, 42);
+//! ```
+//!
+//! A closure that captures part of its environment can't be used where a function pointer is
+//! expected (see Item 1.2), since the pointer has nowhere to keep what was captured:
+//!
+//! ```compile_fail,E0308
+//! let amount = 3;
+//! let add_num: fn(i32) -> i32 = |y| y + amount;
+//! ```
+//!
+//! Either capture nothing, in which case the closure does coerce to a function pointer, or
+//! accept any closure through a trait bound:
+//!
+//! ```
+//! let add_three: fn(i32) -> i32 = |y| y + 3;
+//! assert_eq!(add_three(23), 26);
+//!
+//! fn apply(f: impl Fn(i32) -> i32, x: i32) -> i32 {
+//!     f(x)
+//! }
+//! let amount = 3;
+//! assert_eq!(apply(|y| y + amount, 23), 26);
+//! ```
@@ -0,0 +1,261 @@
+// The "good" `DisplayProps` from the ENUMS WITH FIELDS section of `run`, with builders to put
+// one together by name rather than position, checking the result as it is built.
+//
+// Builders come in two styles, both here for comparison:
+//
+// - `DisplayPropsBuilder` methods take the builder by value and hand it back, so a whole
+//   build is one expression. Making it conditional means rebinding: `b = b.monochrome()`.
+// - `DisplayPropsMutBuilder` methods take `&mut self`, so the builder can be kept in a
+//   variable and set up over several statements, and `build` can be called more than once.
+//   A single chained expression still works, as long as `build` borrows rather than consumes.
+//
+// `x` and `y` are required, and must be within the maximum resolution (by default 3840x2160,
+// so `x` at most 3839). The color is optional, and monochrome unless set.
+//
+// Props can also be made without a builder, and without its checks: `DisplayProps::default()`
+// is monochrome at the origin, and struct update syntax, `..`, fills in whatever fields a
+// literal leaves out from another value. A `DisplayPropsPatch` does the same at run time, with
+// an `Option` for each field, `None` meaning "leave it as it is".
+
+// `RgbColor` is in the `core-types` crate, with `ColorRangeError`, so that it can be used
+// without `std`.
+pub use core_types::color::{ColorRangeError, RgbColor};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+// An internal tag can't sit alongside `RgbColor`'s array, so the color goes next to it.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "kind", content = "rgb")
+)]
+pub enum Color {
+    #[default]
+    Monochrome,
+    Foreground(RgbColor),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisplayProps {
+    pub x: u32,
+    pub y: u32,
+    pub color: Color,
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Monochrome => write!(f, "monochrome"),
+            Color::Foreground(color) => write!(f, "{color}"),
+        }
+    }
+}
+
+impl fmt::Display for DisplayProps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}) in {}", self.x, self.y, self.color)
+    }
+}
+
+// Written out rather than derived, to say what the defaults are; deriving would give the same.
+impl Default for DisplayProps {
+    fn default() -> Self {
+        DisplayProps {
+            x: 0,
+            y: 0,
+            color: Color::Monochrome,
+        }
+    }
+}
+
+impl DisplayProps {
+    pub fn builder() -> DisplayPropsBuilder {
+        DisplayPropsBuilder::new()
+    }
+
+    /// The default props, moved to `(x, y)`.
+    pub fn with_position(x: u32, y: u32) -> Self {
+        DisplayProps {
+            x,
+            y,
+            ..Default::default()
+        }
+    }
+
+    /// These props, with each field the patch sets replaced.
+    pub fn merge(&self, overrides: DisplayPropsPatch) -> DisplayProps {
+        DisplayProps {
+            x: overrides.x.unwrap_or(self.x),
+            y: overrides.y.unwrap_or(self.y),
+            color: overrides.color.unwrap_or(self.color),
+        }
+    }
+}
+
+/// Changes to some of the fields of a `DisplayProps`, to be made by `DisplayProps::merge`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DisplayPropsPatch {
+    pub x: Option<u32>,
+    pub y: Option<u32>,
+    pub color: Option<Color>,
+}
+
+impl DisplayPropsPatch {
+    /// One patch that does what applying `self` and then `later` would: where both set a
+    /// field, `later` wins.
+    pub fn then(self, later: DisplayPropsPatch) -> DisplayPropsPatch {
+        DisplayPropsPatch {
+            x: later.x.or(self.x),
+            y: later.y.or(self.y),
+            color: later.color.or(self.color),
+        }
+    }
+}
+
+/// The resolution used unless a builder is given another.
+pub const DEFAULT_MAX_RESOLUTION: (u32, u32) = (3840, 2160);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildError {
+    MissingField(&'static str),
+    /// A coordinate of `max` or more, on a display `max` pixels across (or high).
+    OutOfBounds {
+        field: &'static str,
+        value: u32,
+        max: u32,
+    },
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::MissingField(field) => write!(f, "{field} is required"),
+            BuildError::OutOfBounds { field, value, max } => {
+                write!(f, "{field} {value} is off the screen (must be below {max})")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+// What both builders collect, and the checks both make.
+#[derive(Debug, Clone, Copy)]
+struct Fields {
+    x: Option<u32>,
+    y: Option<u32>,
+    color: Color,
+    max_resolution: (u32, u32),
+}
+
+impl Default for Fields {
+    fn default() -> Self {
+        Fields {
+            x: None,
+            y: None,
+            color: Color::default(),
+            max_resolution: DEFAULT_MAX_RESOLUTION,
+        }
+    }
+}
+
+impl Fields {
+    fn build(&self) -> Result<DisplayProps, BuildError> {
+        let coordinate = |field, value: Option<u32>, max| {
+            let value = value.ok_or(BuildError::MissingField(field))?;
+            if value >= max {
+                return Err(BuildError::OutOfBounds { field, value, max });
+            }
+            Ok(value)
+        };
+        let (max_x, max_y) = self.max_resolution;
+        Ok(DisplayProps {
+            x: coordinate("x", self.x, max_x)?,
+            y: coordinate("y", self.y, max_y)?,
+            color: self.color,
+        })
+    }
+}
+
+/// A consuming builder: each method takes `self` and returns it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DisplayPropsBuilder {
+    fields: Fields,
+}
+
+impl DisplayPropsBuilder {
+    pub fn new() -> Self {
+        DisplayPropsBuilder::default()
+    }
+
+    pub fn x(mut self, x: u32) -> Self {
+        self.fields.x = Some(x);
+        self
+    }
+
+    pub fn y(mut self, y: u32) -> Self {
+        self.fields.y = Some(y);
+        self
+    }
+
+    pub fn fg_color(mut self, color: RgbColor) -> Self {
+        self.fields.color = Color::Foreground(color);
+        self
+    }
+
+    pub fn monochrome(mut self) -> Self {
+        self.fields.color = Color::Monochrome;
+        self
+    }
+
+    pub fn max_resolution(mut self, width: u32, height: u32) -> Self {
+        self.fields.max_resolution = (width, height);
+        self
+    }
+
+    pub fn build(self) -> Result<DisplayProps, BuildError> {
+        self.fields.build()
+    }
+}
+
+/// A `&mut self` builder: each method changes the builder in place.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DisplayPropsMutBuilder {
+    fields: Fields,
+}
+
+impl DisplayPropsMutBuilder {
+    pub fn new() -> Self {
+        DisplayPropsMutBuilder::default()
+    }
+
+    pub fn x(&mut self, x: u32) -> &mut Self {
+        self.fields.x = Some(x);
+        self
+    }
+
+    pub fn y(&mut self, y: u32) -> &mut Self {
+        self.fields.y = Some(y);
+        self
+    }
+
+    pub fn fg_color(&mut self, color: RgbColor) -> &mut Self {
+        self.fields.color = Color::Foreground(color);
+        self
+    }
+
+    pub fn monochrome(&mut self) -> &mut Self {
+        self.fields.color = Color::Monochrome;
+        self
+    }
+
+    pub fn max_resolution(&mut self, width: u32, height: u32) -> &mut Self {
+        self.fields.max_resolution = (width, height);
+        self
+    }
+
+    pub fn build(&self) -> Result<DisplayProps, BuildError> {
+        self.fields.build()
+    }
+}
@@ -0,0 +1,175 @@
+// The scheduler again, kept as a record of what happened to it rather than as its current
+// state. Each change that succeeds is appended to an `EventLog` as a `SchedulerEvent`, and the
+// state can be rebuilt at any time by replaying the log from the start: it is the log, not the
+// state, that is the source of truth, and it can be saved and read back to recover a scheduler.
+//
+// An event only says what happened, so a log read from outside can describe things that
+// couldn't have: a job completing before it was assigned, or submitted twice. `replay` goes
+// through `SchedulerState`'s methods, which refuse those, and says which event was the first
+// to be impossible. A `Scheduler` only ever appends events that its state accepted, so its own
+// log always replays.
+//
+// With the `serde` feature, events and logs can be serialized; a log is a plain list.
+
+use crate::newtypes::CpuId;
+use crate::scheduler::{Job, JobId, SchedulerError, SchedulerState};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SchedulerEvent {
+    Submitted(Job),
+    Assigned(JobId, CpuId),
+    /// The job finished, on whichever CPU it was running on.
+    Completed(JobId),
+}
+
+/// Events in the order they happened. There is no way to remove or change one once it is in.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct EventLog {
+    events: Vec<SchedulerEvent>,
+}
+
+impl EventLog {
+    pub fn new() -> Self {
+        EventLog::default()
+    }
+
+    pub fn push(&mut self, event: SchedulerEvent) {
+        self.events.push(event);
+    }
+
+    pub fn events(&self) -> &[SchedulerEvent] {
+        &self.events
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+impl FromIterator<SchedulerEvent> for EventLog {
+    fn from_iter<I: IntoIterator<Item = SchedulerEvent>>(events: I) -> Self {
+        EventLog {
+            events: events.into_iter().collect(),
+        }
+    }
+}
+
+/// The first event in a log that couldn't have happened, after those before it. `index`
+/// counts from 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayError {
+    /// The job was submitted while already pending or running.
+    DuplicateSubmission { index: usize, job: JobId },
+    /// The job was assigned without being pending: never submitted, already running, or done.
+    NotPending { index: usize, job: JobId },
+    /// The job completed without running: never assigned, or already completed.
+    NotRunning { index: usize, job: JobId },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::DuplicateSubmission {
+                index,
+                job: JobId(job),
+            } => write!(f, "event {index}: job {job} is submitted a second time"),
+            ReplayError::NotPending {
+                index,
+                job: JobId(job),
+            } => write!(f, "event {index}: job {job} is assigned but not pending"),
+            ReplayError::NotRunning {
+                index,
+                job: JobId(job),
+            } => write!(f, "event {index}: job {job} completes but is not running"),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+/// The state that `events` leave a new scheduler in.
+pub fn replay(events: &[SchedulerEvent]) -> Result<SchedulerState, ReplayError> {
+    let mut state = SchedulerState::new();
+    for (index, event) in events.iter().enumerate() {
+        apply(&mut state, event).map_err(|job| match event {
+            SchedulerEvent::Submitted(_) => ReplayError::DuplicateSubmission { index, job },
+            SchedulerEvent::Assigned(..) => ReplayError::NotPending { index, job },
+            SchedulerEvent::Completed(_) => ReplayError::NotRunning { index, job },
+        })?;
+    }
+    Ok(state)
+}
+
+// The event's change to `state`, or the id of the job it couldn't be made for. Which of
+// `SchedulerState`'s errors it was follows from the kind of event.
+fn apply(state: &mut SchedulerState, event: &SchedulerEvent) -> Result<(), JobId> {
+    match event {
+        SchedulerEvent::Submitted(job) => state.submit(job.clone()).map_err(|_| job.id),
+        SchedulerEvent::Assigned(job, cpu) => state.assign(*job, *cpu).map_err(|_| *job),
+        SchedulerEvent::Completed(job) => {
+            let cpu = state.cpu_of(*job).ok_or(*job)?;
+            state.complete(cpu, *job).map(drop).map_err(|_| *job)
+        }
+    }
+}
+
+/// A `SchedulerState` that logs every change made to it.
+#[derive(Debug, Clone, Default)]
+pub struct Scheduler {
+    state: SchedulerState,
+    log: EventLog,
+}
+
+impl Scheduler {
+    pub fn new() -> Self {
+        Scheduler::default()
+    }
+
+    /// A scheduler recovered from its log, which carries on from where the log stops.
+    pub fn from_log(log: EventLog) -> Result<Self, ReplayError> {
+        let state = replay(log.events())?;
+        Ok(Scheduler { state, log })
+    }
+
+    pub fn submit(&mut self, job: Job) -> Result<(), SchedulerError> {
+        self.state.submit(job.clone())?;
+        self.log.push(SchedulerEvent::Submitted(job));
+        Ok(())
+    }
+
+    pub fn assign(&mut self, job: JobId, cpu: CpuId) -> Result<(), SchedulerError> {
+        self.state.assign(job, cpu)?;
+        self.log.push(SchedulerEvent::Assigned(job, cpu));
+        Ok(())
+    }
+
+    pub fn complete(&mut self, cpu: CpuId, job: JobId) -> Result<Job, SchedulerError> {
+        let done = self.state.complete(cpu, job)?;
+        self.log.push(SchedulerEvent::Completed(job));
+        Ok(done)
+    }
+
+    /// A copy of the current state, to keep while the scheduler carries on.
+    pub fn snapshot(&self) -> SchedulerState {
+        self.state.clone()
+    }
+
+    pub fn state(&self) -> &SchedulerState {
+        &self.state
+    }
+
+    pub fn log(&self) -> &EventLog {
+        &self.log
+    }
+}
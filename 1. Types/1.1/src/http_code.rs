@@ -0,0 +1,47 @@
+// The enum from the ENUMS section of `run`, grown into something an HTTP client could use. An
+// explicit discriminant per variant means `code as u16` is the status code itself, while going
+// the other way has to be checked: most `u16`s aren't a status code we know about.
+//
+// The enum, with its codes and reason phrases, `ALL`, `Display` and `TryFrom<u16>`, is declared
+// through Item 5.2's `code_enum!`, which writes out the boilerplate each of those would repeat.
+
+item_5_2::code_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum HttpResultCode {
+        Ok = 200 => "OK",
+        Created = 201 => "Created",
+        NoContent = 204 => "No Content",
+        MovedPermanently = 301 => "Moved Permanently",
+        BadRequest = 400 => "Bad Request",
+        Unauthorized = 401 => "Unauthorized",
+        Forbidden = 403 => "Forbidden",
+        NotFound = 404 => "Not Found",
+        InternalServerError = 500 => "Internal Server Error",
+        ServiceUnavailable = 503 => "Service Unavailable",
+    }
+
+    /// A number that isn't one of the status codes `HttpResultCode` covers.
+    pub struct UnknownStatusCode => "unknown HTTP status code";
+}
+
+impl HttpResultCode {
+    /// 2xx.
+    pub fn is_success(self) -> bool {
+        (200..300).contains(&self.code())
+    }
+
+    /// 3xx.
+    pub fn is_redirection(self) -> bool {
+        (300..400).contains(&self.code())
+    }
+
+    /// 4xx: the request was wrong, and sending it again won't help.
+    pub fn is_client_error(self) -> bool {
+        (400..500).contains(&self.code())
+    }
+
+    /// 5xx: the server failed, and the same request may work later.
+    pub fn is_server_error(self) -> bool {
+        (500..600).contains(&self.code())
+    }
+}
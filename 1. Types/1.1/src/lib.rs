@@ -0,0 +1,299 @@
+// Item 1.1: Use the type system to express your data structures.
+
+use std::error::Error;
+
+#[cfg(feature = "config")]
+pub mod config;
+pub mod conversions;
+pub mod display;
+pub mod events;
+pub mod http_code;
+mod logging;
+pub mod newtypes;
+pub mod printing;
+pub mod priority;
+pub mod render;
+pub mod scheduler;
+pub mod search;
+pub mod temperatures;
+pub mod typestate;
+pub mod units;
+
+pub use display::{
+    BuildError, Color, ColorRangeError, DisplayProps, DisplayPropsBuilder, DisplayPropsMutBuilder,
+    DisplayPropsPatch, RgbColor,
+};
+pub use events::{replay, EventLog, ReplayError, Scheduler, SchedulerEvent};
+pub use http_code::{HttpResultCode, UnknownStatusCode};
+pub use newtypes::{CpuId, IdError, UserId};
+pub use printing::{Output, Sides};
+pub use priority::PriorityScheduler;
+pub use scheduler::{Job, JobId, SchedulerError, SchedulerState};
+pub use search::{SearchOptions, TextMatch};
+pub use units::{ConversionError, Feet, Meters, Millimeters};
+
+pub const DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
+
+// Several snippets below only show what a definition looks like, and are never used.
+#[allow(dead_code, unused_variables)]
+pub fn run() -> Result<(), Box<dyn Error>> {
+    // FUNDAMENTAL TYPES
+
+    // Putting a larger integer type (i32) into a smaller integer type (i16) is a compile-time
+    // error, and so, more surprisingly, is putting an i32 into an i64. The failing versions are
+    // `compile_fail` doctests in the `conversions` module; these are the fixes.
+    let x: i32 = 42;
+    let y: i16 = x.try_into().unwrap();
+    // Or:
+    let x: i32 = 42;
+    let y: i32 = x;
+
+    let x = 42i32;
+    let y: i64 = x.into();
+    println!("{x}i32 widened to {y}i64");
+
+    // Types of our own get the same two traits: `From` for conversions that always work, and
+    // `TryFrom` for those that might not. See the `units` module.
+    println!("a 6-foot shelf is {}", units::describe(Feet(6.0)));
+    let too_long: Result<Millimeters, _> = Meters(1e20).try_into();
+    if let Err(e) = too_long {
+        println!("{e}");
+    }
+    // Newtypes keep units apart: a `Celsius` can't be added to a `Fahrenheit` without
+    // converting one of them. See the `temperatures` module.
+    let readings = [
+        temperatures::Celsius(18.5),
+        temperatures::Fahrenheit(71.6).into(),
+    ];
+    if let Some(mean) = temperatures::average(&readings) {
+        println!(
+            "average {mean:.1}, or {:.1}",
+            temperatures::Fahrenheit::from(mean)
+        );
+    }
+
+    // AGGREGATE TYPES
+    // 1. Arrays:  Holds multiple instances of a single type
+    // 2. Tuples:  Hold instances of multiple heterogeneous types
+    // 3. Structs: Also hold instances of heterogeneous types known at compile time but allow
+    //             both the overall type and the individual fields to be referred to by name.
+
+    // Struct with two unnamed fields (`search::find_all` returns these)
+    //
+    //     pub struct TextMatch(pub usize, pub String);
+    let t = TextMatch(4, "str_to_match".to_owned());
+    assert_eq!(t.0, 4); // Access by field number
+    for m in search::find_all_regexless_ci("Naïve or NAÏVE?", "naïve") {
+        println!("found {:?} at byte {}", m.1, m.0);
+    }
+
+    // ENUMS
+    // Each variant of `HttpResultCode` (in the `http_code` module) is given its status code:
+    //
+    //     pub enum HttpResultCode {
+    //         Ok = 200,
+    //         ...
+    //         Unauthorized = 401,
+    //         Forbidden = 403,
+    //         NotFound = 404,
+    //         ...
+    //     }
+
+    let result_code = HttpResultCode::Unauthorized;
+    assert_eq!(result_code as i32, 401);
+    // The cast only goes one way; an arbitrary number may not be a variant at all.
+    let not_found = HttpResultCode::try_from(404)?;
+    println!("{} {not_found}", not_found.code());
+    if let Err(e) = HttpResultCode::try_from(418) {
+        println!("{e}");
+    }
+
+    // can improve readability:
+    // Instead of print_page(/* both_sides= */ true, /* color= */, false)
+    // You can do (see the `printing` module):
+    //
+    //     pub enum Sides {
+    //         Both,
+    //         Single,
+    //     }
+    //
+    //     pub enum Output {
+    //         BlackAndWhite,
+    //         Color,
+    //     }
+
+    // then apply to function (`printing::Printer::print` takes both)
+    let printer = printing::Printer::new(2);
+    let pages = printer.print(
+        "{#ff0000}Title{/}\nfirst line\nsecond line",
+        Sides::Both,
+        Output::BlackAndWhite,
+    );
+    for page in &pages {
+        println!("page {} ({:?}): {:?}", page.number, page.face, page.lines);
+    }
+
+    // Can be used in match statement. Listing every variant, without a `_` arm, means that
+    // adding a variant to `Output` makes this fail to compile until it is handled here too;
+    // `printing::output_name` is the same match, with a doctest showing just that.
+    let output = Output::Color;
+    let output_str = match output {
+        Output::Color => "color",
+        Output::BlackAndWhite => "black_and_white",
+    };
+    println!("printing in {output_str}");
+    // For an enum from another crate marked `#[non_exhaustive]`, a `_` arm is required.
+    println!("on {} paper", printing::paper_name(printing::PaperSize::A4));
+
+    // The enums can also say what state something is in. A print job checked at run time has
+    // to report an error if it is submitted too soon; one that carries its state in its type
+    // (see the `typestate` module) won't compile with the mistake in it.
+    let mut checked = typestate::CheckedPrintJob::new("draft", printer);
+    if let Err(e) = checked.submit() {
+        println!("{e}");
+    }
+    checked.configure(Sides::Single, Output::Color)?;
+    println!("{} page(s)", checked.submit()?.len());
+    let job = typestate::PrintJob::new("draft", printer)
+        .configure(Sides::Both, Output::BlackAndWhite)
+        .submit();
+    println!("{} page(s)", job.pages().len());
+
+    // ENUMS WITH FIELDS
+    // In Rust each enum variant can have data along with them. It acts as
+    // an algebraic data type.
+
+    //     pub enum SchedulerState {
+    //         Insert,
+    //         Pending(HashSet<Job>),
+    //         Running(HashMap<CpuId, Vec<Job>>),
+    //     }
+    //
+    // The working version is in the `scheduler` module, where each variant's data is only
+    // reachable once the state is known to be that variant.
+    let mut scheduler = SchedulerState::new();
+    scheduler.submit(Job::new(1, "compile"))?;
+    scheduler.assign(JobId(1), CpuId(0))?;
+    if let Err(e) = scheduler.assign(JobId(1), CpuId(1)) {
+        println!("{e}");
+    }
+    let done = scheduler.complete(CpuId(0), JobId(1))?;
+    println!("finished job {}: {}", done.id.0, done.payload);
+
+    // A scheduler can be collected from jobs, but `collect` has no way to fail, and panics on a
+    // duplicate id. `try_from_iter` hands the duplicate back instead.
+    let mut scheduler: SchedulerState = (1..=3).map(|id| Job::new(id, "batch")).collect();
+    scheduler.extend([Job::new(4, "late")]);
+    println!("collected {} pending jobs", scheduler.pending().count());
+    let twice = [Job::new(1, "build"), Job::new(1, "build again")];
+    if let Err(e) = SchedulerState::try_from_iter(twice) {
+        println!("{e}");
+    }
+
+    // Jobs can also go straight to the least busy CPU, and an idle CPU can take some of another's.
+    let mut scheduler = SchedulerState::new();
+    for id in 1..=4 {
+        scheduler.assign_to_least_loaded(Job::new(id, "batch"))?;
+    }
+    let moved = scheduler.steal_work(CpuId(0), CpuId(1), 2)?;
+    scheduler.assign_to_least_loaded(Job::new(5, "batch"))?;
+    println!("moved {moved} jobs; load {:?}", scheduler.load_report());
+
+    // A `Scheduler` keeps a log of its changes, from which its state can be rebuilt, and which
+    // is refused if it describes something impossible; see the `events` module.
+    let mut scheduler = Scheduler::new();
+    scheduler.submit(Job::new(1, "compile"))?;
+    scheduler.assign(JobId(1), CpuId(0))?;
+    assert_eq!(replay(scheduler.log().events())?, scheduler.snapshot());
+    println!("replayed {} events", scheduler.log().len());
+    let backwards = [
+        SchedulerEvent::Submitted(Job::new(1, "compile")),
+        SchedulerEvent::Completed(JobId(1)),
+    ];
+    if let Err(e) = replay(&backwards) {
+        println!("{e}");
+    }
+
+    // A `PriorityScheduler` hands out the most urgent job first, the earliest submitted among
+    // equals; see the `priority` module.
+    let mut queue = PriorityScheduler::new();
+    queue.submit(Job::new(1, "backup"))?;
+    queue.submit(Job::new(2, "deploy").with_priority(5))?;
+    queue.submit(Job::new(3, "report"))?;
+    queue.reprioritize(JobId(3), 9)?;
+    while let Some(job) = queue.next_job() {
+        println!("next: job {} ({})", job.id.0, job.payload);
+    }
+
+    // Ids from outside (here, as if from the command line) are checked on the way in; see the
+    // `newtypes` module.
+    let cpu: CpuId = "3".parse()?;
+    println!("{}", newtypes::pin_to_cpu(UserId(1000), cpu));
+    if let Err(e) = CpuId::try_from(-1) {
+        println!("{e}");
+    }
+
+    // Wrong:
+    mod wrong {
+        // `i32`s let this hold colors that don't exist; the good version uses `u8`s.
+        pub struct RgbColor(pub i32, pub i32, pub i32);
+        pub struct DisplayProps {
+            pub x: u32,
+            pub y: u32,
+            pub monochrome: bool,
+            pub fg_color: RgbColor,
+        }
+    }
+
+    // Good (this version is `display::DisplayProps`):
+    //
+    //     pub enum Color {
+    //         Monochrome,
+    //         Foreground(RgbColor),
+    //     }
+    //
+    //     pub struct DisplayProps {
+    //         pub x: u32,
+    //         pub y: u32,
+    //         pub color: Color,
+    //     }
+    //
+    // A builder names each field as it is set, and checks them when it's done.
+    let props = DisplayProps::builder()
+        .x(640)
+        .y(480)
+        .fg_color(RgbColor(255, 128, 0))
+        .build()?;
+    println!("{props:?}");
+    println!("{}", render::styled("in orange", &props));
+    let mut builder = DisplayPropsMutBuilder::new();
+    builder.max_resolution(800, 600).x(1024);
+    if let Err(e) = builder.y(10).build() {
+        println!("{e}");
+    }
+    // Without a builder, `..` takes the fields a literal doesn't give from another value, here
+    // the default; a patch overrides only the fields it sets.
+    let corner = DisplayProps {
+        x: 3839,
+        ..DisplayProps::with_position(0, 2159)
+    };
+    let recolored = corner.merge(DisplayPropsPatch {
+        color: Some(Color::Foreground(RgbColor(0, 0, 255))),
+        ..Default::default()
+    });
+    println!("{corner:?} recolored is {recolored:?}");
+
+    // OPTION<T>
+    // Is there a value of a particular type (Some(T))? Or not (None)
+    {
+        pub struct Job {
+            pub cpus: Option<Vec<i32>>,
+        }
+        let new_job = Job {
+            cpus: Some(vec![10, 20, 30]),
+        };
+        assert!(new_job.cpus.is_some());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+//! Ids as newtypes rather than bare integers. A `UserId` and a `CpuId` are both numbers
+//! underneath, but a function that takes one of each can't be handed them the wrong way round:
+//!
+//! ```compile_fail,E0308
+//! use item_1_1::newtypes::{pin_to_cpu, CpuId, UserId};
+//!
+//! let (user, cpu) = (UserId(1000), CpuId(3));
+//! pin_to_cpu(cpu, user);
+//! ```
+//!
+//! whereas with bare integers the swap compiles, and quietly does the wrong thing:
+//!
+//! ```
+//! use item_1_1::newtypes::pin_to_cpu_unchecked;
+//!
+//! let (user, cpu) = (1000, 3);
+//! assert_eq!(pin_to_cpu_unchecked(cpu, user), "user 3 pinned to cpu 1000");
+//! ```
+//!
+//! Every `u32` is a valid id, so the wrapped value is public; what does need checking is a
+//! value from outside, which arrives through `TryFrom<i64>` or `FromStr`.
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UserId(pub u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CpuId(pub u32);
+
+/// Why a value isn't an id. `id` names the kind of id, such as `"cpu id"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdError {
+    Negative {
+        id: &'static str,
+        value: i64,
+    },
+    TooLarge {
+        id: &'static str,
+        value: i64,
+    },
+    /// Text that isn't a number, or is too long to be one.
+    Invalid {
+        id: &'static str,
+        input: String,
+    },
+}
+
+impl fmt::Display for IdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdError::Negative { id, value } => write!(f, "{id} {value} is negative"),
+            IdError::TooLarge { id, value } => {
+                write!(f, "{id} {value} is larger than {}", u32::MAX)
+            }
+            IdError::Invalid { id, input } => {
+                write!(f, "{id} {input:?} is not a number from 0 to {}", u32::MAX)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IdError {}
+
+fn check_id(id: &'static str, value: i64) -> Result<u32, IdError> {
+    if value < 0 {
+        Err(IdError::Negative { id, value })
+    } else {
+        u32::try_from(value).map_err(|_| IdError::TooLarge { id, value })
+    }
+}
+
+fn parse_id(id: &'static str, input: &str) -> Result<u32, IdError> {
+    let value = input.trim().parse().map_err(|_| IdError::Invalid {
+        id,
+        input: input.to_owned(),
+    })?;
+    check_id(id, value)
+}
+
+// The two types differ only in name, which a macro could stamp out; written out, each is
+// plain to read.
+
+impl TryFrom<i64> for UserId {
+    type Error = IdError;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        check_id("user id", value).map(UserId)
+    }
+}
+
+impl FromStr for UserId {
+    type Err = IdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_id("user id", s).map(UserId)
+    }
+}
+
+impl fmt::Display for UserId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl TryFrom<i64> for CpuId {
+    type Error = IdError;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        check_id("cpu id", value).map(CpuId)
+    }
+}
+
+impl FromStr for CpuId {
+    type Err = IdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_id("cpu id", s).map(CpuId)
+    }
+}
+
+impl fmt::Display for CpuId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub fn pin_to_cpu(user: UserId, cpu: CpuId) -> String {
+    format!("user {user} pinned to cpu {cpu}")
+}
+
+/// `pin_to_cpu` with bare integers, for contrast: nothing stops the arguments being swapped.
+pub fn pin_to_cpu_unchecked(user: i32, cpu: i32) -> String {
+    format!("user {user} pinned to cpu {cpu}")
+}
@@ -0,0 +1,193 @@
+//! The `Sides` and `Output` enums that stand in for `bool` arguments to `print_page`, and what
+//! matching on them can and can't guarantee.
+//!
+//! A `match` that lists every variant, with no `_` arm, is checked by the compiler: add a
+//! variant to the enum and every such `match` stops compiling until it says what to do with
+//! it. Here `Output` has grown a `Grayscale` variant that `output_name` never heard of:
+//!
+//! ```compile_fail,E0004
+//! pub enum Output {
+//!     BlackAndWhite,
+//!     Color,
+//!     Grayscale,
+//! }
+//!
+//! pub fn output_name(output: Output) -> &'static str {
+//!     match output {
+//!         Output::BlackAndWhite => "black_and_white",
+//!         Output::Color => "color",
+//!     }
+//! }
+//! ```
+//!
+//! A `_ => "No output"` arm would have made that compile, and `Grayscale` would have been
+//! printed as "No output". So for an enum you control, leave the wildcard out.
+//!
+//! An enum marked `#[non_exhaustive]` is different: its crate reserves the right to add
+//! variants without that counting as a breaking change, so other crates must always have a
+//! wildcard arm, even if they list every variant there is today:
+//!
+//! ```compile_fail,E0004
+//! use item_1_1::printing::PaperSize;
+//!
+//! fn width_mm(size: PaperSize) -> u32 {
+//!     match size {
+//!         PaperSize::A4 => 210,
+//!         PaperSize::Letter => 216,
+//!     }
+//! }
+//! ```
+//!
+//! ```
+//! use item_1_1::printing::PaperSize;
+//!
+//! fn width_mm(size: PaperSize) -> Option<u32> {
+//!     match size {
+//!         PaperSize::A4 => Some(210),
+//!         PaperSize::Letter => Some(216),
+//!         // Whatever sizes are added later.
+//!         _ => None,
+//!     }
+//! }
+//! assert_eq!(width_mm(PaperSize::A4), Some(210));
+//! ```
+//!
+//! (Inside its own crate a `#[non_exhaustive]` enum is matched like any other, as
+//! `paper_name` does.)
+//!
+//! `Printer` is where `Sides` and `Output` earn their keep: each decides something about the
+//! pages printed, and a call like `print(text, Sides::Both, Output::BlackAndWhite)` says what
+//! without a comment to explain which `bool` was which.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sides {
+    Both,
+    Single,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Output {
+    BlackAndWhite,
+    Color,
+}
+
+/// No wildcard: a new `Output` variant won't compile until it is named here.
+pub fn output_name(output: Output) -> &'static str {
+    match output {
+        Output::Color => "color",
+        Output::BlackAndWhite => "black_and_white",
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PaperSize {
+    A4,
+    Letter,
+}
+
+pub fn paper_name(size: PaperSize) -> &'static str {
+    match size {
+        PaperSize::A4 => "A4",
+        PaperSize::Letter => "US Letter",
+    }
+}
+
+/// Which side of the sheet a page is printed on, when printing on both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Face {
+    Front,
+    Back,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page {
+    /// Counting from 1, blank pages included.
+    pub number: usize,
+    /// `None` when printing on one side only.
+    pub face: Option<Face>,
+    pub lines: Vec<String>,
+}
+
+/// Splits text into pages of a fixed number of lines.
+///
+/// Text may contain color markup: `{#rrggbb}` switches to a color, given in hex, and `{/}`
+/// switches back. `Output::Color` keeps the markup for the printer to act on, and
+/// `Output::BlackAndWhite` removes it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Printer {
+    lines_per_page: usize,
+}
+
+impl Printer {
+    /// Panics if `lines_per_page` is 0.
+    pub fn new(lines_per_page: usize) -> Self {
+        assert!(lines_per_page > 0, "a page must hold at least one line");
+        Printer { lines_per_page }
+    }
+
+    pub fn lines_per_page(&self) -> usize {
+        self.lines_per_page
+    }
+
+    /// The pages for `text`; none at all if it is empty. Printed on both sides, pages
+    /// alternate front and back, with a blank back added to finish the last sheet.
+    pub fn print(&self, text: &str, sides: Sides, output: Output) -> Vec<Page> {
+        let lines: Vec<String> = text
+            .lines()
+            .map(|line| match output {
+                Output::Color => line.to_owned(),
+                Output::BlackAndWhite => strip_color(line),
+            })
+            .collect();
+        let mut contents: Vec<Vec<String>> = lines
+            .chunks(self.lines_per_page)
+            .map(<[String]>::to_vec)
+            .collect();
+        if sides == Sides::Both && contents.len() % 2 == 1 {
+            contents.push(Vec::new());
+        }
+        contents
+            .into_iter()
+            .enumerate()
+            .map(|(i, lines)| Page {
+                number: i + 1,
+                face: match sides {
+                    Sides::Single => None,
+                    Sides::Both if i % 2 == 0 => Some(Face::Front),
+                    Sides::Both => Some(Face::Back),
+                },
+                lines,
+            })
+            .collect()
+    }
+}
+
+/// `line` without its color markup. Anything else in braces is left alone.
+pub fn strip_color(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let tag = &rest[start..];
+        let len = if tag.starts_with("{/}") {
+            3
+        } else if is_color_tag(tag) {
+            "{#rrggbb}".len()
+        } else {
+            out.push('{');
+            1
+        };
+        rest = &tag[len..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn is_color_tag(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    bytes.len() >= 9
+        && bytes.starts_with(b"{#")
+        && bytes[2..8].iter().all(u8::is_ascii_hexdigit)
+        && bytes[8] == b'}'
+}
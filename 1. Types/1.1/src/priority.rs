@@ -0,0 +1,72 @@
+// A scheduler that hands jobs out by priority rather than by id. The jobs sit in a
+// `BinaryHeap`, a max-heap, and `Job`'s `Ord` puts the job that should run next at the top:
+// the highest priority, and among those the one submitted first.
+//
+// A heap only knows where its greatest item is, and offers no way to change an item in place,
+// since that could break the heap's order without it noticing. So `reprioritize` takes the
+// jobs out as a `Vec`, changes the one it was asked to, and builds a new heap, which is O(n).
+
+use crate::scheduler::{Job, JobId, SchedulerError};
+use std::collections::BinaryHeap;
+
+#[derive(Debug, Clone, Default)]
+pub struct PriorityScheduler {
+    heap: BinaryHeap<Job>,
+    // Counts submissions, to stamp each job with when it arrived.
+    clock: u64,
+}
+
+impl PriorityScheduler {
+    pub fn new() -> Self {
+        PriorityScheduler::default()
+    }
+
+    /// Queue a job, stamping it with the time it was submitted. Any `submitted_at` it already
+    /// had is overwritten.
+    pub fn submit(&mut self, mut job: Job) -> Result<(), SchedulerError> {
+        if self.contains(job.id) {
+            return Err(SchedulerError::DuplicateJob(job.id));
+        }
+        job.submitted_at = self.clock;
+        self.clock += 1;
+        self.heap.push(job);
+        Ok(())
+    }
+
+    /// Take the job that should run next.
+    pub fn next_job(&mut self) -> Option<Job> {
+        self.heap.pop()
+    }
+
+    /// The job `next_job` would take, left in place.
+    pub fn peek(&self) -> Option<&Job> {
+        self.heap.peek()
+    }
+
+    /// Change a waiting job's priority. It keeps its place among jobs of its new priority
+    /// by when it was submitted, not by when it was changed.
+    pub fn reprioritize(&mut self, job: JobId, priority: u8) -> Result<(), SchedulerError> {
+        let mut jobs = std::mem::take(&mut self.heap).into_vec();
+        let result = match jobs.iter_mut().find(|waiting| waiting.id == job) {
+            Some(waiting) => {
+                waiting.priority = priority;
+                Ok(())
+            }
+            None => Err(SchedulerError::NotPending(job)),
+        };
+        self.heap = BinaryHeap::from(jobs);
+        result
+    }
+
+    pub fn contains(&self, job: JobId) -> bool {
+        self.heap.iter().any(|waiting| waiting.id == job)
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+}
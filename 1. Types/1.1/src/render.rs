@@ -0,0 +1,58 @@
+// Drawing `DisplayProps` on an ANSI terminal. `Color::Foreground` becomes a 24-bit color
+// escape, `Color::Monochrome` leaves the text alone, and `Canvas` uses `x` and `y` as the
+// column and row (from 0) to put each string at.
+
+use crate::display::{Color, DisplayProps, RgbColor};
+use std::io::{self, Write};
+
+const RESET: &str = "\x1b[0m";
+
+/// The escape sequence that sets the foreground to `color`.
+pub fn foreground(RgbColor(r, g, b): RgbColor) -> String {
+    format!("\x1b[38;2;{r};{g};{b}m")
+}
+
+/// `text` in the color from `props`, resetting the color afterwards.
+pub fn styled(text: &str, props: &DisplayProps) -> String {
+    match props.color {
+        Color::Monochrome => text.to_owned(),
+        Color::Foreground(color) => format!("{}{text}{RESET}", foreground(color)),
+    }
+}
+
+/// The escape sequence that moves the cursor to column `x`, row `y`, counting from 0. The
+/// terminal counts from 1.
+pub fn move_to(x: u32, y: u32) -> String {
+    format!("\x1b[{};{}H", u64::from(y) + 1, u64::from(x) + 1)
+}
+
+/// Strings waiting to be drawn, each at its own position.
+#[derive(Debug, Clone, Default)]
+pub struct Canvas {
+    pending: Vec<(String, DisplayProps)>,
+}
+
+impl Canvas {
+    pub fn new() -> Self {
+        Canvas::default()
+    }
+
+    /// Queue `text` to be drawn at `props.x`, `props.y`, in `props.color`.
+    pub fn draw(&mut self, text: &str, props: &DisplayProps) -> &mut Self {
+        self.pending.push((text.to_owned(), *props));
+        self
+    }
+
+    /// Write everything drawn since the last flush, in the order it was drawn.
+    pub fn flush(&mut self, out: &mut impl Write) -> io::Result<()> {
+        for (text, props) in self.pending.drain(..) {
+            write!(
+                out,
+                "{}{}",
+                move_to(props.x, props.y),
+                styled(&text, &props)
+            )?;
+        }
+        out.flush()
+    }
+}
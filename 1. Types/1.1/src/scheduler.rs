@@ -0,0 +1,384 @@
+// The `SchedulerState` enum from the ENUMS WITH FIELDS section of `run`, made to work. Putting
+// jobs in a `HashSet` and CPUs in `HashMap` keys needs `Hash` and `Eq` on both, and the state
+// only changes through methods that refuse transitions that make no sense.
+//
+// The states keep the shape the book gives them, except that a scheduler with jobs running
+// can still have others waiting: `Insert` means there is nothing at all, `Pending` that jobs
+// are waiting but no CPU is known yet, and `Running` that the scheduler knows at least one
+// CPU, whether or not it has anything on it.
+//
+// A scheduler can also be collected from jobs, and extended with more, through `FromIterator`
+// and `Extend`. Neither trait has a way to report an error, so both panic on a duplicate id;
+// `try_from_iter` is the version that returns it instead.
+//
+// The CPUs a scheduler knows about are the keys of its `running` map. A CPU joins when it is
+// registered, is assigned a job, or takes jobs from another with `steal_work`, and stays once
+// it runs out of work, with an empty `Vec`, so that `assign_to_least_loaded` can choose an
+// idle CPU. With none known, that is CPU 0. Each of these goes through `HashMap`'s entry API,
+// which finds a CPU's jobs or puts an empty `Vec` there in one lookup.
+//
+// With the `logging` feature, each change of state is logged at debug level through the `log`
+// facade.
+
+use crate::logging::log_debug;
+use crate::newtypes::CpuId;
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobId(pub u32);
+
+/// A job is identified by its id alone: equality and hashing ignore the other fields, so that
+/// a set of jobs can be searched by `JobId`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Job {
+    pub id: JobId,
+    pub payload: String,
+    /// Higher runs sooner, in a `PriorityScheduler`.
+    pub priority: u8,
+    /// When the job was submitted to a `PriorityScheduler`, which sets it.
+    pub submitted_at: u64,
+}
+
+impl Job {
+    /// A job with the lowest priority.
+    pub fn new(id: u32, payload: impl Into<String>) -> Self {
+        Job {
+            id: JobId(id),
+            payload: payload.into(),
+            priority: 0,
+            submitted_at: 0,
+        }
+    }
+
+    pub fn with_priority(self, priority: u8) -> Self {
+        Job { priority, ..self }
+    }
+}
+
+// `Debug` is derived, and shows every field, for whoever is debugging the scheduler; `Display`
+// is for telling a user which job this is.
+impl fmt::Display for Job {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "job {} ({:?})", self.id.0, self.payload)?;
+        if self.priority > 0 {
+            write!(f, " at priority {}", self.priority)?;
+        }
+        Ok(())
+    }
+}
+
+impl PartialEq for Job {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Job {}
+
+impl Hash for Job {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+// Sound because `Job`'s `Eq` and `Hash` agree with `JobId`'s.
+impl Borrow<JobId> for Job {
+    fn borrow(&self) -> &JobId {
+        &self.id
+    }
+}
+
+/// Jobs order by how soon they should run, greatest first: higher `priority`, then earlier
+/// `submitted_at`, then lower id, so that no two jobs with different ids compare equal.
+///
+/// A job is always equal to itself, whatever its other fields, to agree with `Eq`. That makes
+/// the order consistent only among jobs with distinct ids, which every scheduler here
+/// guarantees, but comparing two versions of the same job tells you nothing.
+impl Ord for Job {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.id == other.id {
+            return Ordering::Equal;
+        }
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.submitted_at.cmp(&self.submitted_at))
+            .then_with(|| other.id.cmp(&self.id))
+    }
+}
+
+impl PartialOrd for Job {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchedulerError {
+    /// A job with the same id has already been submitted and hasn't completed.
+    DuplicateJob(JobId),
+    /// The job can't be assigned, because it isn't waiting for a CPU.
+    NotPending(JobId),
+    /// The job can't be completed on this CPU, because it isn't running there.
+    NotRunning { cpu: CpuId, job: JobId },
+    /// There are no jobs running on the CPU to be taken from it.
+    NoJobs(CpuId),
+}
+
+impl fmt::Display for SchedulerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchedulerError::DuplicateJob(JobId(job)) => {
+                write!(f, "job {job} has already been submitted")
+            }
+            SchedulerError::NotPending(JobId(job)) => write!(f, "job {job} is not pending"),
+            SchedulerError::NotRunning {
+                cpu,
+                job: JobId(job),
+            } => {
+                write!(f, "job {job} is not running on cpu {cpu}")
+            }
+            SchedulerError::NoJobs(cpu) => write!(f, "cpu {cpu} has no jobs running"),
+        }
+    }
+}
+
+impl std::error::Error for SchedulerError {}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SchedulerState {
+    #[default]
+    Insert,
+    Pending(HashSet<Job>),
+    Running {
+        pending: HashSet<Job>,
+        running: HashMap<CpuId, Vec<Job>>,
+    },
+}
+
+impl SchedulerState {
+    pub fn new() -> Self {
+        SchedulerState::Insert
+    }
+
+    /// Queue a job until it is assigned a CPU.
+    pub fn submit(&mut self, job: Job) -> Result<(), SchedulerError> {
+        let id = job.id;
+        if self.contains(id) {
+            return Err(SchedulerError::DuplicateJob(id));
+        }
+        match self {
+            SchedulerState::Insert => *self = SchedulerState::Pending(HashSet::from([job])),
+            SchedulerState::Pending(pending) | SchedulerState::Running { pending, .. } => {
+                pending.insert(job);
+            }
+        }
+        log_debug!("job {} submitted, {}", id.0, self.state_name());
+        Ok(())
+    }
+
+    /// A scheduler with all of `jobs` pending, or the first duplicate id among them.
+    pub fn try_from_iter<I>(jobs: I) -> Result<Self, SchedulerError>
+    where
+        I: IntoIterator<Item = Job>,
+    {
+        let mut scheduler = SchedulerState::new();
+        for job in jobs {
+            scheduler.submit(job)?;
+        }
+        Ok(scheduler)
+    }
+
+    /// Start a pending job on `cpu`, alongside anything already running there.
+    pub fn assign(&mut self, job: JobId, cpu: CpuId) -> Result<(), SchedulerError> {
+        let (mut pending, mut running) = self.take_parts();
+        let result = match pending.take(&job) {
+            Some(job) => {
+                running.entry(cpu).or_default().push(job);
+                Ok(())
+            }
+            None => Err(SchedulerError::NotPending(job)),
+        };
+        *self = SchedulerState::from_parts(pending, running);
+        if result.is_ok() {
+            log_debug!("job {} assigned to cpu {cpu}, {}", job.0, self.state_name());
+        }
+        result
+    }
+
+    /// Finish a job running on `cpu`, and hand it back.
+    pub fn complete(&mut self, cpu: CpuId, job: JobId) -> Result<Job, SchedulerError> {
+        let (pending, mut running) = self.take_parts();
+        let finished = running.get_mut(&cpu).and_then(|jobs| {
+            let index = jobs.iter().position(|running| running.id == job)?;
+            Some(jobs.remove(index))
+        });
+        *self = SchedulerState::from_parts(pending, running);
+        if finished.is_some() {
+            log_debug!(
+                "job {} completed on cpu {cpu}, {}",
+                job.0,
+                self.state_name()
+            );
+        }
+        finished.ok_or(SchedulerError::NotRunning { cpu, job })
+    }
+
+    /// Make `cpu` known to the scheduler, so that `assign_to_least_loaded` can choose it. A CPU
+    /// that is already known keeps the jobs it has.
+    pub fn register_cpu(&mut self, cpu: CpuId) {
+        let (pending, mut running) = self.take_parts();
+        running.entry(cpu).or_default();
+        *self = SchedulerState::from_parts(pending, running);
+        log_debug!("cpu {cpu} registered, {}", self.state_name());
+    }
+
+    /// Start a new job straight away on whichever known CPU has the fewest jobs running, the
+    /// lowest numbered among those tied, and say which it was. With no CPU known, that is CPU 0.
+    pub fn assign_to_least_loaded(&mut self, job: Job) -> Result<CpuId, SchedulerError> {
+        let id = job.id;
+        if self.contains(id) {
+            return Err(SchedulerError::DuplicateJob(id));
+        }
+        let (pending, mut running) = self.take_parts();
+        let cpu = running
+            .iter()
+            .min_by_key(|&(&cpu, jobs)| (jobs.len(), cpu))
+            .map_or(CpuId(0), |(&cpu, _)| cpu);
+        running.entry(cpu).or_default().push(job);
+        *self = SchedulerState::from_parts(pending, running);
+        log_debug!("job {} assigned to cpu {cpu}, {}", id.0, self.state_name());
+        Ok(cpu)
+    }
+
+    /// Move up to `n` jobs from `from` to `to`, the ones most recently assigned, and say how
+    /// many moved: fewer than `n` if `from` doesn't have that many, in which case it gives up
+    /// all it has. They keep their order, after any jobs already on `to`. Stealing from a CPU
+    /// for itself moves nothing.
+    pub fn steal_work(
+        &mut self,
+        from: CpuId,
+        to: CpuId,
+        n: usize,
+    ) -> Result<usize, SchedulerError> {
+        if self.running_on(from).is_empty() {
+            return Err(SchedulerError::NoJobs(from));
+        }
+        if from == to || n == 0 {
+            return Ok(0);
+        }
+        let (pending, mut running) = self.take_parts();
+        let jobs = running.get_mut(&from).expect("checked above");
+        let stolen = jobs.split_off(jobs.len().saturating_sub(n));
+        let count = stolen.len();
+        running.entry(to).or_default().extend(stolen);
+        *self = SchedulerState::from_parts(pending, running);
+        log_debug!("{count} jobs moved from cpu {from} to cpu {to}");
+        Ok(count)
+    }
+
+    /// How many jobs are running on each known CPU, idle ones included, in CPU order.
+    pub fn load_report(&self) -> BTreeMap<CpuId, usize> {
+        match self {
+            SchedulerState::Running { running, .. } => running
+                .iter()
+                .map(|(&cpu, jobs)| (cpu, jobs.len()))
+                .collect(),
+            _ => BTreeMap::new(),
+        }
+    }
+
+    /// Whether a job with this id is pending or running.
+    pub fn contains(&self, job: JobId) -> bool {
+        match self {
+            SchedulerState::Insert => false,
+            SchedulerState::Pending(pending) => pending.contains(&job),
+            SchedulerState::Running { pending, running } => {
+                pending.contains(&job) || running.values().flatten().any(|j| j.id == job)
+            }
+        }
+    }
+
+    /// The jobs waiting for a CPU, in no particular order.
+    pub fn pending(&self) -> impl Iterator<Item = &Job> {
+        match self {
+            SchedulerState::Insert => None,
+            SchedulerState::Pending(pending) | SchedulerState::Running { pending, .. } => {
+                Some(pending)
+            }
+        }
+        .into_iter()
+        .flatten()
+    }
+
+    /// The CPU the job is running on, if it is running.
+    pub fn cpu_of(&self, job: JobId) -> Option<CpuId> {
+        match self {
+            SchedulerState::Running { running, .. } => running
+                .iter()
+                .find(|(_, jobs)| jobs.iter().any(|j| j.id == job))
+                .map(|(&cpu, _)| cpu),
+            _ => None,
+        }
+    }
+
+    /// The jobs running on `cpu`, in the order they were assigned.
+    pub fn running_on(&self, cpu: CpuId) -> &[Job] {
+        match self {
+            SchedulerState::Running { running, .. } => running.get(&cpu).map_or(&[], Vec::as_slice),
+            _ => &[],
+        }
+    }
+
+    fn state_name(&self) -> &'static str {
+        match self {
+            SchedulerState::Insert => "now empty",
+            SchedulerState::Pending(_) => "now pending",
+            SchedulerState::Running { running, .. } if running.values().all(Vec::is_empty) => {
+                "now idle"
+            }
+            SchedulerState::Running { .. } => "now running",
+        }
+    }
+
+    fn take_parts(&mut self) -> (HashSet<Job>, HashMap<CpuId, Vec<Job>>) {
+        match std::mem::take(self) {
+            SchedulerState::Insert => (HashSet::new(), HashMap::new()),
+            SchedulerState::Pending(pending) => (pending, HashMap::new()),
+            SchedulerState::Running { pending, running } => (pending, running),
+        }
+    }
+
+    // The one place that decides which state a set of jobs and CPUs is in.
+    fn from_parts(pending: HashSet<Job>, running: HashMap<CpuId, Vec<Job>>) -> Self {
+        if !running.is_empty() {
+            SchedulerState::Running { pending, running }
+        } else if !pending.is_empty() {
+            SchedulerState::Pending(pending)
+        } else {
+            SchedulerState::Insert
+        }
+    }
+}
+
+/// Panics if two jobs have the same id; see `SchedulerState::try_from_iter`.
+impl FromIterator<Job> for SchedulerState {
+    fn from_iter<I: IntoIterator<Item = Job>>(jobs: I) -> Self {
+        SchedulerState::try_from_iter(jobs).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+/// Submits each job in turn. Panics if one has the same id as a job already in the scheduler,
+/// or as one before it; the jobs before that are left submitted.
+impl Extend<Job> for SchedulerState {
+    fn extend<I: IntoIterator<Item = Job>>(&mut self, jobs: I) {
+        for job in jobs {
+            self.submit(job).unwrap_or_else(|e| panic!("{e}"));
+        }
+    }
+}
@@ -0,0 +1,83 @@
+// Finding every occurrence of a string in another, as `TextMatch` values: the byte offset where
+// the match starts, and the text it matched. Offsets are always on `char` boundaries, so they
+// can be used to slice the haystack.
+//
+// Case-insensitive matching compares one `char` with one `char` after lowercasing both, so a
+// match is always as many characters long as the needle, though it may not be as many bytes.
+
+/// A match at a byte offset in the haystack, and the matched text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextMatch(pub usize, pub String);
+
+impl TextMatch {
+    /// 1-based line and column (in `char`s) of the start of the match in `haystack`.
+    pub fn line_col(&self, haystack: &str) -> (usize, usize) {
+        diagnostic::line_col(haystack, self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SearchOptions {
+    pub case_insensitive: bool,
+    /// Whether a match may start inside the previous one: "aa" is found in "aaa" twice when
+    /// overlapping, and once when not.
+    pub overlapping: bool,
+}
+
+/// Every non-overlapping, case-sensitive occurrence of `needle`.
+pub fn find_all(haystack: &str, needle: &str) -> Vec<TextMatch> {
+    find_all_with(haystack, needle, &SearchOptions::default())
+}
+
+/// Every non-overlapping occurrence of `needle`, ignoring case.
+pub fn find_all_regexless_ci(haystack: &str, needle: &str) -> Vec<TextMatch> {
+    let options = SearchOptions {
+        case_insensitive: true,
+        ..SearchOptions::default()
+    };
+    find_all_with(haystack, needle, &options)
+}
+
+/// Every occurrence of `needle`, from left to right. An empty needle matches nothing.
+pub fn find_all_with(haystack: &str, needle: &str, options: &SearchOptions) -> Vec<TextMatch> {
+    let mut matches = Vec::new();
+    if needle.is_empty() {
+        return matches;
+    }
+    let mut start = 0;
+    while start < haystack.len() {
+        match match_len(&haystack[start..], needle, options.case_insensitive) {
+            Some(len) => {
+                matches.push(TextMatch(start, haystack[start..start + len].to_owned()));
+                start += if options.overlapping {
+                    next_char_len(&haystack[start..])
+                } else {
+                    len
+                };
+            }
+            None => start += next_char_len(&haystack[start..]),
+        }
+    }
+    matches
+}
+
+// How many bytes at the start of `text` match `needle`, if they do.
+fn match_len(text: &str, needle: &str, case_insensitive: bool) -> Option<usize> {
+    if !case_insensitive {
+        return text.starts_with(needle).then_some(needle.len());
+    }
+    let mut len = 0;
+    let mut chars = text.chars();
+    for wanted in needle.chars() {
+        let c = chars.next()?;
+        if c != wanted && !c.to_lowercase().eq(wanted.to_lowercase()) {
+            return None;
+        }
+        len += c.len_utf8();
+    }
+    Some(len)
+}
+
+fn next_char_len(text: &str) -> usize {
+    text.chars().next().map_or(1, char::len_utf8)
+}
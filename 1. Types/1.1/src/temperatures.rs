@@ -0,0 +1,129 @@
+//! Temperatures as newtypes, so that a reading in Celsius can't be added to one in Fahrenheit
+//! by mistake. Each unit adds to itself, but there is no `Add` across units, so mixing them
+//! doesn't compile:
+//!
+//! ```compile_fail,E0308
+//! use item_1_1::temperatures::{Celsius, Fahrenheit};
+//!
+//! let total = Celsius(20.0) + Fahrenheit(68.0);
+//! ```
+//!
+//! Converting first says which unit the answer is in:
+//!
+//! ```
+//! use item_1_1::temperatures::{Celsius, Fahrenheit};
+//!
+//! let total = Celsius(20.0) + Celsius::from(Fahrenheit(68.0));
+//! assert_eq!(total, Celsius(40.0));
+//! ```
+//!
+//! `Temperature` is the arithmetic that `average` needs, and nothing more, so it works for
+//! either unit and gives its answer in the same one.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div};
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Celsius(pub f64);
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Fahrenheit(pub f64);
+
+impl From<Fahrenheit> for Celsius {
+    fn from(Fahrenheit(f): Fahrenheit) -> Self {
+        Celsius((f - 32.0) * 5.0 / 9.0)
+    }
+}
+
+impl From<Celsius> for Fahrenheit {
+    fn from(Celsius(c): Celsius) -> Self {
+        Fahrenheit(c * 9.0 / 5.0 + 32.0)
+    }
+}
+
+/// A temperature scale whose readings can be added up and divided by a count.
+pub trait Temperature: Copy + AddAssign + Div<f64, Output = Self> {
+    /// What a sum starts from.
+    const ZERO: Self;
+}
+
+/// The mean of `readings`, in their own unit, or `None` if there aren't any.
+pub fn average<T: Temperature>(readings: &[T]) -> Option<T> {
+    if readings.is_empty() {
+        return None;
+    }
+    let mut total = T::ZERO;
+    for &reading in readings {
+        total += reading;
+    }
+    Some(total / readings.len() as f64)
+}
+
+// The same impls for each unit. The number is written with the formatter it was given, so
+// `{:.1}` rounds it, and the unit goes after.
+
+impl Add for Celsius {
+    type Output = Celsius;
+
+    fn add(self, other: Celsius) -> Celsius {
+        Celsius(self.0 + other.0)
+    }
+}
+
+impl AddAssign for Celsius {
+    fn add_assign(&mut self, other: Celsius) {
+        self.0 += other.0;
+    }
+}
+
+impl Div<f64> for Celsius {
+    type Output = Celsius;
+
+    fn div(self, divisor: f64) -> Celsius {
+        Celsius(self.0 / divisor)
+    }
+}
+
+impl Temperature for Celsius {
+    const ZERO: Self = Celsius(0.0);
+}
+
+impl fmt::Display for Celsius {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)?;
+        f.write_str(" °C")
+    }
+}
+
+impl Add for Fahrenheit {
+    type Output = Fahrenheit;
+
+    fn add(self, other: Fahrenheit) -> Fahrenheit {
+        Fahrenheit(self.0 + other.0)
+    }
+}
+
+impl AddAssign for Fahrenheit {
+    fn add_assign(&mut self, other: Fahrenheit) {
+        self.0 += other.0;
+    }
+}
+
+impl Div<f64> for Fahrenheit {
+    type Output = Fahrenheit;
+
+    fn div(self, divisor: f64) -> Fahrenheit {
+        Fahrenheit(self.0 / divisor)
+    }
+}
+
+impl Temperature for Fahrenheit {
+    const ZERO: Self = Fahrenheit(0.0);
+}
+
+impl fmt::Display for Fahrenheit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)?;
+        f.write_str(" °F")
+    }
+}
@@ -0,0 +1,203 @@
+//! A print job that has to be configured, with `Sides` and `Output`, before it is submitted,
+//! done twice: once checked when the program runs, and once checked by the compiler.
+//!
+//! `CheckedPrintJob` keeps its state in an enum, and `submit` returns an error if the job
+//! isn't ready. `PrintJob<S>` keeps its state in its type instead: `S` is one of
+//! `Unconfigured`, `Configured` and `Submitted`, which hold what the enum's variants do, each
+//! transition consumes the job and returns it with a new `S`, and each method only exists in
+//! the states it makes sense in. Submitting a job that hasn't been configured isn't an error to handle, because
+//! there is no such method to call:
+//!
+//! ```compile_fail,E0599
+//! use item_1_1::printing::Printer;
+//! use item_1_1::typestate::PrintJob;
+//!
+//! let pages = PrintJob::new("hello", Printer::new(10)).submit();
+//! ```
+//!
+//! Nor can a job be submitted twice, since the first `submit` used it up, and what it gave
+//! back is a `PrintJob<Submitted>`, which has no `submit` either:
+//!
+//! ```compile_fail,E0599
+//! use item_1_1::printing::{Output, Printer, Sides};
+//! use item_1_1::typestate::PrintJob;
+//!
+//! let job = PrintJob::new("hello", Printer::new(10)).configure(Sides::Single, Output::Color);
+//! let submitted = job.submit();
+//! submitted.submit();
+//! ```
+
+use crate::printing::{Output, Page, Printer, Sides};
+use std::fmt;
+
+// CHECKED AT RUN TIME
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JobState {
+    Unconfigured,
+    Configured { sides: Sides, output: Output },
+    Submitted(Vec<Page>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrintJobError {
+    NotConfigured,
+    AlreadySubmitted,
+}
+
+impl fmt::Display for PrintJobError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PrintJobError::NotConfigured => write!(f, "the print job hasn't been configured"),
+            PrintJobError::AlreadySubmitted => {
+                write!(f, "the print job has already been submitted")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PrintJobError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckedPrintJob {
+    text: String,
+    printer: Printer,
+    state: JobState,
+}
+
+impl CheckedPrintJob {
+    pub fn new(text: impl Into<String>, printer: Printer) -> Self {
+        CheckedPrintJob {
+            text: text.into(),
+            printer,
+            state: JobState::Unconfigured,
+        }
+    }
+
+    pub fn state(&self) -> &JobState {
+        &self.state
+    }
+
+    /// Set how the job is to be printed, replacing any earlier settings, until it is submitted.
+    pub fn configure(&mut self, sides: Sides, output: Output) -> Result<(), PrintJobError> {
+        if let JobState::Submitted(_) = self.state {
+            return Err(PrintJobError::AlreadySubmitted);
+        }
+        self.state = JobState::Configured { sides, output };
+        Ok(())
+    }
+
+    pub fn submit(&mut self) -> Result<&[Page], PrintJobError> {
+        let (sides, output) = match self.state {
+            JobState::Unconfigured => return Err(PrintJobError::NotConfigured),
+            JobState::Configured { sides, output } => (sides, output),
+            JobState::Submitted(_) => return Err(PrintJobError::AlreadySubmitted),
+        };
+        self.state = JobState::Submitted(self.printer.print(&self.text, sides, output));
+        match &self.state {
+            JobState::Submitted(pages) => Ok(pages),
+            _ => unreachable!("the state was just set"),
+        }
+    }
+}
+
+// CHECKED AT COMPILE TIME
+
+/// The state of a job that hasn't been told how to print yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unconfigured;
+
+/// The state of a job that is ready to submit, with how it is to be printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Configured {
+    sides: Sides,
+    output: Output,
+}
+
+/// The state of a job that has been printed, with the settings it was printed with and the
+/// pages that came out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submitted {
+    settings: Configured,
+    pages: Vec<Page>,
+}
+
+/// A print job in state `S`, which holds whatever the job only has in that state. The fields
+/// of `Configured` and `Submitted` are private, so the only way to get either is through the
+/// transitions below, and a job in them can't be missing its settings or its pages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrintJob<S> {
+    text: String,
+    printer: Printer,
+    state: S,
+}
+
+impl<S> PrintJob<S> {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    fn into_state<T>(self, state: T) -> PrintJob<T> {
+        PrintJob {
+            text: self.text,
+            printer: self.printer,
+            state,
+        }
+    }
+}
+
+impl PrintJob<Unconfigured> {
+    pub fn new(text: impl Into<String>, printer: Printer) -> Self {
+        PrintJob {
+            text: text.into(),
+            printer,
+            state: Unconfigured,
+        }
+    }
+
+    pub fn configure(self, sides: Sides, output: Output) -> PrintJob<Configured> {
+        self.into_state(Configured { sides, output })
+    }
+}
+
+impl PrintJob<Configured> {
+    pub fn sides(&self) -> Sides {
+        self.state.sides
+    }
+
+    pub fn output(&self) -> Output {
+        self.state.output
+    }
+
+    /// Change the settings, which is allowed up until the job is submitted.
+    pub fn reconfigure(self, sides: Sides, output: Output) -> PrintJob<Configured> {
+        self.into_state(Configured { sides, output })
+    }
+
+    pub fn submit(self) -> PrintJob<Submitted> {
+        let settings = self.state;
+        let pages = self
+            .printer
+            .print(&self.text, settings.sides, settings.output);
+        self.into_state(Submitted { settings, pages })
+    }
+}
+
+impl PrintJob<Submitted> {
+    /// The settings the job was printed with.
+    pub fn sides(&self) -> Sides {
+        self.state.settings.sides
+    }
+
+    pub fn output(&self) -> Output {
+        self.state.settings.output
+    }
+
+    pub fn pages(&self) -> &[Page] {
+        &self.state.pages
+    }
+
+    pub fn into_pages(self) -> Vec<Page> {
+        self.state.pages
+    }
+}
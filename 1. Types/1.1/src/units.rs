@@ -0,0 +1,29 @@
+//! Lengths in three units, defined in the `core-types` crate with the conversions between them,
+//! and re-exported here.
+//!
+//! Implementing `From` is enough to get `Into` as well, through a blanket impl in the standard
+//! library, so `describe` can take anything that is `Into<Meters>`. A count of millimeters
+//! isn't, having only `TryFrom`, and has to be converted first:
+//!
+//! ```compile_fail,E0277
+//! use item_1_1::units::{describe, Millimeters};
+//!
+//! describe(Millimeters(1500));
+//! ```
+//!
+//! ```
+//! use item_1_1::units::{describe, Meters, Millimeters};
+//!
+//! let length = Meters::try_from(Millimeters(1500)).unwrap();
+//! assert_eq!(describe(length), "1.500 m");
+//! ```
+
+pub use core_types::units::{
+    ConversionError, Feet, Meters, Millimeters, MAX_EXACT_MILLIMETERS, METERS_PER_FOOT,
+};
+
+/// Anything that converts to meters without failing, described in meters.
+pub fn describe<T: Into<Meters>>(length: T) -> String {
+    let Meters(meters) = length.into();
+    format!("{meters:.3} m")
+}
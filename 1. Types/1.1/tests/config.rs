@@ -0,0 +1,170 @@
+// Only built with the `config` feature.
+
+use item_1_1::config::{Config, ConfigError, RawConfig, RawDisplay};
+use item_1_1::{Color, DisplayProps, Output, RgbColor, Sides};
+use std::path::{Path, PathBuf};
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+#[test]
+fn loads_a_valid_file() {
+    let config = Config::load(&fixture("valid_config.toml")).unwrap();
+    assert_eq!(
+        config,
+        Config {
+            display: DisplayProps {
+                x: 640,
+                y: 480,
+                color: Color::Foreground(RgbColor(0xff, 0x88, 0x00)),
+            },
+            sides: Sides::Both,
+            output: Output::BlackAndWhite,
+        }
+    );
+}
+
+#[test]
+fn reports_every_error_in_an_invalid_file() {
+    let errors = Config::load(&fixture("invalid_config.toml")).unwrap_err();
+    let [x, y, color, sides, output] = &errors.0[..] else {
+        panic!("expected five errors, got {errors:?}");
+    };
+    assert!(
+        matches!(
+            x,
+            ConfigError::OutOfRange {
+                key: "display.x",
+                value: -1,
+                max: 3840
+            }
+        ),
+        "{x:?}"
+    );
+    assert!(
+        matches!(
+            y,
+            ConfigError::OutOfRange {
+                key: "display.y",
+                value: 2160,
+                max: 2160
+            }
+        ),
+        "{y:?}"
+    );
+    assert!(
+        matches!(color, ConfigError::BadColor { key: "display.color", value } if value == "#ff88"),
+        "{color:?}"
+    );
+    assert!(
+        matches!(
+            sides,
+            ConfigError::UnknownValue { key: "printer.sides", value, expected: ["both", "single"] }
+                if value == "double"
+        ),
+        "{sides:?}"
+    );
+    assert!(
+        matches!(output, ConfigError::MissingKey("printer.output")),
+        "{output:?}"
+    );
+    assert_eq!(
+        errors.to_string(),
+        "\
+display.x -1 is off the screen (must be 0 to 3839)
+display.y 2160 is off the screen (must be 0 to 2159)
+display.color \"#ff88\" is not a color like \"#ff8800\"
+printer.sides \"double\" is not one of both, single
+printer.output is required"
+    );
+}
+
+#[test]
+fn color_may_be_left_out_or_monochrome() {
+    let text = "[display]\nx = 0\ny = 0\n[printer]\nsides = \"single\"\noutput = \"color\"\n";
+    let config = Config::parse(text).unwrap();
+    assert_eq!(config.display.color, Color::Monochrome);
+    assert_eq!(
+        (config.sides, config.output),
+        (Sides::Single, Output::Color)
+    );
+
+    let text = text.replace("y = 0", "y = 0\ncolor = \"monochrome\"");
+    assert_eq!(Config::parse(&text).unwrap(), config);
+}
+
+#[test]
+fn rejects_colors_that_are_not_six_hex_digits() {
+    for bad in [
+        "ff8800", "#ff880", "#ff88000", "#gg8800", "#ff 800", "red", "",
+    ] {
+        let raw = RawConfig {
+            display: RawDisplay {
+                x: Some(0),
+                y: Some(0),
+                color: Some(bad.to_owned()),
+            },
+            ..RawConfig::default()
+        };
+        let errors = Config::try_from(raw).unwrap_err();
+        assert!(
+            matches!(&errors.0[0], ConfigError::BadColor { value, .. } if value == bad),
+            "{bad:?}: {errors:?}"
+        );
+    }
+}
+
+#[test]
+fn an_empty_file_is_missing_every_required_key() {
+    let errors = Config::parse("").unwrap_err();
+    let keys: Vec<_> = errors
+        .0
+        .iter()
+        .map(|e| match e {
+            ConfigError::MissingKey(key) => *key,
+            other => panic!("expected a missing key, got {other:?}"),
+        })
+        .collect();
+    assert_eq!(
+        keys,
+        ["display.x", "display.y", "printer.sides", "printer.output"]
+    );
+}
+
+#[test]
+fn rejects_unknown_keys() {
+    for (text, unknown) in [
+        ("[display]\ncolour = \"#ff8800\"\n", "colour"),
+        ("[printer]\nduplex = true\n", "duplex"),
+        ("[paper]\nsize = \"A4\"\n", "paper"),
+    ] {
+        let err = RawConfig::parse(text).unwrap_err();
+        let ConfigError::Toml(e) = &err else {
+            panic!("expected a TOML error, got {err:?}");
+        };
+        assert!(
+            e.message()
+                .starts_with(&format!("unknown field `{unknown}`")),
+            "{}",
+            e.message()
+        );
+        assert!(err
+            .to_string()
+            .starts_with("invalid config file: unknown field"));
+    }
+}
+
+#[test]
+fn rejects_values_of_the_wrong_type() {
+    let err = RawConfig::parse("[display]\nx = \"640\"\n").unwrap_err();
+    assert!(matches!(err, ConfigError::Toml(_)), "{err:?}");
+}
+
+#[test]
+fn a_missing_file_is_an_io_error() {
+    let errors = Config::load(&fixture("no_such_config.toml")).unwrap_err();
+    assert!(matches!(&errors.0[..], [ConfigError::Io(_)]), "{errors:?}");
+}
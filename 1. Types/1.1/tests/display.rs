@@ -0,0 +1,195 @@
+use item_1_1::display::DEFAULT_MAX_RESOLUTION;
+use item_1_1::{
+    BuildError, Color, DisplayProps, DisplayPropsBuilder, DisplayPropsMutBuilder,
+    DisplayPropsPatch, RgbColor,
+};
+
+const ORANGE: RgbColor = RgbColor(255, 128, 0);
+
+#[test]
+fn fully_specified() {
+    let expected = DisplayProps {
+        x: 640,
+        y: 480,
+        color: Color::Foreground(ORANGE),
+    };
+    let consuming = DisplayPropsBuilder::new()
+        .x(640)
+        .y(480)
+        .fg_color(ORANGE)
+        .max_resolution(1024, 768)
+        .build();
+    assert_eq!(consuming, Ok(expected));
+
+    let mut builder = DisplayPropsMutBuilder::new();
+    builder
+        .x(640)
+        .y(480)
+        .fg_color(ORANGE)
+        .max_resolution(1024, 768);
+    assert_eq!(builder.build(), Ok(expected));
+}
+
+#[test]
+fn color_defaults_to_monochrome() {
+    let props = DisplayProps::builder().x(1).y(2).build().unwrap();
+    assert_eq!(props.color, Color::Monochrome);
+    assert_eq!(
+        DisplayPropsMutBuilder::new()
+            .x(1)
+            .y(2)
+            .build()
+            .unwrap()
+            .color,
+        Color::Monochrome
+    );
+    // Setting a color and then asking for monochrome leaves it monochrome.
+    let props = DisplayProps::builder()
+        .x(1)
+        .y(2)
+        .fg_color(ORANGE)
+        .monochrome()
+        .build()
+        .unwrap();
+    assert_eq!(props.color, Color::Monochrome);
+}
+
+#[test]
+fn resolution_defaults_to_4k() {
+    let (width, height) = DEFAULT_MAX_RESOLUTION;
+    assert!(DisplayProps::builder()
+        .x(width - 1)
+        .y(height - 1)
+        .build()
+        .is_ok());
+    assert_eq!(
+        DisplayProps::builder().x(width).y(0).build(),
+        Err(BuildError::OutOfBounds {
+            field: "x",
+            value: width,
+            max: width
+        })
+    );
+}
+
+#[test]
+fn missing_fields() {
+    assert_eq!(
+        DisplayPropsBuilder::new().y(1).build(),
+        Err(BuildError::MissingField("x"))
+    );
+    assert_eq!(
+        DisplayPropsBuilder::new().x(1).build(),
+        Err(BuildError::MissingField("y"))
+    );
+    assert_eq!(
+        DisplayPropsMutBuilder::new().build(),
+        Err(BuildError::MissingField("x"))
+    );
+}
+
+#[test]
+fn out_of_bounds() {
+    assert_eq!(
+        DisplayPropsBuilder::new()
+            .max_resolution(800, 600)
+            .x(800)
+            .y(0)
+            .build(),
+        Err(BuildError::OutOfBounds {
+            field: "x",
+            value: 800,
+            max: 800
+        })
+    );
+    let mut builder = DisplayPropsMutBuilder::new();
+    builder.max_resolution(800, 600).x(799).y(600);
+    assert_eq!(
+        builder.build(),
+        Err(BuildError::OutOfBounds {
+            field: "y",
+            value: 600,
+            max: 600
+        })
+    );
+    // A `&mut self` builder can be fixed up and built again.
+    builder.y(599);
+    assert_eq!(builder.build().map(|p| (p.x, p.y)), Ok((799, 599)));
+}
+
+#[test]
+fn errors_display() {
+    assert_eq!(BuildError::MissingField("y").to_string(), "y is required");
+    assert_eq!(
+        BuildError::OutOfBounds {
+            field: "x",
+            value: 900,
+            max: 800
+        }
+        .to_string(),
+        "x 900 is off the screen (must be below 800)"
+    );
+}
+
+#[test]
+fn defaults() {
+    let props = DisplayProps::default();
+    assert_eq!((props.x, props.y, props.color), (0, 0, Color::Monochrome));
+    assert_eq!(
+        DisplayProps::with_position(3, 4),
+        DisplayProps {
+            x: 3,
+            y: 4,
+            color: Color::Monochrome
+        }
+    );
+    assert_eq!(DisplayPropsPatch::default().x, None);
+}
+
+#[test]
+fn merge_only_changes_what_the_patch_sets() {
+    let props = DisplayProps::with_position(10, 20);
+    let patch = DisplayPropsPatch {
+        y: Some(99),
+        color: Some(Color::Foreground(ORANGE)),
+        ..Default::default()
+    };
+    assert_eq!(
+        props.merge(patch),
+        DisplayProps {
+            x: 10,
+            y: 99,
+            color: Color::Foreground(ORANGE)
+        }
+    );
+    // The original is borrowed, not changed, and an empty patch changes nothing.
+    assert_eq!(props, DisplayProps::with_position(10, 20));
+    assert_eq!(props.merge(DisplayPropsPatch::default()), props);
+}
+
+#[test]
+fn patches_compose() {
+    let props = DisplayProps::with_position(1, 2);
+    let first = DisplayPropsPatch {
+        x: Some(5),
+        color: Some(Color::Foreground(ORANGE)),
+        ..Default::default()
+    };
+    let second = DisplayPropsPatch {
+        x: Some(7),
+        y: Some(8),
+        ..Default::default()
+    };
+    let twice = props.merge(first).merge(second);
+    assert_eq!(
+        twice,
+        DisplayProps {
+            x: 7,
+            y: 8,
+            color: Color::Foreground(ORANGE)
+        }
+    );
+    assert_eq!(props.merge(first.then(second)), twice);
+    // Applying a patch again to its own result changes nothing more.
+    assert_eq!(twice.merge(second), twice);
+}
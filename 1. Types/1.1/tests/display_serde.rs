@@ -0,0 +1,43 @@
+use item_1_1::{Color, DisplayProps, RgbColor};
+
+#[test]
+fn every_color_round_trips() {
+    for color in [Color::Monochrome, Color::Foreground(RgbColor(255, 128, 0))] {
+        let json = serde_json::to_string(&color).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Color>(&json).unwrap(),
+            color,
+            "{json}"
+        );
+    }
+}
+
+#[test]
+fn display_props_round_trip() {
+    let props = DisplayProps {
+        x: 640,
+        y: 480,
+        color: Color::Foreground(RgbColor(1, 2, 3)),
+    };
+    let json = serde_json::to_string(&props).unwrap();
+    assert_eq!(
+        json,
+        r#"{"x":640,"y":480,"color":{"kind":"Foreground","rgb":[1,2,3]}}"#
+    );
+    assert_eq!(serde_json::from_str::<DisplayProps>(&json).unwrap(), props);
+
+    let mono: DisplayProps =
+        serde_json::from_str(r#"{"x": 1, "y": 2, "color": {"kind": "Monochrome"}}"#).unwrap();
+    assert_eq!(mono.color, Color::Monochrome);
+}
+
+#[test]
+fn malformed_json() {
+    let err = serde_json::from_str::<DisplayProps>(
+        r#"{"x": -1, "y": 2, "color": {"kind": "Monochrome"}}"#,
+    )
+    .unwrap_err();
+    assert!(err.is_data());
+    let err = serde_json::from_str::<Color>(r#"{"kind": "Sepia"}"#).unwrap_err();
+    assert!(err.to_string().contains("unknown variant `Sepia`"));
+}
@@ -0,0 +1,231 @@
+use item_1_1::{
+    replay, CpuId, EventLog, Job, JobId, ReplayError, Scheduler, SchedulerError, SchedulerEvent,
+    SchedulerState,
+};
+use std::collections::BTreeMap;
+
+// Every field of a job, which `Job`'s `PartialEq` ignores for all but the id.
+type JobContents = (JobId, String, u8, u64);
+
+fn contents(job: &Job) -> JobContents {
+    (job.id, job.payload.clone(), job.priority, job.submitted_at)
+}
+
+// What a state holds, job for job: the pending jobs by id, then the jobs on each CPU in the
+// order they were assigned, by CPU.
+fn state_contents(state: &SchedulerState) -> (Vec<JobContents>, Vec<(CpuId, Vec<JobContents>)>) {
+    let mut pending: Vec<_> = state.pending().map(contents).collect();
+    pending.sort();
+    let mut running: Vec<_> = match state {
+        SchedulerState::Running { running, .. } => running
+            .iter()
+            .map(|(&cpu, jobs)| (cpu, jobs.iter().map(contents).collect()))
+            .collect(),
+        _ => Vec::new(),
+    };
+    running.sort();
+    (pending, running)
+}
+
+#[test]
+fn replaying_the_log_rebuilds_the_state() {
+    // The same after every step, not just at the end, down to each job's payload and priority.
+    let replays = |s: &Scheduler| {
+        let replayed = replay(s.log().events()).unwrap();
+        assert_eq!(replayed, s.snapshot());
+        assert_eq!(state_contents(&replayed), state_contents(s.state()));
+    };
+    let mut scheduler = Scheduler::new();
+    scheduler.submit(Job::new(1, "compile")).unwrap();
+    replays(&scheduler);
+    scheduler.submit(Job::new(2, "test")).unwrap();
+    replays(&scheduler);
+    scheduler.assign(JobId(1), CpuId(0)).unwrap();
+    replays(&scheduler);
+    scheduler
+        .submit(Job::new(3, "lint").with_priority(2))
+        .unwrap();
+    replays(&scheduler);
+    scheduler.assign(JobId(3), CpuId(0)).unwrap();
+    replays(&scheduler);
+    scheduler.assign(JobId(2), CpuId(1)).unwrap();
+    replays(&scheduler);
+    scheduler.complete(CpuId(0), JobId(1)).unwrap();
+    replays(&scheduler);
+    scheduler.complete(CpuId(1), JobId(2)).unwrap();
+    replays(&scheduler);
+    assert_eq!(scheduler.log().len(), 8);
+    let running: Vec<_> = scheduler
+        .state()
+        .running_on(CpuId(0))
+        .iter()
+        .map(contents)
+        .collect();
+    assert_eq!(running, [(JobId(3), "lint".to_owned(), 2, 0)]);
+    assert_eq!(scheduler.state().pending().count(), 0);
+}
+
+#[test]
+fn an_empty_log_is_an_empty_scheduler() {
+    assert_eq!(replay(&[]), Ok(SchedulerState::Insert));
+    let scheduler = Scheduler::from_log(EventLog::new()).unwrap();
+    assert!(scheduler.log().is_empty());
+    assert_eq!(scheduler.snapshot(), SchedulerState::Insert);
+}
+
+#[test]
+fn a_snapshot_stays_as_it_was() {
+    let mut scheduler = Scheduler::new();
+    scheduler.submit(Job::new(1, "compile")).unwrap();
+    let before = scheduler.snapshot();
+    scheduler.assign(JobId(1), CpuId(0)).unwrap();
+    assert!(matches!(before, SchedulerState::Pending(_)));
+    assert_ne!(before, scheduler.snapshot());
+    // It is what the first event alone replays to.
+    assert_eq!(replay(&scheduler.log().events()[..1]), Ok(before));
+}
+
+#[test]
+fn refused_changes_are_not_logged() {
+    let mut scheduler = Scheduler::new();
+    scheduler.submit(Job::new(1, "compile")).unwrap();
+    assert_eq!(
+        scheduler.submit(Job::new(1, "again")),
+        Err(SchedulerError::DuplicateJob(JobId(1)))
+    );
+    assert_eq!(
+        scheduler.assign(JobId(2), CpuId(0)),
+        Err(SchedulerError::NotPending(JobId(2)))
+    );
+    assert!(scheduler.complete(CpuId(0), JobId(1)).is_err());
+    assert_eq!(
+        scheduler.log().events(),
+        [SchedulerEvent::Submitted(Job::new(1, "compile"))]
+    );
+}
+
+#[test]
+fn recovers_and_carries_on() {
+    let mut first = Scheduler::new();
+    first.submit(Job::new(1, "compile")).unwrap();
+    first.assign(JobId(1), CpuId(2)).unwrap();
+
+    let mut recovered = Scheduler::from_log(first.log().clone()).unwrap();
+    assert_eq!(
+        state_contents(recovered.state()),
+        state_contents(first.state())
+    );
+    assert_eq!(recovered.state().cpu_of(JobId(1)), Some(CpuId(2)));
+    recovered.complete(CpuId(2), JobId(1)).unwrap();
+    assert_eq!(recovered.log().len(), 3);
+    // CPU 2 is still known, with nothing left on it.
+    assert_eq!(
+        recovered.state().load_report(),
+        BTreeMap::from([(CpuId(2), 0)])
+    );
+    assert_eq!(recovered.state().pending().count(), 0);
+}
+
+#[test]
+fn rejects_completion_before_assignment() {
+    let events = [
+        SchedulerEvent::Submitted(Job::new(1, "compile")),
+        SchedulerEvent::Completed(JobId(1)),
+        SchedulerEvent::Assigned(JobId(1), CpuId(0)),
+    ];
+    assert_eq!(
+        replay(&events),
+        Err(ReplayError::NotRunning {
+            index: 1,
+            job: JobId(1)
+        })
+    );
+}
+
+#[test]
+fn rejects_a_duplicate_submission() {
+    let events = [
+        SchedulerEvent::Submitted(Job::new(1, "compile")),
+        SchedulerEvent::Assigned(JobId(1), CpuId(0)),
+        SchedulerEvent::Submitted(Job::new(1, "compile again")),
+    ];
+    assert_eq!(
+        replay(&events),
+        Err(ReplayError::DuplicateSubmission {
+            index: 2,
+            job: JobId(1)
+        })
+    );
+    // Once it has completed, the id can be used again.
+    let events = [
+        SchedulerEvent::Submitted(Job::new(1, "compile")),
+        SchedulerEvent::Assigned(JobId(1), CpuId(0)),
+        SchedulerEvent::Completed(JobId(1)),
+        SchedulerEvent::Submitted(Job::new(1, "compile again")),
+    ];
+    let state = replay(&events).unwrap();
+    assert_eq!(
+        state.pending().map(|job| job.id).collect::<Vec<_>>(),
+        [JobId(1)]
+    );
+}
+
+#[test]
+fn rejects_assigning_what_is_not_pending() {
+    let never_submitted = [SchedulerEvent::Assigned(JobId(4), CpuId(0))];
+    assert_eq!(
+        replay(&never_submitted),
+        Err(ReplayError::NotPending {
+            index: 0,
+            job: JobId(4)
+        })
+    );
+    let assigned_twice: EventLog = [
+        SchedulerEvent::Submitted(Job::new(1, "compile")),
+        SchedulerEvent::Assigned(JobId(1), CpuId(0)),
+        SchedulerEvent::Assigned(JobId(1), CpuId(1)),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(
+        Scheduler::from_log(assigned_twice).unwrap_err(),
+        ReplayError::NotPending {
+            index: 2,
+            job: JobId(1)
+        }
+    );
+}
+
+#[test]
+fn rejects_completing_twice() {
+    let events = [
+        SchedulerEvent::Submitted(Job::new(1, "compile")),
+        SchedulerEvent::Assigned(JobId(1), CpuId(0)),
+        SchedulerEvent::Completed(JobId(1)),
+        SchedulerEvent::Completed(JobId(1)),
+    ];
+    assert_eq!(
+        replay(&events),
+        Err(ReplayError::NotRunning {
+            index: 3,
+            job: JobId(1)
+        })
+    );
+}
+
+#[test]
+fn replay_errors_display() {
+    let job = JobId(7);
+    assert_eq!(
+        ReplayError::DuplicateSubmission { index: 2, job }.to_string(),
+        "event 2: job 7 is submitted a second time"
+    );
+    assert_eq!(
+        ReplayError::NotPending { index: 0, job }.to_string(),
+        "event 0: job 7 is assigned but not pending"
+    );
+    assert_eq!(
+        ReplayError::NotRunning { index: 5, job }.to_string(),
+        "event 5: job 7 completes but is not running"
+    );
+}
@@ -0,0 +1,68 @@
+use item_1_1::{CpuId, EventLog, Job, JobId, ReplayError, Scheduler, SchedulerEvent};
+
+fn busy_scheduler() -> Scheduler {
+    let mut scheduler = Scheduler::new();
+    scheduler
+        .submit(Job::new(1, "compile").with_priority(3))
+        .unwrap();
+    scheduler.submit(Job::new(2, "test")).unwrap();
+    scheduler.assign(JobId(1), CpuId(0)).unwrap();
+    scheduler.assign(JobId(2), CpuId(1)).unwrap();
+    scheduler.complete(CpuId(0), JobId(1)).unwrap();
+    scheduler
+}
+
+#[test]
+fn events_serialize_as_tagged_values() {
+    let log: EventLog = [
+        SchedulerEvent::Submitted(Job::new(1, "compile")),
+        SchedulerEvent::Assigned(JobId(1), CpuId(3)),
+        SchedulerEvent::Completed(JobId(1)),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(
+        serde_json::to_string(&log).unwrap(),
+        r#"[{"Submitted":{"id":1,"payload":"compile","priority":0,"submitted_at":0}},{"Assigned":[1,3]},{"Completed":1}]"#
+    );
+}
+
+#[test]
+fn recovers_from_a_saved_log() {
+    let scheduler = busy_scheduler();
+    let json = serde_json::to_string_pretty(scheduler.log()).unwrap();
+
+    let log: EventLog = serde_json::from_str(&json).unwrap();
+    assert_eq!(&log, scheduler.log());
+    let recovered = Scheduler::from_log(log).unwrap();
+    assert_eq!(recovered.snapshot(), scheduler.snapshot());
+    // Jobs compare by id alone, so check the rest came back too.
+    let SchedulerEvent::Submitted(job) = &recovered.log().events()[0] else {
+        panic!("expected a submission first");
+    };
+    assert_eq!((job.payload.as_str(), job.priority), ("compile", 3));
+}
+
+#[test]
+fn a_corrupted_log_is_rejected() {
+    let json = serde_json::to_string(busy_scheduler().log()).unwrap();
+    // Lose the record of job 1 being assigned, as a torn write might, so that it completes
+    // without ever having run.
+    let assigned = r#"{"Assigned":[1,0]},"#;
+    assert!(json.contains(assigned), "{json}");
+    let log: EventLog = serde_json::from_str(&json.replace(assigned, "")).unwrap();
+    assert_eq!(
+        Scheduler::from_log(log).unwrap_err(),
+        ReplayError::NotRunning {
+            index: 3,
+            job: JobId(1)
+        }
+    );
+}
+
+#[test]
+fn a_log_that_isnt_json_is_a_serde_error() {
+    let json = serde_json::to_string(busy_scheduler().log()).unwrap();
+    let truncated = &json[..json.len() / 2];
+    assert!(serde_json::from_str::<EventLog>(truncated).is_err());
+}
@@ -0,0 +1,62 @@
+use item_1_1::{HttpResultCode, UnknownStatusCode};
+
+#[test]
+fn every_variant_round_trips_through_its_code() {
+    for variant in HttpResultCode::ALL {
+        assert_eq!(HttpResultCode::try_from(variant as u16), Ok(variant));
+        assert_eq!(variant.code(), variant as u16);
+    }
+}
+
+#[test]
+fn the_codes_are_the_standard_ones() {
+    assert_eq!(HttpResultCode::Unauthorized as i32, 401);
+    assert_eq!(HttpResultCode::Forbidden as i32, 403);
+    let codes: Vec<u16> = HttpResultCode::ALL.iter().map(|c| c.code()).collect();
+    assert_eq!(codes, [200, 201, 204, 301, 400, 401, 403, 404, 500, 503]);
+}
+
+#[test]
+fn unknown_codes_are_errors() {
+    for code in [0, 100, 202, 302, 418, 502, 999, u16::MAX] {
+        assert_eq!(HttpResultCode::try_from(code), Err(UnknownStatusCode(code)));
+    }
+    assert_eq!(
+        UnknownStatusCode(418).to_string(),
+        "unknown HTTP status code 418"
+    );
+}
+
+#[test]
+fn display_is_the_reason_phrase() {
+    assert_eq!(HttpResultCode::Ok.to_string(), "OK");
+    assert_eq!(HttpResultCode::NoContent.to_string(), "No Content");
+    assert_eq!(HttpResultCode::Unauthorized.to_string(), "Unauthorized");
+    assert_eq!(HttpResultCode::Forbidden.to_string(), "Forbidden");
+    assert_eq!(
+        HttpResultCode::InternalServerError.to_string(),
+        "Internal Server Error"
+    );
+}
+
+#[test]
+fn categories() {
+    for variant in HttpResultCode::ALL {
+        let categories = [
+            variant.is_success(),
+            variant.is_redirection(),
+            variant.is_client_error(),
+            variant.is_server_error(),
+        ];
+        assert_eq!(
+            categories.iter().filter(|&&c| c).count(),
+            1,
+            "{variant:?} should be in exactly one category"
+        );
+    }
+    assert!(HttpResultCode::Created.is_success());
+    assert!(HttpResultCode::MovedPermanently.is_redirection());
+    assert!(HttpResultCode::NotFound.is_client_error());
+    assert!(!HttpResultCode::NotFound.is_server_error());
+    assert!(HttpResultCode::ServiceUnavailable.is_server_error());
+}
@@ -0,0 +1,117 @@
+use item_1_1::newtypes::{pin_to_cpu, pin_to_cpu_unchecked};
+use item_1_1::{CpuId, IdError, UserId};
+
+#[test]
+fn converts_in_range_values() {
+    assert_eq!(UserId::try_from(0), Ok(UserId(0)));
+    assert_eq!(UserId::try_from(1000), Ok(UserId(1000)));
+    assert_eq!(UserId::try_from(i64::from(u32::MAX)), Ok(UserId(u32::MAX)));
+    assert_eq!(CpuId::try_from(0), Ok(CpuId(0)));
+    assert_eq!(CpuId::try_from(i64::from(u32::MAX)), Ok(CpuId(u32::MAX)));
+}
+
+#[test]
+fn rejects_negative_values() {
+    for value in [-1, i64::MIN] {
+        assert_eq!(
+            UserId::try_from(value),
+            Err(IdError::Negative {
+                id: "user id",
+                value
+            })
+        );
+        assert_eq!(
+            CpuId::try_from(value),
+            Err(IdError::Negative {
+                id: "cpu id",
+                value
+            })
+        );
+    }
+}
+
+#[test]
+fn rejects_values_too_large() {
+    for value in [i64::from(u32::MAX) + 1, i64::MAX] {
+        assert_eq!(
+            UserId::try_from(value),
+            Err(IdError::TooLarge {
+                id: "user id",
+                value
+            })
+        );
+        assert_eq!(
+            CpuId::try_from(value),
+            Err(IdError::TooLarge {
+                id: "cpu id",
+                value
+            })
+        );
+    }
+}
+
+#[test]
+fn parses_from_strings() {
+    assert_eq!("0".parse(), Ok(CpuId(0)));
+    assert_eq!(" 42\n".parse(), Ok(UserId(42)));
+    assert_eq!("4294967295".parse(), Ok(UserId(u32::MAX)));
+    assert_eq!(
+        "-1".parse::<CpuId>(),
+        Err(IdError::Negative {
+            id: "cpu id",
+            value: -1
+        })
+    );
+    assert_eq!(
+        "4294967296".parse::<UserId>(),
+        Err(IdError::TooLarge {
+            id: "user id",
+            value: 4_294_967_296
+        })
+    );
+    for input in ["", "cpu0", "1.5", "99999999999999999999"] {
+        assert_eq!(
+            input.parse::<CpuId>(),
+            Err(IdError::Invalid {
+                id: "cpu id",
+                input: input.to_owned()
+            })
+        );
+    }
+}
+
+#[test]
+fn display_round_trips_through_from_str() {
+    for value in [0, 7, u32::MAX] {
+        assert_eq!(UserId(value).to_string().parse(), Ok(UserId(value)));
+        assert_eq!(CpuId(value).to_string().parse(), Ok(CpuId(value)));
+    }
+}
+
+#[test]
+fn errors_display() {
+    assert_eq!(
+        CpuId::try_from(-2).unwrap_err().to_string(),
+        "cpu id -2 is negative"
+    );
+    assert_eq!(
+        UserId::try_from(1 << 32).unwrap_err().to_string(),
+        "user id 4294967296 is larger than 4294967295"
+    );
+    assert_eq!(
+        "abc".parse::<UserId>().unwrap_err().to_string(),
+        "user id \"abc\" is not a number from 0 to 4294967295"
+    );
+}
+
+#[test]
+fn pinning() {
+    assert_eq!(
+        pin_to_cpu(UserId(1000), CpuId(3)),
+        "user 1000 pinned to cpu 3"
+    );
+    assert_eq!(
+        pin_to_cpu_unchecked(1000, 3),
+        pin_to_cpu(UserId(1000), CpuId(3))
+    );
+}
@@ -0,0 +1,128 @@
+use item_1_1::printing::{output_name, paper_name, strip_color, Face, Page, PaperSize, Printer};
+use item_1_1::{Output, Sides};
+
+#[test]
+fn output_names() {
+    assert_eq!(output_name(Output::Color), "color");
+    assert_eq!(output_name(Output::BlackAndWhite), "black_and_white");
+}
+
+#[test]
+fn paper_names() {
+    assert_eq!(paper_name(PaperSize::A4), "A4");
+    assert_eq!(paper_name(PaperSize::Letter), "US Letter");
+}
+
+#[test]
+fn non_exhaustive_enums_need_a_wildcard_outside_their_crate() {
+    // Without the `_` arm this wouldn't compile, as this test is a crate of its own.
+    fn is_metric(size: PaperSize) -> Option<bool> {
+        match size {
+            PaperSize::A4 => Some(true),
+            PaperSize::Letter => Some(false),
+            _ => None,
+        }
+    }
+    assert_eq!(is_metric(PaperSize::A4), Some(true));
+    assert_eq!(is_metric(PaperSize::Letter), Some(false));
+}
+
+const TEXT: &str = "{#ff0000}one{/}\ntwo\nthree\n{#00ff00}four{/}\nfive";
+
+fn lines(page: &Page) -> Vec<&str> {
+    page.lines.iter().map(String::as_str).collect()
+}
+
+#[test]
+fn empty_input_prints_nothing() {
+    let printer = Printer::new(3);
+    for sides in [Sides::Single, Sides::Both] {
+        for output in [Output::Color, Output::BlackAndWhite] {
+            assert_eq!(printer.print("", sides, output), []);
+        }
+    }
+}
+
+#[test]
+fn single_sided_in_color() {
+    let pages = Printer::new(2).print(TEXT, Sides::Single, Output::Color);
+    assert_eq!(pages.len(), 3);
+    assert_eq!(lines(&pages[0]), ["{#ff0000}one{/}", "two"]);
+    assert_eq!(lines(&pages[1]), ["three", "{#00ff00}four{/}"]);
+    // The final page is partial.
+    assert_eq!(lines(&pages[2]), ["five"]);
+    assert!(pages.iter().all(|p| p.face.is_none()));
+    let numbers: Vec<usize> = pages.iter().map(|p| p.number).collect();
+    assert_eq!(numbers, [1, 2, 3]);
+}
+
+#[test]
+fn single_sided_in_black_and_white() {
+    let pages = Printer::new(2).print(TEXT, Sides::Single, Output::BlackAndWhite);
+    assert_eq!(pages.len(), 3);
+    assert_eq!(lines(&pages[0]), ["one", "two"]);
+    assert_eq!(lines(&pages[1]), ["three", "four"]);
+    assert_eq!(lines(&pages[2]), ["five"]);
+}
+
+#[test]
+fn double_sided_in_color() {
+    let pages = Printer::new(2).print(TEXT, Sides::Both, Output::Color);
+    // Three pages of text, and a blank back to the second sheet.
+    assert_eq!(pages.len(), 4);
+    let faces: Vec<_> = pages.iter().map(|p| p.face).collect();
+    assert_eq!(
+        faces,
+        [
+            Some(Face::Front),
+            Some(Face::Back),
+            Some(Face::Front),
+            Some(Face::Back)
+        ]
+    );
+    assert_eq!(lines(&pages[0]), ["{#ff0000}one{/}", "two"]);
+    assert_eq!(lines(&pages[2]), ["five"]);
+    assert_eq!(
+        pages[3],
+        Page {
+            number: 4,
+            face: Some(Face::Back),
+            lines: Vec::new()
+        }
+    );
+}
+
+#[test]
+fn double_sided_in_black_and_white() {
+    // An even number of pages needs no blank.
+    let pages = Printer::new(3).print(TEXT, Sides::Both, Output::BlackAndWhite);
+    assert_eq!(pages.len(), 2);
+    assert_eq!(lines(&pages[0]), ["one", "two", "three"]);
+    assert_eq!(lines(&pages[1]), ["four", "five"]);
+    assert_eq!(pages[1].face, Some(Face::Back));
+}
+
+#[test]
+fn exactly_full_pages() {
+    let pages = Printer::new(5).print(TEXT, Sides::Single, Output::Color);
+    assert_eq!(pages.len(), 1);
+    assert_eq!(pages[0].lines.len(), 5);
+    assert_eq!(Printer::new(5).lines_per_page(), 5);
+}
+
+#[test]
+#[should_panic(expected = "a page must hold at least one line")]
+fn pages_hold_at_least_one_line() {
+    Printer::new(0);
+}
+
+#[test]
+fn strips_only_color_markup() {
+    assert_eq!(strip_color("{#A0b1C2}hi{/} there"), "hi there");
+    assert_eq!(strip_color("no markup"), "no markup");
+    // Not color tags: left as they are.
+    assert_eq!(
+        strip_color("{name} {#12345} {#12345g} {"),
+        "{name} {#12345} {#12345g} {"
+    );
+}
@@ -0,0 +1,127 @@
+use item_1_1::{Job, JobId, PriorityScheduler, SchedulerError};
+use std::cmp::Ordering;
+
+fn drain(scheduler: &mut PriorityScheduler) -> Vec<u32> {
+    std::iter::from_fn(|| scheduler.next_job())
+        .map(|job| job.id.0)
+        .collect()
+}
+
+#[test]
+fn higher_priority_first() {
+    let mut scheduler = PriorityScheduler::new();
+    scheduler
+        .submit(Job::new(1, "low").with_priority(1))
+        .unwrap();
+    scheduler
+        .submit(Job::new(2, "high").with_priority(9))
+        .unwrap();
+    scheduler
+        .submit(Job::new(3, "mid").with_priority(5))
+        .unwrap();
+    assert_eq!(scheduler.len(), 3);
+    assert_eq!(scheduler.peek().map(|job| job.id), Some(JobId(2)));
+    assert_eq!(drain(&mut scheduler), [2, 3, 1]);
+    assert!(scheduler.is_empty());
+    assert!(scheduler.next_job().is_none());
+}
+
+#[test]
+fn ties_are_first_in_first_out() {
+    let mut scheduler = PriorityScheduler::new();
+    // Ids in the opposite order to submission, so that the id can't be what decides.
+    for id in (1..=5).rev() {
+        scheduler.submit(Job::new(id, "same")).unwrap();
+    }
+    scheduler
+        .submit(Job::new(10, "urgent").with_priority(1))
+        .unwrap();
+    assert_eq!(drain(&mut scheduler), [10, 5, 4, 3, 2, 1]);
+}
+
+#[test]
+fn submit_stamps_arrival() {
+    let mut scheduler = PriorityScheduler::new();
+    let mut job = Job::new(1, "late");
+    job.submitted_at = 1_000;
+    scheduler.submit(job).unwrap();
+    scheduler.submit(Job::new(2, "later")).unwrap();
+    let stamps: Vec<_> = std::iter::from_fn(|| scheduler.next_job())
+        .map(|job| job.submitted_at)
+        .collect();
+    assert_eq!(stamps, [0, 1]);
+}
+
+#[test]
+fn duplicate_ids_are_refused() {
+    let mut scheduler = PriorityScheduler::new();
+    scheduler.submit(Job::new(1, "build")).unwrap();
+    assert_eq!(
+        scheduler.submit(Job::new(1, "again").with_priority(9)),
+        Err(SchedulerError::DuplicateJob(JobId(1)))
+    );
+    assert_eq!(scheduler.len(), 1);
+    // Once taken, the id is free again.
+    scheduler.next_job().unwrap();
+    scheduler.submit(Job::new(1, "again")).unwrap();
+}
+
+#[test]
+fn reprioritize_reorders() {
+    let mut scheduler = PriorityScheduler::new();
+    scheduler.submit(Job::new(1, "a").with_priority(5)).unwrap();
+    scheduler.submit(Job::new(2, "b").with_priority(3)).unwrap();
+    scheduler.submit(Job::new(3, "c").with_priority(1)).unwrap();
+
+    scheduler.reprioritize(JobId(3), 8).unwrap();
+    assert_eq!(scheduler.peek().map(|job| job.id), Some(JobId(3)));
+    // Raised to the same priority as job 1, job 2 still comes after it, having arrived later.
+    scheduler.reprioritize(JobId(2), 5).unwrap();
+    assert_eq!(drain(&mut scheduler), [3, 1, 2]);
+}
+
+#[test]
+fn reprioritize_missing_job() {
+    let mut scheduler = PriorityScheduler::new();
+    scheduler.submit(Job::new(1, "a")).unwrap();
+    assert_eq!(
+        scheduler.reprioritize(JobId(2), 1),
+        Err(SchedulerError::NotPending(JobId(2)))
+    );
+    assert_eq!(drain(&mut scheduler), [1]);
+}
+
+#[test]
+fn ord_agrees_with_eq_and_partial_ord() {
+    let stamped = |id, priority, submitted_at| Job {
+        submitted_at,
+        ..Job::new(id, "job").with_priority(priority)
+    };
+    let jobs = [
+        stamped(1, 0, 0),
+        stamped(2, 0, 1),
+        stamped(3, 5, 2),
+        stamped(4, 5, 2),
+        stamped(5, 9, 3),
+    ];
+    for a in &jobs {
+        for b in &jobs {
+            assert_eq!(a.partial_cmp(b), Some(a.cmp(b)));
+            assert_eq!(a.cmp(b) == Ordering::Equal, a == b);
+            assert_eq!(a.cmp(b), b.cmp(a).reverse());
+        }
+    }
+    assert!(jobs[0] > jobs[1], "earlier submission wins a tie");
+    assert!(jobs[2] > jobs[1], "higher priority wins");
+    assert!(jobs[2] > jobs[3], "lower id breaks a full tie");
+    let mut sorted = jobs.to_vec();
+    sorted.sort();
+    let ids: Vec<_> = sorted.iter().map(|job| job.id.0).collect();
+    assert_eq!(ids, [2, 1, 4, 3, 5]);
+
+    // The same job is equal to itself even with its priority changed.
+    assert_eq!(
+        jobs[0].cmp(&jobs[0].clone().with_priority(7)),
+        Ordering::Equal
+    );
+}
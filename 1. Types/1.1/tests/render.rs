@@ -0,0 +1,73 @@
+use item_1_1::render::{foreground, move_to, styled, Canvas};
+use item_1_1::{Color, ColorRangeError, DisplayProps, RgbColor};
+
+fn at(x: u32, y: u32, color: Color) -> DisplayProps {
+    DisplayProps { x, y, color }
+}
+
+#[test]
+fn foreground_escapes() {
+    assert_eq!(foreground(RgbColor(255, 0, 0)), "\x1b[38;2;255;0;0m");
+    assert_eq!(foreground(RgbColor(0, 128, 255)), "\x1b[38;2;0;128;255m");
+    assert_eq!(
+        foreground(RgbColor(0, 0, 0)).as_bytes(),
+        b"\x1b[38;2;0;0;0m"
+    );
+}
+
+#[test]
+fn styled_text() {
+    let red = at(0, 0, Color::Foreground(RgbColor(255, 0, 0)));
+    assert_eq!(
+        styled("hi", &red).as_bytes(),
+        b"\x1b[38;2;255;0;0mhi\x1b[0m"
+    );
+    assert_eq!(styled("hi", &at(0, 0, Color::Monochrome)), "hi");
+    assert_eq!(styled("", &at(0, 0, Color::Monochrome)), "");
+}
+
+#[test]
+fn cursor_positions_are_one_based() {
+    assert_eq!(move_to(0, 0), "\x1b[1;1H");
+    assert_eq!(move_to(9, 4), "\x1b[5;10H");
+    assert_eq!(move_to(u32::MAX, 0), "\x1b[1;4294967296H");
+}
+
+#[test]
+fn canvas_flushes_in_drawing_order() {
+    let green = Color::Foreground(RgbColor(0, 255, 0));
+    let mut canvas = Canvas::new();
+    canvas
+        .draw("title", &at(2, 0, Color::Monochrome))
+        .draw("ok", &at(0, 3, green));
+    let mut out = Vec::new();
+    canvas.flush(&mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "\x1b[1;3Htitle\x1b[4;1H\x1b[38;2;0;255;0mok\x1b[0m"
+    );
+
+    // Flushing empties the canvas.
+    let mut out = Vec::new();
+    canvas.flush(&mut out).unwrap();
+    assert!(out.is_empty());
+}
+
+#[test]
+fn converts_in_range_tuples() {
+    assert_eq!(RgbColor::try_from((0, 128, 255)), Ok(RgbColor(0, 128, 255)));
+}
+
+#[test]
+fn rejects_out_of_range_tuples() {
+    assert_eq!(RgbColor::try_from((256, 0, 0)), Err(ColorRangeError(256)));
+    assert_eq!(RgbColor::try_from((0, -1, 0)), Err(ColorRangeError(-1)));
+    assert_eq!(
+        RgbColor::try_from((0, 0, i32::MAX)),
+        Err(ColorRangeError(i32::MAX))
+    );
+    assert_eq!(
+        ColorRangeError(300).to_string(),
+        "color component 300 is not between 0 and 255"
+    );
+}
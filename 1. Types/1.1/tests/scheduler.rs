@@ -0,0 +1,383 @@
+use item_1_1::{CpuId, Job, JobId, SchedulerError, SchedulerState};
+use std::collections::BTreeMap;
+
+#[test]
+fn full_lifecycle() {
+    let mut scheduler = SchedulerState::new();
+    assert_eq!(scheduler, SchedulerState::Insert);
+
+    scheduler.submit(Job::new(1, "build")).unwrap();
+    scheduler.submit(Job::new(2, "test")).unwrap();
+    assert!(matches!(&scheduler, SchedulerState::Pending(jobs) if jobs.len() == 2));
+
+    scheduler.assign(JobId(1), CpuId(0)).unwrap();
+    // Job 2 is still waiting while job 1 runs.
+    assert!(matches!(
+        &scheduler,
+        SchedulerState::Running { pending, running } if pending.len() == 1 && running.len() == 1
+    ));
+    assert_eq!(
+        scheduler.pending().map(|j| j.id).collect::<Vec<_>>(),
+        [JobId(2)]
+    );
+    scheduler.assign(JobId(2), CpuId(0)).unwrap();
+    assert_eq!(scheduler.pending().count(), 0);
+    let on_cpu: Vec<_> = scheduler
+        .running_on(CpuId(0))
+        .iter()
+        .map(|j| j.id)
+        .collect();
+    assert_eq!(on_cpu, [JobId(1), JobId(2)]);
+    assert!(scheduler.running_on(CpuId(1)).is_empty());
+
+    let done = scheduler.complete(CpuId(0), JobId(1)).unwrap();
+    assert_eq!(done.id, JobId(1));
+    assert_eq!(done.payload, "build");
+    assert!(!scheduler.contains(JobId(1)));
+    assert!(scheduler.contains(JobId(2)));
+
+    let done = scheduler.complete(CpuId(0), JobId(2)).unwrap();
+    assert_eq!(done.payload, "test");
+    // Nothing is left, but CPU 0 is still known.
+    assert_eq!(scheduler.pending().count(), 0);
+    assert_eq!(scheduler.load_report(), BTreeMap::from([(CpuId(0), 0)]));
+}
+
+#[test]
+fn completing_the_last_running_job_leaves_the_rest_pending() {
+    let mut scheduler = SchedulerState::new();
+    scheduler.submit(Job::new(1, "a")).unwrap();
+    scheduler.submit(Job::new(2, "b")).unwrap();
+    scheduler.assign(JobId(1), CpuId(3)).unwrap();
+    scheduler.complete(CpuId(3), JobId(1)).unwrap();
+    assert!(matches!(
+        &scheduler,
+        SchedulerState::Running { pending, running }
+            if pending.len() == 1 && running[&CpuId(3)].is_empty()
+    ));
+    // A completed id can be used again.
+    scheduler.submit(Job::new(1, "a again")).unwrap();
+}
+
+#[test]
+fn duplicate_submissions_are_rejected() {
+    let mut scheduler = SchedulerState::new();
+    scheduler.submit(Job::new(1, "a")).unwrap();
+    assert_eq!(
+        scheduler.submit(Job::new(1, "b")),
+        Err(SchedulerError::DuplicateJob(JobId(1)))
+    );
+    scheduler.assign(JobId(1), CpuId(0)).unwrap();
+    // Running counts too.
+    assert_eq!(
+        scheduler.submit(Job::new(1, "c")),
+        Err(SchedulerError::DuplicateJob(JobId(1)))
+    );
+}
+
+#[test]
+fn only_pending_jobs_can_be_assigned() {
+    let mut scheduler = SchedulerState::new();
+    assert_eq!(
+        scheduler.assign(JobId(1), CpuId(0)),
+        Err(SchedulerError::NotPending(JobId(1)))
+    );
+    assert_eq!(scheduler, SchedulerState::Insert);
+
+    scheduler.submit(Job::new(1, "a")).unwrap();
+    scheduler.assign(JobId(1), CpuId(0)).unwrap();
+    let before = scheduler.clone();
+    assert_eq!(
+        scheduler.assign(JobId(1), CpuId(1)),
+        Err(SchedulerError::NotPending(JobId(1)))
+    );
+    assert_eq!(scheduler, before);
+    assert_eq!(scheduler.running_on(CpuId(0)).len(), 1);
+}
+
+#[test]
+fn only_running_jobs_can_be_completed() {
+    let mut scheduler = SchedulerState::new();
+    assert_eq!(
+        scheduler.complete(CpuId(0), JobId(1)).unwrap_err(),
+        SchedulerError::NotRunning {
+            cpu: CpuId(0),
+            job: JobId(1)
+        }
+    );
+
+    // Pending, but not running.
+    scheduler.submit(Job::new(1, "a")).unwrap();
+    assert!(scheduler.complete(CpuId(0), JobId(1)).is_err());
+
+    // Running, but on another CPU.
+    scheduler.assign(JobId(1), CpuId(0)).unwrap();
+    assert_eq!(
+        scheduler.complete(CpuId(1), JobId(1)).unwrap_err(),
+        SchedulerError::NotRunning {
+            cpu: CpuId(1),
+            job: JobId(1)
+        }
+    );
+    assert!(scheduler.contains(JobId(1)));
+}
+
+#[test]
+fn errors_display() {
+    assert_eq!(
+        SchedulerError::DuplicateJob(JobId(4)).to_string(),
+        "job 4 has already been submitted"
+    );
+    assert_eq!(
+        SchedulerError::NotPending(JobId(4)).to_string(),
+        "job 4 is not pending"
+    );
+    assert_eq!(
+        SchedulerError::NotRunning {
+            cpu: CpuId(2),
+            job: JobId(4)
+        }
+        .to_string(),
+        "job 4 is not running on cpu 2"
+    );
+    assert_eq!(
+        SchedulerError::NoJobs(CpuId(2)).to_string(),
+        "cpu 2 has no jobs running"
+    );
+}
+
+#[test]
+fn collect_and_extend() {
+    let empty: SchedulerState = std::iter::empty().collect();
+    assert_eq!(empty, SchedulerState::Insert);
+
+    let mut scheduler: SchedulerState = (1..=3).map(|id| Job::new(id, "batch")).collect();
+    assert_eq!(scheduler.pending().count(), 3);
+    scheduler.assign(JobId(1), CpuId(0)).unwrap();
+    scheduler.extend([Job::new(4, "more"), Job::new(5, "more")]);
+    let mut pending: Vec<_> = scheduler.pending().map(|job| job.id.0).collect();
+    pending.sort();
+    assert_eq!(pending, [2, 3, 4, 5]);
+    assert_eq!(scheduler.running_on(CpuId(0)).len(), 1);
+
+    // Extending with nothing changes nothing.
+    let before = scheduler.clone();
+    scheduler.extend(std::iter::empty());
+    assert_eq!(scheduler, before);
+}
+
+#[test]
+fn try_from_iter() {
+    let scheduler = SchedulerState::try_from_iter(std::iter::empty()).unwrap();
+    assert_eq!(scheduler, SchedulerState::Insert);
+
+    let scheduler = SchedulerState::try_from_iter([Job::new(1, "a"), Job::new(2, "b")]).unwrap();
+    assert!(scheduler.contains(JobId(1)) && scheduler.contains(JobId(2)));
+
+    let jobs = [Job::new(1, "a"), Job::new(2, "b"), Job::new(1, "c")];
+    assert_eq!(
+        SchedulerState::try_from_iter(jobs),
+        Err(SchedulerError::DuplicateJob(JobId(1)))
+    );
+}
+
+#[test]
+#[should_panic(expected = "job 7 has already been submitted")]
+fn collect_panics_on_a_duplicate() {
+    let _: SchedulerState = [Job::new(7, "a"), Job::new(7, "b")].into_iter().collect();
+}
+
+#[test]
+fn extend_panics_on_a_duplicate_of_an_existing_job() {
+    let mut scheduler: SchedulerState = [Job::new(1, "a")].into_iter().collect();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        scheduler.extend([Job::new(2, "b"), Job::new(1, "again"), Job::new(3, "c")]);
+    }));
+    let message = result.unwrap_err();
+    assert_eq!(
+        message.downcast_ref::<String>().map(String::as_str),
+        Some("job 1 has already been submitted")
+    );
+    // The job before the duplicate got in; the one after it didn't.
+    assert!(scheduler.contains(JobId(2)));
+    assert!(!scheduler.contains(JobId(3)));
+}
+
+fn ids(jobs: &[Job]) -> Vec<u32> {
+    jobs.iter().map(|job| job.id.0).collect()
+}
+
+#[test]
+fn assigning_to_an_empty_scheduler_uses_cpu_0() {
+    let mut scheduler = SchedulerState::new();
+    assert_eq!(scheduler.load_report(), BTreeMap::new());
+    assert_eq!(
+        scheduler.assign_to_least_loaded(Job::new(1, "first")),
+        Ok(CpuId(0))
+    );
+    assert_eq!(scheduler.load_report(), BTreeMap::from([(CpuId(0), 1)]));
+    assert!(matches!(scheduler, SchedulerState::Running { .. }));
+    // With only CPU 0 known, that is where everything goes.
+    assert_eq!(
+        scheduler.assign_to_least_loaded(Job::new(2, "second")),
+        Ok(CpuId(0))
+    );
+    assert_eq!(ids(scheduler.running_on(CpuId(0))), [1, 2]);
+}
+
+#[test]
+fn balances_across_three_cpus() {
+    let mut scheduler: SchedulerState = (1..=3).map(|id| Job::new(id, "seed")).collect();
+    for (job, cpu) in [(1, 0), (2, 1), (3, 2)] {
+        scheduler.assign(JobId(job), CpuId(cpu)).unwrap();
+    }
+    let cpus: Vec<u32> = (4..=9)
+        .map(|id| {
+            scheduler
+                .assign_to_least_loaded(Job::new(id, "batch"))
+                .unwrap()
+                .0
+        })
+        .collect();
+    // Ties go to the lowest CPU, so they are filled in turn.
+    assert_eq!(cpus, [0, 1, 2, 0, 1, 2]);
+    assert_eq!(
+        scheduler.load_report(),
+        BTreeMap::from([(CpuId(0), 3), (CpuId(1), 3), (CpuId(2), 3)])
+    );
+
+    // After CPU 2 finishes a job, it is the least loaded.
+    scheduler.complete(CpuId(2), JobId(3)).unwrap();
+    assert_eq!(
+        scheduler.assign_to_least_loaded(Job::new(10, "next")),
+        Ok(CpuId(2))
+    );
+}
+
+#[test]
+fn an_idle_cpu_gets_the_next_job() {
+    let mut scheduler: SchedulerState = (1..=3).map(|id| Job::new(id, "seed")).collect();
+    for (job, cpu) in [(1, 0), (2, 0), (3, 1)] {
+        scheduler.assign(JobId(job), CpuId(cpu)).unwrap();
+    }
+    scheduler.complete(CpuId(1), JobId(3)).unwrap();
+    assert_eq!(
+        scheduler.load_report(),
+        BTreeMap::from([(CpuId(0), 2), (CpuId(1), 0)])
+    );
+    assert_eq!(
+        scheduler.assign_to_least_loaded(Job::new(4, "next")),
+        Ok(CpuId(1))
+    );
+    assert_eq!(ids(scheduler.running_on(CpuId(1))), [4]);
+}
+
+#[test]
+fn registered_cpus_share_the_work_from_the_start() {
+    let mut scheduler = SchedulerState::new();
+    for cpu in 0..3 {
+        scheduler.register_cpu(CpuId(cpu));
+    }
+    assert_eq!(
+        scheduler.load_report(),
+        BTreeMap::from([(CpuId(0), 0), (CpuId(1), 0), (CpuId(2), 0)])
+    );
+    let cpus: Vec<u32> = (1..=4)
+        .map(|id| {
+            scheduler
+                .assign_to_least_loaded(Job::new(id, "batch"))
+                .unwrap()
+                .0
+        })
+        .collect();
+    assert_eq!(cpus, [0, 1, 2, 0]);
+    // Registering a CPU again leaves its jobs where they are.
+    scheduler.register_cpu(CpuId(0));
+    assert_eq!(ids(scheduler.running_on(CpuId(0))), [1, 4]);
+}
+
+#[test]
+fn assigning_a_duplicate_is_rejected() {
+    let mut scheduler = SchedulerState::new();
+    scheduler.submit(Job::new(1, "waiting")).unwrap();
+    assert_eq!(
+        scheduler.assign_to_least_loaded(Job::new(1, "again")),
+        Err(SchedulerError::DuplicateJob(JobId(1)))
+    );
+    scheduler
+        .assign_to_least_loaded(Job::new(2, "running"))
+        .unwrap();
+    assert_eq!(
+        scheduler.assign_to_least_loaded(Job::new(2, "again")),
+        Err(SchedulerError::DuplicateJob(JobId(2)))
+    );
+    assert_eq!(scheduler.load_report(), BTreeMap::from([(CpuId(0), 1)]));
+    assert_eq!(scheduler.pending().count(), 1);
+}
+
+#[test]
+fn steals_the_most_recently_assigned_jobs() {
+    let mut scheduler = SchedulerState::new();
+    for id in 1..=5 {
+        scheduler
+            .assign_to_least_loaded(Job::new(id, "work"))
+            .unwrap();
+    }
+    assert_eq!(scheduler.steal_work(CpuId(0), CpuId(3), 2), Ok(2));
+    assert_eq!(ids(scheduler.running_on(CpuId(0))), [1, 2, 3]);
+    assert_eq!(ids(scheduler.running_on(CpuId(3))), [4, 5]);
+    // CPU 3 is known now, and gets the next job.
+    assert_eq!(
+        scheduler.assign_to_least_loaded(Job::new(6, "more")),
+        Ok(CpuId(3))
+    );
+    // Stolen jobs go after the ones already there.
+    assert_eq!(scheduler.steal_work(CpuId(0), CpuId(3), 1), Ok(1));
+    assert_eq!(ids(scheduler.running_on(CpuId(3))), [4, 5, 6, 3]);
+    assert_eq!(
+        scheduler.load_report(),
+        BTreeMap::from([(CpuId(0), 2), (CpuId(3), 4)])
+    );
+}
+
+#[test]
+fn stealing_more_than_there_is_takes_everything() {
+    let mut scheduler = SchedulerState::new();
+    scheduler.submit(Job::new(9, "waiting")).unwrap();
+    for id in 1..=3 {
+        scheduler
+            .assign_to_least_loaded(Job::new(id, "work"))
+            .unwrap();
+    }
+    assert_eq!(scheduler.steal_work(CpuId(0), CpuId(1), 10), Ok(3));
+    assert_eq!(scheduler.running_on(CpuId(0)), []);
+    assert_eq!(ids(scheduler.running_on(CpuId(1))), [1, 2, 3]);
+    // CPU 0 has nothing left to give, but is still known.
+    assert_eq!(
+        scheduler.load_report(),
+        BTreeMap::from([(CpuId(0), 0), (CpuId(1), 3)])
+    );
+    assert_eq!(
+        scheduler.steal_work(CpuId(0), CpuId(1), 1),
+        Err(SchedulerError::NoJobs(CpuId(0)))
+    );
+    assert_eq!(scheduler.pending().count(), 1);
+}
+
+#[test]
+fn stealing_nothing_changes_nothing() {
+    let mut scheduler = SchedulerState::new();
+    assert_eq!(
+        scheduler.steal_work(CpuId(0), CpuId(1), 1),
+        Err(SchedulerError::NoJobs(CpuId(0)))
+    );
+    assert_eq!(scheduler, SchedulerState::Insert);
+
+    scheduler
+        .assign_to_least_loaded(Job::new(1, "work"))
+        .unwrap();
+    let before = scheduler.clone();
+    assert_eq!(scheduler.steal_work(CpuId(0), CpuId(0), 1), Ok(0));
+    assert_eq!(scheduler.steal_work(CpuId(0), CpuId(1), 0), Ok(0));
+    assert_eq!(scheduler, before);
+}
@@ -0,0 +1,51 @@
+use item_1_1::{CpuId, Job, JobId, SchedulerState};
+use log::Level;
+use test_support::logger::init_test_logger;
+
+fn debug_messages() -> Vec<String> {
+    init_test_logger()
+        .take()
+        .into_iter()
+        .inspect(|record| {
+            assert_eq!(record.level, Level::Debug);
+            assert_eq!(record.target, "item_1_1::scheduler");
+        })
+        .map(|record| record.message)
+        .collect()
+}
+
+#[test]
+fn each_transition_is_logged() {
+    init_test_logger();
+    let mut scheduler = SchedulerState::new();
+    scheduler.submit(Job::new(1, "build")).unwrap();
+    scheduler.submit(Job::new(2, "test")).unwrap();
+    scheduler.assign(JobId(1), CpuId(0)).unwrap();
+    scheduler.complete(CpuId(0), JobId(1)).unwrap();
+    scheduler.assign(JobId(2), CpuId(3)).unwrap();
+    scheduler.complete(CpuId(3), JobId(2)).unwrap();
+    assert_eq!(
+        debug_messages(),
+        [
+            "job 1 submitted, now pending",
+            "job 2 submitted, now pending",
+            "job 1 assigned to cpu 0, now running",
+            "job 1 completed on cpu 0, now idle",
+            "job 2 assigned to cpu 3, now running",
+            "job 2 completed on cpu 3, now idle",
+        ]
+    );
+}
+
+#[test]
+fn refused_transitions_are_not_logged() {
+    init_test_logger();
+    let mut scheduler = SchedulerState::new();
+    scheduler.submit(Job::new(1, "build")).unwrap();
+    debug_messages();
+
+    scheduler.submit(Job::new(1, "again")).unwrap_err();
+    scheduler.assign(JobId(9), CpuId(0)).unwrap_err();
+    scheduler.complete(CpuId(0), JobId(1)).unwrap_err();
+    assert!(debug_messages().is_empty());
+}
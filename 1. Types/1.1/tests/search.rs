@@ -0,0 +1,102 @@
+use item_1_1::search::{find_all, find_all_regexless_ci, find_all_with};
+use item_1_1::{SearchOptions, TextMatch};
+
+fn m(offset: usize, text: &str) -> TextMatch {
+    TextMatch(offset, text.to_owned())
+}
+
+const OVERLAPPING: SearchOptions = SearchOptions {
+    case_insensitive: false,
+    overlapping: true,
+};
+
+#[test]
+fn finds_every_match() {
+    assert_eq!(
+        find_all("one two one three one", "one"),
+        [m(0, "one"), m(8, "one"), m(18, "one")]
+    );
+    assert_eq!(find_all("abc", "d"), []);
+    assert_eq!(find_all("ab", "abc"), []);
+    assert_eq!(find_all("", "a"), []);
+}
+
+#[test]
+fn an_empty_needle_matches_nothing() {
+    assert_eq!(find_all("abc", ""), []);
+    assert_eq!(find_all_with("abc", "", &OVERLAPPING), []);
+}
+
+#[test]
+fn overlapping_matches() {
+    assert_eq!(find_all("aaaa", "aa"), [m(0, "aa"), m(2, "aa")]);
+    assert_eq!(
+        find_all_with("aaaa", "aa", &OVERLAPPING),
+        [m(0, "aa"), m(1, "aa"), m(2, "aa")]
+    );
+    assert_eq!(
+        find_all_with("ééé", "éé", &OVERLAPPING),
+        [m(0, "éé"), m(2, "éé")]
+    );
+}
+
+#[test]
+fn case_insensitive() {
+    assert_eq!(
+        find_all_regexless_ci("Rust, RUST and rUsT", "rust"),
+        [m(0, "Rust"), m(6, "RUST"), m(15, "rUsT")]
+    );
+    assert_eq!(find_all("Rust", "rust"), []);
+    let options = SearchOptions {
+        case_insensitive: true,
+        overlapping: true,
+    };
+    assert_eq!(
+        find_all_with("AaA", "aa", &options),
+        [m(0, "Aa"), m(1, "aA")]
+    );
+}
+
+#[test]
+fn multi_byte_characters() {
+    let text = "a naïve café, très naïve";
+    let matches = find_all(text, "naïve");
+    assert_eq!(matches, [m(2, "naïve"), m(22, "naïve")]);
+    for TextMatch(offset, found) in &matches {
+        assert!(text.is_char_boundary(*offset));
+        assert_eq!(&text[*offset..offset + found.len()], found);
+    }
+    assert_eq!(find_all(text, "é"), [m(12, "é")]);
+    assert_eq!(find_all(text, "è"), [m(18, "è")]);
+    // Case folding outside ASCII.
+    assert_eq!(
+        find_all_regexless_ci("NAÏVE Naïve", "naïve"),
+        [m(0, "NAÏVE"), m(7, "Naïve")]
+    );
+    assert_eq!(find_all_regexless_ci("ΣΊΣΥΦΟΣ", "σίσυφοσ").len(), 1);
+}
+
+#[test]
+fn case_insensitive_matches_can_differ_in_byte_length() {
+    // 'K' (the Kelvin sign, 3 bytes) lowercases to 'k', like 'K' does.
+    let text = "\u{212a}elvin";
+    assert_eq!(find_all_regexless_ci(text, "kelvin"), [m(0, text)]);
+    assert_eq!(
+        find_all_regexless_ci("kelvin", "\u{212a}elvin"),
+        [m(0, "kelvin")]
+    );
+}
+
+#[test]
+fn line_and_column_of_a_match() {
+    let text = "first line\nsecond naïve line\n\nnaïve";
+    let positions: Vec<(usize, usize)> = find_all(text, "naïve")
+        .iter()
+        .map(|found| found.line_col(text))
+        .collect();
+    assert_eq!(positions, [(2, 8), (4, 1)]);
+    // Columns count characters, not bytes.
+    let text = "ééé x";
+    assert_eq!(find_all(text, "x")[0].line_col(text), (1, 5));
+    assert_eq!(m(0, "").line_col(""), (1, 1));
+}
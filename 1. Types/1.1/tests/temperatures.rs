@@ -0,0 +1,76 @@
+use item_1_1::temperatures::{average, Celsius, Fahrenheit};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() <= 1e-9 * a.abs().max(b.abs()).max(1.0)
+}
+
+#[test]
+fn converts_fixed_points() {
+    assert_eq!(Fahrenheit::from(Celsius(0.0)), Fahrenheit(32.0));
+    assert_eq!(Fahrenheit::from(Celsius(100.0)), Fahrenheit(212.0));
+    assert_eq!(Celsius::from(Fahrenheit(212.0)), Celsius(100.0));
+    // Where the two scales meet.
+    assert_eq!(Celsius::from(Fahrenheit(-40.0)), Celsius(-40.0));
+    let body: Celsius = Fahrenheit(98.6).into();
+    assert!(close(body.0, 37.0), "{body}");
+}
+
+#[test]
+fn conversions_round_trip() {
+    for c in [
+        -273.15, -40.0, -17.5, 0.0, 0.1, 21.7, 37.0, 100.0, 5505.0, 1e6,
+    ] {
+        let back = Celsius::from(Fahrenheit::from(Celsius(c)));
+        assert!(close(back.0, c), "{c} came back as {back}");
+        let back = Fahrenheit::from(Celsius::from(Fahrenheit(c)));
+        assert!(close(back.0, c), "{c} came back as {back}");
+    }
+}
+
+#[test]
+fn adds_within_a_unit() {
+    assert_eq!(Celsius(20.0) + Celsius(1.5), Celsius(21.5));
+    assert_eq!(Fahrenheit(50.0) + Fahrenheit(-8.0), Fahrenheit(42.0));
+    let mut total = Celsius(1.0);
+    total += Celsius(2.0);
+    assert_eq!(total, Celsius(3.0));
+}
+
+#[test]
+fn compares_within_a_unit() {
+    assert!(Celsius(21.0) > Celsius(20.5));
+    assert!(Fahrenheit(-1.0) < Fahrenheit(0.0));
+    let warmest = [Celsius(12.0), Celsius(25.5), Celsius(19.0)]
+        .into_iter()
+        .max_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(warmest, Some(Celsius(25.5)));
+    // NaN is no warmer or colder than anything.
+    assert_eq!(Celsius(f64::NAN).partial_cmp(&Celsius(0.0)), None);
+}
+
+#[test]
+fn averages_in_the_same_unit() {
+    assert_eq!(
+        average(&[Celsius(18.0), Celsius(20.0), Celsius(25.0)]),
+        Some(Celsius(21.0))
+    );
+    assert_eq!(average(&[Fahrenheit(70.0)]), Some(Fahrenheit(70.0)));
+    assert_eq!(
+        average(&[Fahrenheit(-10.0), Fahrenheit(10.0)]),
+        Some(Fahrenheit(0.0))
+    );
+}
+
+#[test]
+fn no_readings_have_no_average() {
+    assert_eq!(average::<Celsius>(&[]), None);
+    assert_eq!(average::<Fahrenheit>(&[]), None);
+}
+
+#[test]
+fn displays_with_the_unit() {
+    assert_eq!(Celsius(21.5).to_string(), "21.5 °C");
+    assert_eq!(Fahrenheit(-40.0).to_string(), "-40 °F");
+    assert_eq!(format!("{:.1}", Fahrenheit::from(Celsius(37.0))), "98.6 °F");
+    assert_eq!(format!("{:>6.2}", Celsius(36.666)), " 36.67 °C");
+}
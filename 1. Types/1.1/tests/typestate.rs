@@ -0,0 +1,99 @@
+use item_1_1::printing::{Face, Output, Printer, Sides};
+use item_1_1::typestate::{CheckedPrintJob, JobState, PrintJob, PrintJobError, Unconfigured};
+use std::mem::size_of;
+
+const TEXT: &str = "{#ff0000}one{/}\ntwo\nthree";
+
+#[test]
+fn typestate_job_end_to_end() {
+    let job = PrintJob::new(TEXT, Printer::new(2));
+    assert_eq!(job.text(), TEXT);
+    let job = job.configure(Sides::Single, Output::Color);
+    assert_eq!((job.sides(), job.output()), (Sides::Single, Output::Color));
+    let job = job.reconfigure(Sides::Both, Output::BlackAndWhite);
+    assert_eq!(job.sides(), Sides::Both);
+
+    let job = job.submit();
+    assert_eq!(
+        (job.sides(), job.output()),
+        (Sides::Both, Output::BlackAndWhite)
+    );
+    let pages = job.pages();
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[0].lines, ["one", "two"]);
+    assert_eq!(pages[0].face, Some(Face::Front));
+    assert_eq!(pages[1].lines, ["three"]);
+    assert_eq!(job.text(), TEXT);
+}
+
+#[test]
+fn typestate_job_prints_what_the_printer_would() {
+    let printer = Printer::new(1);
+    let pages = PrintJob::new(TEXT, printer)
+        .configure(Sides::Both, Output::Color)
+        .submit()
+        .into_pages();
+    assert_eq!(pages, printer.print(TEXT, Sides::Both, Output::Color));
+}
+
+#[test]
+fn a_state_costs_only_what_it_holds() {
+    assert_eq!(size_of::<Unconfigured>(), 0);
+    assert_eq!(
+        size_of::<PrintJob<Unconfigured>>(),
+        size_of::<(String, Printer)>()
+    );
+}
+
+#[test]
+fn checked_job_end_to_end() {
+    let mut job = CheckedPrintJob::new(TEXT, Printer::new(2));
+    assert_eq!(job.state(), &JobState::Unconfigured);
+    job.configure(Sides::Single, Output::BlackAndWhite).unwrap();
+    assert_eq!(
+        job.state(),
+        &JobState::Configured {
+            sides: Sides::Single,
+            output: Output::BlackAndWhite
+        }
+    );
+    let pages = job.submit().unwrap().to_vec();
+    assert_eq!(pages.len(), 2);
+    assert_eq!(job.state(), &JobState::Submitted(pages));
+}
+
+#[test]
+fn checked_job_reports_what_the_typestate_job_rules_out() {
+    let mut job = CheckedPrintJob::new(TEXT, Printer::new(2));
+    assert_eq!(job.submit(), Err(PrintJobError::NotConfigured));
+    job.configure(Sides::Single, Output::Color).unwrap();
+    job.submit().unwrap();
+    assert_eq!(job.submit(), Err(PrintJobError::AlreadySubmitted));
+    assert_eq!(
+        job.configure(Sides::Both, Output::Color),
+        Err(PrintJobError::AlreadySubmitted)
+    );
+}
+
+#[test]
+fn both_jobs_print_the_same_pages() {
+    let printer = Printer::new(2);
+    let mut checked = CheckedPrintJob::new(TEXT, printer);
+    checked.configure(Sides::Both, Output::Color).unwrap();
+    let typed = PrintJob::new(TEXT, printer)
+        .configure(Sides::Both, Output::Color)
+        .submit();
+    assert_eq!(checked.submit().unwrap(), typed.pages());
+}
+
+#[test]
+fn print_job_errors_display() {
+    assert_eq!(
+        PrintJobError::NotConfigured.to_string(),
+        "the print job hasn't been configured"
+    );
+    assert_eq!(
+        PrintJobError::AlreadySubmitted.to_string(),
+        "the print job has already been submitted"
+    );
+}
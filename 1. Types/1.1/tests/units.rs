@@ -0,0 +1,167 @@
+use item_1_1::units::{describe, MAX_EXACT_MILLIMETERS, METERS_PER_FOOT};
+use item_1_1::{ConversionError, Feet, Meters, Millimeters};
+
+#[test]
+fn feet_to_meters() {
+    assert_eq!(Meters::from(Feet(1.0)), Meters(METERS_PER_FOOT));
+    assert_eq!(Meters::from(Feet(0.0)), Meters(0.0));
+    assert_eq!(Meters::from(Feet(-10.0)), Meters(-3.048));
+    // Feet to meters makes the number smaller, so even the largest feet convert.
+    assert!(Meters::from(Feet(f64::MAX)).0.is_finite());
+    let meters: Meters = Feet(100.0).into();
+    assert_eq!(meters, Meters(30.48));
+}
+
+#[test]
+fn meters_to_feet() {
+    assert_eq!(Feet::try_from(Meters(0.3048)), Ok(Feet(1.0)));
+    assert_eq!(Feet::try_from(Meters(-3.048)), Ok(Feet(-10.0)));
+    assert_eq!(
+        Feet::try_from(Meters(f64::MAX)),
+        Err(ConversionError::TooLarge {
+            unit: "m",
+            value: f64::MAX
+        })
+    );
+    assert_eq!(
+        Feet::try_from(Meters(f64::NEG_INFINITY)),
+        Ok(Feet(f64::NEG_INFINITY))
+    );
+    assert!(Feet::try_from(Meters(f64::NAN)).unwrap().0.is_nan());
+}
+
+#[test]
+fn meters_to_millimeters() {
+    assert_eq!(Millimeters::try_from(Meters(1.5)), Ok(Millimeters(1500)));
+    assert_eq!(Millimeters::try_from(Meters(0.0)), Ok(Millimeters(0)));
+    assert_eq!(Millimeters::try_from(Meters(-0.0)), Ok(Millimeters(0)));
+    // Rounded to the nearest millimeter.
+    assert_eq!(Millimeters::try_from(Meters(0.0004)), Ok(Millimeters(0)));
+    assert_eq!(Millimeters::try_from(Meters(0.0006)), Ok(Millimeters(1)));
+    assert_eq!(
+        Millimeters::try_from(Meters(-0.001)),
+        Err(ConversionError::Negative {
+            unit: "m",
+            value: -0.001
+        })
+    );
+    for value in [f64::NAN, f64::INFINITY] {
+        assert!(matches!(
+            Millimeters::try_from(Meters(value)),
+            Err(ConversionError::NotFinite { unit: "m", .. })
+        ));
+    }
+}
+
+#[test]
+fn meters_to_millimeters_overflow() {
+    // 2^64 mm is one more than a u64 holds.
+    let limit = 18_446_744_073_709_551_616.0 / 1000.0;
+    assert_eq!(
+        Millimeters::try_from(Meters(limit)),
+        Err(ConversionError::TooLarge {
+            unit: "m",
+            value: limit
+        })
+    );
+    assert_eq!(
+        Millimeters::try_from(Meters(1.8e16)),
+        Ok(Millimeters(18_000_000_000_000_000_000))
+    );
+    assert!(matches!(
+        Millimeters::try_from(Meters(f64::MAX)),
+        Err(ConversionError::TooLarge { .. })
+    ));
+}
+
+#[test]
+fn feet_to_millimeters() {
+    assert_eq!(Millimeters::try_from(Feet(1.0)), Ok(Millimeters(305)));
+    assert_eq!(Millimeters::try_from(Feet(10.0)), Ok(Millimeters(3048)));
+    assert_eq!(
+        Millimeters::try_from(Feet(-1.0)),
+        Err(ConversionError::Negative {
+            unit: "ft",
+            value: -1.0
+        })
+    );
+    assert!(matches!(
+        Millimeters::try_from(Feet(1e17)),
+        Err(ConversionError::TooLarge { unit: "ft", .. })
+    ));
+    assert!(matches!(
+        Millimeters::try_from(Feet(f64::NAN)),
+        Err(ConversionError::NotFinite { unit: "ft", .. })
+    ));
+}
+
+#[test]
+fn millimeters_to_meters_and_feet() {
+    assert_eq!(Meters::try_from(Millimeters(1500)), Ok(Meters(1.5)));
+    assert_eq!(Meters::try_from(Millimeters(0)), Ok(Meters(0.0)));
+    assert_eq!(Feet::try_from(Millimeters(3048)), Ok(Feet(10.0)));
+    assert_eq!(Feet::try_from(Millimeters(0)), Ok(Feet(0.0)));
+
+    let largest = Millimeters(MAX_EXACT_MILLIMETERS);
+    assert_eq!(Meters::try_from(largest), Ok(Meters(9_007_199_254_740.992)));
+    assert!(Feet::try_from(largest).is_ok());
+    for mm in [MAX_EXACT_MILLIMETERS + 1, u64::MAX] {
+        assert_eq!(
+            Meters::try_from(Millimeters(mm)),
+            Err(ConversionError::Inexact(Millimeters(mm)))
+        );
+        assert_eq!(
+            Feet::try_from(Millimeters(mm)),
+            Err(ConversionError::Inexact(Millimeters(mm)))
+        );
+    }
+}
+
+#[test]
+fn round_trips() {
+    for mm in [0, 1, 999, 1000, 123_456_789] {
+        let meters = Meters::try_from(Millimeters(mm)).unwrap();
+        assert_eq!(Millimeters::try_from(meters), Ok(Millimeters(mm)));
+        let feet = Feet::try_from(Millimeters(mm)).unwrap();
+        assert_eq!(Millimeters::try_from(feet), Ok(Millimeters(mm)));
+    }
+}
+
+#[test]
+fn describe_takes_anything_into_meters() {
+    assert_eq!(describe(Meters(2.0)), "2.000 m");
+    assert_eq!(describe(Feet(6.0)), "1.829 m");
+    assert_eq!(describe(Feet(-1.0)), "-0.305 m");
+}
+
+#[test]
+fn errors_display() {
+    assert_eq!(
+        ConversionError::Negative {
+            unit: "m",
+            value: -1.5
+        }
+        .to_string(),
+        "-1.5 m is negative"
+    );
+    assert_eq!(
+        ConversionError::NotFinite {
+            unit: "ft",
+            value: f64::NAN
+        }
+        .to_string(),
+        "NaN ft is not a finite length"
+    );
+    assert_eq!(
+        ConversionError::TooLarge {
+            unit: "m",
+            value: 1e20
+        }
+        .to_string(),
+        "100000000000000000000 m is too long"
+    );
+    assert_eq!(
+        ConversionError::Inexact(Millimeters(u64::MAX)).to_string(),
+        "18446744073709551615 mm is more than the 9007199254740992 mm that can be converted exactly"
+    );
+}
@@ -0,0 +1,153 @@
+// Dispatch tables: operators looked up by name. `Calculator` stores bare function pointers,
+// which is all it needs while every operator is a fixed function of its two operands.
+// `ClosureCalculator` stores boxed closures instead, so an operator can carry state of its own,
+// like the modulus in `with_modulus`; a function pointer has nowhere to put it.
+//
+// Every operator returns a `Result`, so that overflow (and division by zero) is reported
+// rather than wrapping round or panicking.
+
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CalcError {
+    UnknownOperator(String),
+    Overflow,
+    DivisionByZero,
+}
+
+impl fmt::Display for CalcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalcError::UnknownOperator(op) => write!(f, "unknown operator {op:?}"),
+            CalcError::Overflow => write!(f, "overflow"),
+            CalcError::DivisionByZero => write!(f, "division by zero"),
+        }
+    }
+}
+
+impl std::error::Error for CalcError {}
+
+pub type Op = fn(i32, i32) -> Result<i32, CalcError>;
+
+pub fn add(x: i32, y: i32) -> Result<i32, CalcError> {
+    x.checked_add(y).ok_or(CalcError::Overflow)
+}
+
+pub fn sub(x: i32, y: i32) -> Result<i32, CalcError> {
+    x.checked_sub(y).ok_or(CalcError::Overflow)
+}
+
+pub fn mul(x: i32, y: i32) -> Result<i32, CalcError> {
+    x.checked_mul(y).ok_or(CalcError::Overflow)
+}
+
+/// Integer division, rounding towards zero. `i32::MIN / -1` overflows.
+pub fn div(x: i32, y: i32) -> Result<i32, CalcError> {
+    if y == 0 {
+        return Err(CalcError::DivisionByZero);
+    }
+    x.checked_div(y).ok_or(CalcError::Overflow)
+}
+
+const BUILTINS: [(&str, Op); 4] = [("add", add), ("sub", sub), ("mul", mul), ("div", div)];
+
+/// A table of function pointers, starting with `add`, `sub`, `mul` and `div`.
+pub struct Calculator {
+    ops: HashMap<String, Op>,
+}
+
+impl Calculator {
+    pub fn new() -> Self {
+        Calculator {
+            ops: BUILTINS
+                .into_iter()
+                .map(|(name, op)| (name.to_owned(), op))
+                .collect(),
+        }
+    }
+
+    /// Add an operator, or replace the one with the same name.
+    pub fn register(&mut self, name: &str, op: Op) {
+        self.ops.insert(name.to_owned(), op);
+    }
+
+    pub fn eval(&self, op: &str, x: i32, y: i32) -> Result<i32, CalcError> {
+        let f = self
+            .ops
+            .get(op)
+            .ok_or_else(|| CalcError::UnknownOperator(op.to_owned()))?;
+        f(x, y)
+    }
+
+    /// The operator names, sorted.
+    pub fn operators(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.ops.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+}
+
+impl Default for Calculator {
+    fn default() -> Self {
+        Calculator::new()
+    }
+}
+
+type BoxedOp = Box<dyn Fn(i32, i32) -> Result<i32, CalcError>>;
+
+/// A table of closures. It starts with the same operators as `Calculator`: a function pointer
+/// is a closure too, as far as `Fn` is concerned.
+pub struct ClosureCalculator {
+    ops: HashMap<String, BoxedOp>,
+}
+
+impl ClosureCalculator {
+    pub fn new() -> Self {
+        ClosureCalculator {
+            ops: BUILTINS
+                .into_iter()
+                .map(|(name, op)| (name.to_owned(), Box::new(op) as BoxedOp))
+                .collect(),
+        }
+    }
+
+    /// Also has `addmod` and `mulmod`, which reduce the result modulo `modulus`; the result is
+    /// never negative. A zero modulus makes both fail with `DivisionByZero`.
+    pub fn with_modulus(modulus: i32) -> Self {
+        let mut calc = ClosureCalculator::new();
+        // Each closure captures its own copy of `modulus`. The arithmetic is done in i64, where
+        // neither the sum nor the product of two i32s can overflow.
+        let reduce = move |n: i64| {
+            n.checked_rem_euclid(i64::from(modulus))
+                .map(|r| r as i32)
+                .ok_or(CalcError::DivisionByZero)
+        };
+        calc.register("addmod", move |x, y| reduce(i64::from(x) + i64::from(y)));
+        calc.register("mulmod", move |x, y| reduce(i64::from(x) * i64::from(y)));
+        calc
+    }
+
+    /// Add an operator, or replace the one with the same name.
+    pub fn register(
+        &mut self,
+        name: &str,
+        op: impl Fn(i32, i32) -> Result<i32, CalcError> + 'static,
+    ) {
+        self.ops.insert(name.to_owned(), Box::new(op));
+    }
+
+    pub fn eval(&self, op: &str, x: i32, y: i32) -> Result<i32, CalcError> {
+        let f = self
+            .ops
+            .get(op)
+            .ok_or_else(|| CalcError::UnknownOperator(op.to_owned()))?;
+        f(x, y)
+    }
+}
+
+impl Default for ClosureCalculator {
+    fn default() -> Self {
+        ClosureCalculator::new()
+    }
+}
@@ -0,0 +1,69 @@
+// Storing closures, which is where the difference between `Fn`, `FnMut` and `FnOnce` stops
+// being academic. A registry that calls its callbacks on every event has to be able to call
+// them repeatedly, so it stores `FnMut`s; that admits closures that read their environment
+// (`Fn`) or change it (`FnMut`), but not ones that give away something they captured. Those
+// can only be `FnOnce`, and are stored separately and dropped after their one call.
+//
+// The `'a` lifetime lets callbacks borrow from their surroundings, for as long as the registry
+// lives.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    Click { x: i32, y: i32 },
+    KeyPress(char),
+}
+
+enum Callback<'a> {
+    Repeat(Box<dyn FnMut(&Event) + 'a>),
+    Once(Box<dyn FnOnce(&Event) + 'a>),
+    // What a `Once` becomes after its call.
+    Spent,
+}
+
+/// Callbacks to be called on each event, in the order they were registered.
+#[derive(Default)]
+pub struct CallbackRegistry<'a> {
+    callbacks: Vec<Callback<'a>>,
+}
+
+impl<'a> CallbackRegistry<'a> {
+    pub fn new() -> Self {
+        CallbackRegistry::default()
+    }
+
+    /// Call `callback` on every event from now on.
+    pub fn register(&mut self, callback: impl FnMut(&Event) + 'a) {
+        self.callbacks.push(Callback::Repeat(Box::new(callback)));
+    }
+
+    /// Call `callback` on the next event only.
+    pub fn register_once(&mut self, callback: impl FnOnce(&Event) + 'a) {
+        self.callbacks.push(Callback::Once(Box::new(callback)));
+    }
+
+    pub fn dispatch(&mut self, event: &Event) {
+        for callback in &mut self.callbacks {
+            match callback {
+                Callback::Repeat(f) => f(event),
+                // Calling a `Box<dyn FnOnce>` consumes the box, so it has to be moved out first.
+                Callback::Once(_) => {
+                    if let Callback::Once(f) = std::mem::replace(callback, Callback::Spent) {
+                        f(event);
+                    }
+                }
+                Callback::Spent => {}
+            }
+        }
+        self.callbacks
+            .retain(|callback| !matches!(callback, Callback::Spent));
+    }
+
+    /// How many callbacks are registered, counting once-callbacks that haven't fired yet.
+    pub fn len(&self) -> usize {
+        self.callbacks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.callbacks.is_empty()
+    }
+}
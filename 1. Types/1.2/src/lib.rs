@@ -0,0 +1,524 @@
+// Item 1.2: Use the type system to express common behavior.
+//
+// The mechanisms described in this Item will generally feel familiar,
+// as they all have direct analogs in other languages:
+//
+// Functions: The universal mechanism for associating a chunk of code with a name and a parameter
+//          list.
+// Methods: Functions that are associated with an instance of a particular data structure.
+//          Methods are common in programming languages created after object-orientation
+//          arose as a programming paradigm.
+// Function pointers: Supported by most languages in the C family, including C++ and Go,
+//          as a mechanism that allows an extra level of indirection when invoking other code.
+// Closures: Originally most common in the Lisp family of languages but have been
+//          retrofitted to many popular programming languages,
+//          including C++ (since C++11) and Java (since Java 8).
+// Traits: Describe collections of related functionality that all apply to the same underlying item.
+//         Traits have rough equivalents in many other languages,
+//         including abstract classes in C++ and interfaces in Go and Java.
+//
+// `run` at the bottom shows them in use, along with the function pointer and closure examples,
+// which are about how code is passed around rather than definitions to share.
+
+pub mod calculator;
+pub mod callbacks;
+#[cfg(feature = "serde")]
+pub mod scene;
+pub mod shapes;
+
+pub use calculator::{CalcError, Calculator, ClosureCalculator};
+pub use callbacks::{CallbackRegistry, Event};
+pub use shapes::{parse_shapes, ParseShapeError, Shape, ShapeError};
+
+pub const DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
+
+// FUNCTIONS AND METHODS
+
+/// Why [`div`] couldn't produce a result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DivError {
+    /// The divisor was zero (of either sign).
+    DivisionByZero,
+    /// An input was NaN or infinite, or the quotient overflowed to infinity.
+    NonFinite,
+}
+
+impl std::fmt::Display for DivError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DivError::DivisionByZero => write!(f, "division by zero"),
+            DivError::NonFinite => write!(f, "non-finite number"),
+        }
+    }
+}
+
+impl std::error::Error for DivError {}
+
+// Returning NaN for a zero divisor would push the check onto every caller, who might forget it;
+// a `Result` makes the failure impossible to ignore.
+
+/// Return x divided by y, which is always a finite number.
+pub fn div(x: f64, y: f64) -> Result<f64, DivError> {
+    if !x.is_finite() || !y.is_finite() {
+        return Err(DivError::NonFinite);
+    }
+    if y == 0.0 {
+        return Err(DivError::DivisionByZero);
+    }
+    let quotient = x / y;
+    // The last expression is the return value
+    if quotient.is_finite() {
+        Ok(quotient)
+    } else {
+        Err(DivError::NonFinite)
+    }
+}
+
+pub fn show(x: f64) {
+    println!("x = {x}");
+}
+
+// Methods are functions defined in an `impl` block for a type: see `Shape` in the `shapes`
+// module.
+
+// A &self parameter indicates that the contents of the data structure may be read from
+// but will not be modified.
+// A &mut self parameter indicates that the method might modify the contents of the data structure.
+// A self parameter indicates that the method consumes the data structure.
+
+// CLOSURES
+
+// A closure can't be used where a function pointer is expected.
+// Instead, the code that receives the closure has to accept an instance of one of the Fn* traits:
+
+/// Replace each value with what `mutator` makes of it.
+///
+/// The mutator maps values to values, rather than changing them through a `&mut T`, so that
+/// plain functions such as `fn double(x: u32) -> u32` can be passed as they are. That needs
+/// `T: Copy`, to take each value out of the slice while its slot is being written.
+pub fn modify_all<T, F>(data: &mut [T], mut mutator: F)
+where
+    T: Copy,
+    F: FnMut(T) -> T,
+{
+    for value in data {
+        *value = mutator(*value);
+    }
+}
+
+/// `modify_all` for a mutator that can fail. Values are changed in order, and the first
+/// error stops it, returned with the index of the value that failed. The values before that
+/// index keep their new contents, and the failing one and those after it their old: the
+/// slice is left half done.
+///
+/// On success, the result is how many values were changed, which is all of them.
+pub fn try_modify_all<T, F, E>(data: &mut [T], mut mutator: F) -> Result<usize, (usize, E)>
+where
+    T: Copy,
+    F: FnMut(T) -> Result<T, E>,
+{
+    for (index, value) in data.iter_mut().enumerate() {
+        *value = mutator(*value).map_err(|e| (index, e))?;
+    }
+    Ok(data.len())
+}
+
+/// `try_modify_all`, all or nothing: the new values are collected on the side, and only
+/// written back once every one of them has succeeded, so a failure leaves `data` as it was.
+/// The cost is a buffer the size of the slice.
+pub fn try_modify_all_atomic<T, F, E>(data: &mut [T], mut mutator: F) -> Result<usize, (usize, E)>
+where
+    T: Copy,
+    F: FnMut(T) -> Result<T, E>,
+{
+    let modified = data
+        .iter()
+        .enumerate()
+        .map(|(index, &value)| mutator(value).map_err(|e| (index, e)))
+        .collect::<Result<Vec<_>, _>>()?;
+    data.copy_from_slice(&modified);
+    Ok(modified.len())
+}
+
+/// `modify_all`, with the slice split across threads by `rayon`.
+///
+/// The mutator is shared by every thread at once, so it has to be `Sync` and callable through
+/// a shared reference: `Fn`, not `FnMut`. A closure that counts its calls, as `modify_all`'s
+/// may, would be changing one counter from several threads with no synchronization, and is
+/// rejected:
+///
+/// ```compile_fail,E0594
+/// let mut data = [1, 2, 3];
+/// let mut calls = 0;
+/// item_1_2::par_modify_all(&mut data, |value| {
+///     calls += 1;
+///     value * 10
+/// });
+/// ```
+///
+/// A counter that is safe to share, such as an `AtomicUsize`, can be updated through `&`, and
+/// is fine.
+///
+/// It takes `&T` rather than `T`, since there is then no need for `T: Copy`, only for `T`
+/// to be sendable and shareable between threads.
+#[cfg(feature = "rayon")]
+pub fn par_modify_all<T, F>(data: &mut [T], mutator: F)
+where
+    T: Send + Sync,
+    F: Fn(&T) -> T + Sync,
+{
+    use rayon::prelude::*;
+
+    data.par_iter_mut()
+        .for_each(|value| *value = mutator(value));
+}
+
+// FnOnce: Describes a closure that can be called only once. If some part of the environment is
+//         moved into the closure's context, and the closure's body subsequently moves it
+//         out of the closure's context, then those moves can happen only once—there's
+//         no other copy of the source item to move from—and so the closure can be invoked only once.
+// FnMut:  Describes a closure that can be called repeatedly and that can make changes to its
+//         environment because it mutably borrows from the environment.
+// Fn:     Describes a closure that can be called repeatedly and that only borrows values from
+//         the environment immutably.
+// Or:
+//      FnOnce = Any moved values
+//      FnMut  = Any mutable references to values (&mut T)
+//      Fn     = Only normal references to values (&T)
+
+// TRAITS
+// A trait defines a set of related functions that some underlying item makes
+// publicly available. The functions are typically methods:
+
+pub trait Sort {
+    fn sort(&mut self);
+    fn reverse(&mut self);
+
+    // A default method is written in terms of the others; implementors get it for free, but
+    // can override it with something faster.
+    fn sort_desc(&mut self) {
+        self.sort();
+        self.reverse();
+    }
+}
+
+impl<T: Ord> Sort for Vec<T> {
+    fn sort(&mut self) {
+        self.as_mut_slice().sort();
+    }
+
+    fn reverse(&mut self) {
+        self.as_mut_slice().reverse();
+    }
+}
+
+/// A list that only sorts itself when asked to, and remembers whether it still needs to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortedList<T> {
+    items: Vec<T>,
+    sorted: bool,
+}
+
+impl<T> SortedList<T> {
+    pub fn new() -> Self {
+        SortedList {
+            items: Vec::new(),
+            // An empty list is in order.
+            sorted: true,
+        }
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+        self.sorted = self.items.len() <= 1;
+    }
+
+    /// Whether the items are known to be in ascending order.
+    pub fn is_sorted(&self) -> bool {
+        self.sorted
+    }
+
+    /// The items in their current order, sorted or not.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+}
+
+impl<T> Default for SortedList<T> {
+    fn default() -> Self {
+        SortedList::new()
+    }
+}
+
+impl<T: Ord> Sort for SortedList<T> {
+    fn sort(&mut self) {
+        if !self.sorted {
+            self.items.sort();
+            self.sorted = true;
+        }
+    }
+
+    fn reverse(&mut self) {
+        self.items.reverse();
+        self.sorted = self.items.len() <= 1;
+    }
+}
+
+impl<T> FromIterator<T> for SortedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = SortedList::new();
+        for item in iter {
+            list.push(item);
+        }
+        list
+    }
+}
+
+impl<T> IntoIterator for SortedList<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+// Trait bounds are used to express requirements on the types used in generics:
+pub fn dump_sorted<T>(collection: T)
+where
+    T: Sort + IntoIterator,
+    T::Item: std::fmt::Debug,
+{
+    write_sorted(collection, &mut std::io::stdout()).expect("failed writing to stdout");
+}
+
+/// `dump_sorted`, but to any writer rather than stdout.
+pub fn write_sorted<T>(mut collection: T, out: &mut impl std::io::Write) -> std::io::Result<()>
+where
+    T: Sort + IntoIterator,
+    T::Item: std::fmt::Debug,
+{
+    // Next line requires `T: Sort` trait bound.
+    collection.sort();
+    // Next line requires `T: IntoIterator` trait bound.
+    for item in collection {
+        // Next line requires `T::Item : Debug` trait bound
+        writeln!(out, "{:?}", item)?;
+    }
+    Ok(())
+}
+
+/// The items of `collection` in order, each formatted with `Debug` as it is asked for, so
+/// taking the first few formats only those. There is a string per item, without a newline
+/// at the end, even where an item's `Debug` output runs over several lines.
+///
+/// The sort itself can't be put off: the first item isn't known until every item has been
+/// compared, so it happens before the first string is produced.
+///
+/// ```
+/// let smallest: Vec<String> = item_1_2::sorted_lines(vec![30, 10, 20]).take(2).collect();
+/// assert_eq!(smallest, ["10", "20"]);
+/// ```
+pub fn sorted_lines<T>(mut collection: T) -> impl Iterator<Item = String>
+where
+    T: Sort + IntoIterator,
+    T::Item: std::fmt::Debug,
+{
+    collection.sort();
+    collection.into_iter().map(|item| format!("{item:?}"))
+}
+
+// TRAIT OBJECTS
+// `dump_sorted` is monomorphized: the compiler generates a copy for each `T` it is used with,
+// and each copy calls `sort` directly. A trait object instead holds a pointer to a vtable, and
+// every call goes through it, so one compiled function serves every type.
+//
+// `Sort + IntoIterator` can't be made into a trait object: `into_iter` takes `self` by value,
+// and `IntoIterator` has an associated type that would differ between implementors. The
+// workaround is an object-safe trait of our own that borrows the items instead, and erases
+// their type behind `dyn Debug`. Making `Sort` its supertrait means a `&mut dyn SortAndIter`
+// can be sorted too.
+
+pub trait SortAndIter: Sort {
+    fn debug_items(&self) -> Box<dyn Iterator<Item = &dyn std::fmt::Debug> + '_>;
+}
+
+impl<T: Ord + std::fmt::Debug> SortAndIter for Vec<T> {
+    fn debug_items(&self) -> Box<dyn Iterator<Item = &dyn std::fmt::Debug> + '_> {
+        Box::new(self.iter().map(|item| item as &dyn std::fmt::Debug))
+    }
+}
+
+impl<T: Ord + std::fmt::Debug> SortAndIter for SortedList<T> {
+    fn debug_items(&self) -> Box<dyn Iterator<Item = &dyn std::fmt::Debug> + '_> {
+        Box::new(self.items.iter().map(|item| item as &dyn std::fmt::Debug))
+    }
+}
+
+pub fn dump_sorted_dyn(collection: &mut dyn SortAndIter) {
+    write_sorted_dyn(collection, &mut std::io::stdout()).expect("failed writing to stdout");
+}
+
+/// `dump_sorted_dyn`, but to any writer rather than stdout.
+pub fn write_sorted_dyn(
+    collection: &mut dyn SortAndIter,
+    out: &mut dyn std::io::Write,
+) -> std::io::Result<()> {
+    // Both calls go through the vtable, as does each step of the iterator.
+    collection.sort();
+    for item in collection.debug_items() {
+        writeln!(out, "{:?}", item)?;
+    }
+    Ok(())
+}
+
+pub fn run() -> Result<(), Box<dyn std::error::Error>> {
+    // FUNCTIONS AND METHODS
+
+    // `?` hands a failed division straight back to our caller (see Item 1.3).
+    show(div(7.0, 2.0)?);
+    // `map_err` turns the error into whatever the surrounding code deals in.
+    match div(1.0, 0.0).map_err(|e| format!("can't divide 1 by 0: {e}")) {
+        Ok(x) => show(x),
+        Err(message) => println!("{message}"),
+    }
+
+    let mut shapes = vec![
+        Shape::Rectangle {
+            width: 3.0,
+            height: 4.0,
+        },
+        Shape::Circle { radius: 1.0 },
+        Shape::try_new_triangle(3.0, 4.0, 5.0)?,
+    ];
+    for shape in &mut shapes {
+        println!(
+            "{shape:?}: area {:.3}, perimeter {:.3}, bounding box {:?}",
+            shape.area(),
+            shape.perimeter(),
+            shape.bounding_box()
+        );
+        shape.scale(2.0);
+        println!("  scaled by 2: area {:.3}", shape.area());
+    }
+    // Lengths from outside can be checked on the way in.
+    if let Err(e) = Shape::try_new_triangle(1.0, 2.0, 3.0) {
+        println!("no such triangle: {e}");
+    }
+    // And so can shapes written out as text, one per line.
+    let text = "rect 3x4\n  CIRCLE   r=2 \ntriangle 3, 4, 5";
+    let parsed = parse_shapes(text).map_err(|(line, e)| format!("line {line}: {e}"))?;
+    for shape in parsed {
+        println!("{shape}");
+    }
+    if let Err((line, e)) = parse_shapes("circle r=1\nsquare 2") {
+        println!("line {line}: {e}");
+    }
+
+    // FUNCTION POINTERS
+    // A function pointer is a pointer to some code, with a type that reflects the signature.
+    // The bare function pointers are limiting, because the only inputs available to the invoked
+    // function are those that are explicitly passed as parameter values.
+
+    fn sum(x: i32, y: i32) -> i32 {
+        x + y
+    }
+    let fun: fn(i32, i32) -> i32 = sum;
+    println!("fun(2, 3) = {}", fun(2, 3));
+
+    // A table of them dispatches by name; see the `calculator` module.
+    let calc = Calculator::new();
+    println!("mul(6, 7) = {}", calc.eval("mul", 6, 7)?);
+    if let Err(e) = calc.eval("add", i32::MAX, 1) {
+        println!("add(i32::MAX, 1): {e}");
+    }
+
+    // CLOSURES
+    // A closure is a chunk of code that looks like the body of a function definition, but can
+    // be built from an expression. It can also capture parts of the surrounding environment.
+
+    let amount = 3;
+    let add_num = |y| y + amount;
+    let z = add_num(23);
+    assert_eq!(z, 26);
+
+    // A closure can carry state that a function pointer has nowhere to keep; this table's
+    // `addmod` captures the modulus.
+    let clock = ClosureCalculator::with_modulus(12);
+    println!(
+        "9 o'clock + 5 hours = {} o'clock",
+        clock.eval("addmod", 9, 5)?
+    );
+
+    // `modify_all` takes an `FnMut`, so the closure may update the environment it captured.
+    let mut data = [1, 2, 3];
+    let mut calls = 0;
+    modify_all(&mut data, |value| {
+        calls += 1;
+        value * 10
+    });
+    println!("modify_all: {data:?} after {calls} calls");
+
+    // A mutator that can fail stops at the first error. `try_modify_all` leaves the values it
+    // has already changed; `try_modify_all_atomic` leaves none.
+    let checked_double = |value: u32| value.checked_mul(2).ok_or("overflow");
+    let mut data = [1, u32::MAX, 3];
+    if let Err((index, e)) = try_modify_all(&mut data, checked_double) {
+        println!("try_modify_all: {e} at {index}, leaving {data:?}");
+    }
+    let mut data = [1, u32::MAX, 3];
+    if let Err((index, e)) = try_modify_all_atomic(&mut data, checked_double) {
+        println!("try_modify_all_atomic: {e} at {index}, leaving {data:?}");
+    }
+
+    // Stored closures have to say up front which Fn* trait they meet; see the `callbacks`
+    // module. Each of these three needs a different one.
+    let prefix = String::from("event");
+    let mut clicks = 0;
+    let farewell = String::from("first event seen");
+    let mut log = Vec::new();
+    {
+        let mut registry = CallbackRegistry::new();
+        // Only reads `prefix`: `Fn`.
+        registry.register(|event| println!("{prefix}: {event:?}"));
+        // Changes `clicks`: `FnMut`.
+        registry.register(|event| {
+            if let Event::Click { .. } = event {
+                clicks += 1;
+            }
+        });
+        // Gives away `farewell`, so it can only run once: `FnOnce`.
+        let log = &mut log;
+        registry.register_once(move |_| log.push(farewell));
+        registry.dispatch(&Event::Click { x: 1, y: 2 });
+        registry.dispatch(&Event::KeyPress('q'));
+        registry.dispatch(&Event::Click { x: 3, y: 4 });
+    }
+    // The registry is gone, and with it the borrows of `clicks` and `log`.
+    println!("{clicks} clicks; log: {log:?}");
+
+    // TRAITS
+
+    dump_sorted(vec![3, 1, 2]);
+    dump_sorted(
+        ["pear", "apple", "fig"]
+            .into_iter()
+            .collect::<SortedList<_>>(),
+    );
+    // Lazily, stopping after the first.
+    for line in sorted_lines(vec![3, 1, 2]).take(1) {
+        println!("smallest: {line}");
+    }
+    // The same, through a trait object: one compiled function for both types.
+    dump_sorted_dyn(&mut vec![3, 1, 2]);
+    dump_sorted_dyn(
+        &mut ["pear", "apple", "fig"]
+            .into_iter()
+            .collect::<SortedList<_>>(),
+    );
+    let mut countdown = vec![1, 3, 2];
+    countdown.sort_desc();
+    println!("sort_desc: {countdown:?}");
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+// Saving and loading a scene: a list of shapes, as a JSON array. Each shape is an object
+// tagged with its variant, like `{"kind": "Circle", "radius": 1.0}`.
+//
+// The lengths in a loaded scene aren't checked; use the `Shape::try_new_*` constructors for
+// that.
+
+use crate::Shape;
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+#[derive(Debug)]
+pub enum SceneError {
+    Io(io::Error),
+    /// The file was read, but isn't a scene.
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for SceneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SceneError::Io(_) => write!(f, "failed to read or write the scene file"),
+            SceneError::Json(e) => write!(
+                f,
+                "the scene file is not a list of shapes (line {}, column {})",
+                e.line(),
+                e.column()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SceneError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SceneError::Io(e) => Some(e),
+            SceneError::Json(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for SceneError {
+    fn from(e: io::Error) -> Self {
+        SceneError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for SceneError {
+    fn from(e: serde_json::Error) -> Self {
+        SceneError::Json(e)
+    }
+}
+
+pub fn load_scene(path: impl AsRef<Path>) -> Result<Vec<Shape>, SceneError> {
+    let text = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&text)?)
+}
+
+/// Write `shapes` to `path`, pretty-printed, replacing anything already there.
+pub fn save_scene(path: impl AsRef<Path>, shapes: &[Shape]) -> Result<(), SceneError> {
+    let mut text = serde_json::to_string_pretty(shapes)?;
+    text.push('\n');
+    std::fs::write(path, text)?;
+    Ok(())
+}
@@ -0,0 +1,8 @@
+// Shapes as an enum with fields rather than a class hierarchy: each variant carries exactly the
+// data it needs, and every method is a single `match` that the compiler checks covers all of
+// them. Adding a variant makes every such `match` fail to compile until it handles the newcomer.
+//
+// `Shape` is defined in the `core-types` crate, along with its errors and its text form, so that
+// it can be used without `std`, and re-exported here.
+
+pub use core_types::shapes::{parse_shapes, ParseShapeError, Shape, ShapeError};
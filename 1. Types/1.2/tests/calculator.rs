@@ -0,0 +1,120 @@
+use item_1_2::calculator::{add, div, mul, sub};
+use item_1_2::{CalcError, Calculator, ClosureCalculator};
+
+#[test]
+fn every_operator() {
+    let calc = Calculator::new();
+    assert_eq!(calc.operators(), ["add", "div", "mul", "sub"]);
+    assert_eq!(calc.eval("add", 2, 3), Ok(5));
+    assert_eq!(calc.eval("sub", 2, 3), Ok(-1));
+    assert_eq!(calc.eval("mul", -4, 3), Ok(-12));
+    assert_eq!(calc.eval("div", 7, 2), Ok(3));
+    assert_eq!(calc.eval("div", -7, 2), Ok(-3));
+}
+
+#[test]
+fn overflow() {
+    let calc = Calculator::new();
+    assert_eq!(calc.eval("add", i32::MAX, 1), Err(CalcError::Overflow));
+    assert_eq!(calc.eval("sub", i32::MIN, 1), Err(CalcError::Overflow));
+    assert_eq!(calc.eval("mul", i32::MAX, 2), Err(CalcError::Overflow));
+    assert_eq!(calc.eval("div", i32::MIN, -1), Err(CalcError::Overflow));
+    // Right at the edge is fine.
+    assert_eq!(calc.eval("add", i32::MAX - 1, 1), Ok(i32::MAX));
+}
+
+#[test]
+fn division_by_zero() {
+    assert_eq!(
+        Calculator::new().eval("div", 1, 0),
+        Err(CalcError::DivisionByZero)
+    );
+    assert_eq!(div(0, 0), Err(CalcError::DivisionByZero));
+}
+
+#[test]
+fn unknown_operator() {
+    let calc = Calculator::new();
+    assert_eq!(
+        calc.eval("pow", 2, 3),
+        Err(CalcError::UnknownOperator("pow".to_owned()))
+    );
+    assert_eq!(
+        calc.eval("pow", 2, 3).unwrap_err().to_string(),
+        "unknown operator \"pow\""
+    );
+}
+
+#[test]
+fn registering_operators() {
+    fn max(x: i32, y: i32) -> Result<i32, CalcError> {
+        Ok(x.max(y))
+    }
+    let mut calc = Calculator::new();
+    calc.register("max", max);
+    assert_eq!(calc.eval("max", 3, 9), Ok(9));
+    // A closure that captures nothing coerces to a function pointer.
+    calc.register("add", |x, y| sub(x, -y));
+    assert_eq!(calc.eval("add", 3, 9), Ok(12));
+}
+
+#[test]
+fn closure_table_has_the_same_builtins() {
+    let (calc, closures) = (Calculator::new(), ClosureCalculator::new());
+    for op in ["add", "sub", "mul", "div", "nope"] {
+        for (x, y) in [(6, 3), (i32::MAX, 2), (1, 0)] {
+            assert_eq!(
+                calc.eval(op, x, y),
+                closures.eval(op, x, y),
+                "{op}({x}, {y})"
+            );
+        }
+    }
+}
+
+#[test]
+fn modular_operators_capture_the_modulus() {
+    let clock = ClosureCalculator::with_modulus(12);
+    assert_eq!(clock.eval("addmod", 9, 5), Ok(2));
+    assert_eq!(clock.eval("mulmod", 5, 5), Ok(1));
+    assert_eq!(clock.eval("addmod", -1, 0), Ok(11));
+    // No overflow on the way, even for the largest operands.
+    assert_eq!(
+        clock.eval("addmod", i32::MAX, i32::MAX),
+        Ok(((2 * i64::from(i32::MAX)) % 12) as i32)
+    );
+    assert_eq!(
+        ClosureCalculator::with_modulus(7).eval("mulmod", i32::MAX, i32::MAX),
+        Ok(((i64::from(i32::MAX) * i64::from(i32::MAX)) % 7) as i32)
+    );
+    // Two tables, two moduli.
+    assert_eq!(
+        ClosureCalculator::with_modulus(5).eval("addmod", 9, 5),
+        Ok(4)
+    );
+    assert_eq!(
+        ClosureCalculator::with_modulus(0).eval("addmod", 1, 2),
+        Err(CalcError::DivisionByZero)
+    );
+    assert_eq!(
+        ClosureCalculator::new().eval("addmod", 1, 2),
+        Err(CalcError::UnknownOperator("addmod".to_owned()))
+    );
+}
+
+#[test]
+fn registering_a_capturing_closure() {
+    let mut calc = ClosureCalculator::new();
+    let offset = 100;
+    calc.register("offset_add", move |x, y| {
+        add(x, y).and_then(|s| add(s, offset))
+    });
+    assert_eq!(calc.eval("offset_add", 1, 2), Ok(103));
+    assert_eq!(mul(2, 3), calc.eval("mul", 2, 3));
+}
+
+#[test]
+fn errors_display() {
+    assert_eq!(CalcError::Overflow.to_string(), "overflow");
+    assert_eq!(CalcError::DivisionByZero.to_string(), "division by zero");
+}
@@ -0,0 +1,81 @@
+use item_1_2::{CallbackRegistry, Event};
+use std::cell::RefCell;
+
+const CLICK: Event = Event::Click { x: 1, y: 2 };
+
+#[test]
+fn dispatches_in_registration_order() {
+    let log = RefCell::new(Vec::new());
+    let mut registry = CallbackRegistry::new();
+    registry.register(|_| log.borrow_mut().push("a"));
+    registry.register_once(|_| log.borrow_mut().push("b"));
+    registry.register(|_| log.borrow_mut().push("c"));
+    registry.dispatch(&CLICK);
+    registry.dispatch(&CLICK);
+    drop(registry);
+    assert_eq!(log.into_inner(), ["a", "b", "c", "a", "c"]);
+}
+
+#[test]
+fn callbacks_see_the_event() {
+    let mut seen = Vec::new();
+    let mut registry = CallbackRegistry::new();
+    registry.register(|event| seen.push(*event));
+    registry.dispatch(&CLICK);
+    registry.dispatch(&Event::KeyPress('x'));
+    drop(registry);
+    assert_eq!(seen, [CLICK, Event::KeyPress('x')]);
+}
+
+#[test]
+fn once_callbacks_fire_exactly_once() {
+    let message = String::from("hello");
+    let mut received = None;
+    let mut registry = CallbackRegistry::new();
+    registry.register_once(|_| received = Some(message));
+    assert_eq!(registry.len(), 1);
+    registry.dispatch(&CLICK);
+    assert!(registry.is_empty());
+    registry.dispatch(&CLICK);
+    drop(registry);
+    assert_eq!(received.as_deref(), Some("hello"));
+}
+
+#[test]
+fn once_callbacks_registered_after_a_dispatch_still_fire() {
+    let fired = RefCell::new(0);
+    let mut registry = CallbackRegistry::new();
+    registry.register_once(|_| *fired.borrow_mut() += 1);
+    registry.dispatch(&CLICK);
+    registry.register_once(|_| *fired.borrow_mut() += 10);
+    registry.dispatch(&CLICK);
+    registry.dispatch(&CLICK);
+    drop(registry);
+    assert_eq!(fired.into_inner(), 11);
+}
+
+#[test]
+fn counter_accumulates_across_dispatches() {
+    let mut clicks = 0;
+    let mut keys = 0;
+    let mut registry = CallbackRegistry::new();
+    registry.register(|event| match event {
+        Event::Click { .. } => clicks += 1,
+        Event::KeyPress(_) => keys += 1,
+    });
+    for _ in 0..3 {
+        registry.dispatch(&CLICK);
+    }
+    registry.dispatch(&Event::KeyPress('k'));
+    assert_eq!(registry.len(), 1);
+    drop(registry);
+    assert_eq!((clicks, keys), (3, 1));
+}
+
+#[test]
+fn an_empty_registry_dispatches_nothing() {
+    let mut registry = CallbackRegistry::new();
+    assert!(registry.is_empty());
+    registry.dispatch(&CLICK);
+    assert_eq!(registry.len(), 0);
+}
@@ -0,0 +1,267 @@
+use item_1_2::{
+    div, modify_all, sorted_lines, try_modify_all, try_modify_all_atomic, write_sorted,
+    write_sorted_dyn, DivError, Sort, SortAndIter, SortedList,
+};
+
+#[test]
+fn div_divides() {
+    assert_eq!(div(7.0, 2.0), Ok(3.5));
+    assert_eq!(div(-9.0, 3.0), Ok(-3.0));
+    assert_eq!(div(0.0, 5.0), Ok(0.0));
+}
+
+#[test]
+fn div_by_zero() {
+    assert_eq!(div(1.0, 0.0), Err(DivError::DivisionByZero));
+    assert_eq!(div(0.0, 0.0), Err(DivError::DivisionByZero));
+    // Negative zero is zero too.
+    assert_eq!(div(1.0, -0.0), Err(DivError::DivisionByZero));
+}
+
+#[test]
+fn div_rejects_non_finite_numbers() {
+    assert_eq!(div(f64::INFINITY, 2.0), Err(DivError::NonFinite));
+    assert_eq!(div(2.0, f64::NEG_INFINITY), Err(DivError::NonFinite));
+    assert_eq!(div(f64::NAN, 1.0), Err(DivError::NonFinite));
+    // Non-finite beats zero: there is no sensible answer either way.
+    assert_eq!(div(f64::NAN, 0.0), Err(DivError::NonFinite));
+    // Finite inputs whose quotient overflows.
+    assert_eq!(div(f64::MAX, 0.5), Err(DivError::NonFinite));
+}
+
+#[test]
+fn div_errors_display() {
+    assert_eq!(DivError::DivisionByZero.to_string(), "division by zero");
+    assert_eq!(DivError::NonFinite.to_string(), "non-finite number");
+}
+
+#[test]
+fn modify_all_with_a_counting_closure() {
+    let mut data = [5, 6, 7];
+    let mut calls = 0;
+    modify_all(&mut data, |value| {
+        calls += 1;
+        value + calls
+    });
+    assert_eq!(data, [6, 8, 10]);
+    assert_eq!(calls, 3);
+}
+
+#[test]
+fn modify_all_accepts_plain_functions() {
+    fn double(x: u32) -> u32 {
+        x * 2
+    }
+    let mut data = [1, 2];
+    modify_all(&mut data, double);
+    assert_eq!(data, [2, 4]);
+}
+
+#[test]
+fn modify_all_is_generic() {
+    let mut data = [1.5, -2.0];
+    modify_all(&mut data, f64::abs);
+    assert_eq!(data, [1.5, 2.0]);
+
+    let mut flags = [true, false];
+    modify_all(&mut flags, |flag: bool| !flag);
+    assert_eq!(flags, [false, true]);
+}
+
+fn checked_double(value: u32) -> Result<u32, &'static str> {
+    value.checked_mul(2).ok_or("overflow")
+}
+
+#[test]
+fn try_modify_all_succeeds() {
+    let mut data = [1, 2, 3];
+    assert_eq!(try_modify_all(&mut data, checked_double), Ok(3));
+    assert_eq!(data, [2, 4, 6]);
+
+    let mut data = [1, 2, 3];
+    assert_eq!(try_modify_all_atomic(&mut data, checked_double), Ok(3));
+    assert_eq!(data, [2, 4, 6]);
+
+    assert_eq!(try_modify_all(&mut [], checked_double), Ok(0));
+}
+
+#[test]
+fn try_modify_all_stops_part_way() {
+    let mut data = [1, 2, u32::MAX, 4];
+    let mut calls = 0;
+    let result = try_modify_all(&mut data, |value| {
+        calls += 1;
+        checked_double(value)
+    });
+    assert_eq!(result, Err((2, "overflow")));
+    // The mutator isn't called again after it fails, and what it already did stays done.
+    assert_eq!(calls, 3);
+    assert_eq!(data, [2, 4, u32::MAX, 4]);
+}
+
+#[test]
+fn try_modify_all_atomic_leaves_the_slice_alone_on_failure() {
+    let mut data = [1, 2, u32::MAX, 4];
+    let mut calls = 0;
+    let result = try_modify_all_atomic(&mut data, |value| {
+        calls += 1;
+        checked_double(value)
+    });
+    assert_eq!(result, Err((2, "overflow")));
+    assert_eq!(calls, 3);
+    assert_eq!(data, [1, 2, u32::MAX, 4]);
+}
+
+#[test]
+fn vec_sorts() {
+    let mut v = vec!["pear", "apple", "fig"];
+    Sort::sort(&mut v);
+    assert_eq!(v, ["apple", "fig", "pear"]);
+}
+
+#[test]
+fn vec_sorts_descending() {
+    let mut v = vec![2, 5, 1, 5];
+    v.sort_desc();
+    assert_eq!(v, [5, 5, 2, 1]);
+}
+
+#[test]
+fn sorted_list_sorts_when_asked() {
+    let mut list: SortedList<_> = [3, 1, 2].into_iter().collect();
+    assert!(!list.is_sorted());
+    list.sort();
+    assert!(list.is_sorted());
+    list.push(0);
+    assert!(!list.is_sorted());
+    list.sort_desc();
+    assert!(!list.is_sorted());
+    assert_eq!(list.iter().collect::<Vec<_>>(), [&3, &2, &1, &0]);
+    assert_eq!(list.into_iter().collect::<Vec<_>>(), [3, 2, 1, 0]);
+    assert!(SortedList::<i32>::default().is_sorted());
+}
+
+fn sorted_output<T>(collection: T) -> String
+where
+    T: Sort + IntoIterator,
+    T::Item: std::fmt::Debug,
+{
+    let mut out = Vec::new();
+    write_sorted(collection, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn write_sorted_a_vec() {
+    assert_eq!(sorted_output(vec![3, -1, 2]), "-1\n2\n3\n");
+    assert_eq!(sorted_output(Vec::<i32>::new()), "");
+}
+
+#[test]
+fn write_sorted_a_sorted_list() {
+    let list: SortedList<_> = ["pear", "apple", "fig"].into_iter().collect();
+    assert_eq!(sorted_output(list), "\"apple\"\n\"fig\"\n\"pear\"\n");
+}
+
+#[test]
+fn write_sorted_already_sorted() {
+    assert_eq!(sorted_output(vec![1, 2, 3]), "1\n2\n3\n");
+}
+
+// A `Debug` that spans two lines, as `{:#?}` output would.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct TwoLines(u8, u8);
+
+impl std::fmt::Debug for TwoLines {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\n{}", self.0, self.1)
+    }
+}
+
+#[test]
+fn write_sorted_multiline_debug() {
+    assert_eq!(
+        sorted_output(vec![TwoLines(2, 0), TwoLines(1, 9)]),
+        "1\n9\n2\n0\n"
+    );
+}
+
+#[test]
+fn sorted_lines_of_a_vec() {
+    let lines: Vec<String> = sorted_lines(vec![3, -1, 2]).collect();
+    assert_eq!(lines, ["-1", "2", "3"]);
+    let list: SortedList<_> = ["pear", "apple", "fig"].into_iter().collect();
+    let lines: Vec<String> = sorted_lines(list).collect();
+    assert_eq!(lines, ["\"apple\"", "\"fig\"", "\"pear\""]);
+}
+
+#[test]
+fn sorted_lines_of_nothing() {
+    assert_eq!(sorted_lines(Vec::<i32>::new()).count(), 0);
+}
+
+#[test]
+fn sorted_lines_already_sorted() {
+    let lines: Vec<String> = sorted_lines(vec![1, 2, 3]).collect();
+    assert_eq!(lines, ["1", "2", "3"]);
+    // The same lines `write_sorted` writes, without the newlines.
+    assert_eq!(lines.join("\n") + "\n", sorted_output(vec![3, 2, 1]));
+}
+
+#[test]
+fn sorted_lines_keeps_multiline_items_whole() {
+    let lines: Vec<String> = sorted_lines(vec![TwoLines(2, 0), TwoLines(1, 9)]).collect();
+    assert_eq!(lines, ["1\n9", "2\n0"]);
+}
+
+#[test]
+fn sorted_lines_formats_only_what_is_taken() {
+    use std::cell::Cell;
+
+    // Counts how many times it has been formatted.
+    #[derive(PartialEq, Eq, PartialOrd, Ord)]
+    struct Counted<'a>(u32, &'a Cell<u32>);
+
+    impl std::fmt::Debug for Counted<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.1.set(self.1.get() + 1);
+            write!(f, "{}", self.0)
+        }
+    }
+
+    let formatted = Cell::new(0);
+    let items: Vec<_> = (0..1000).rev().map(|n| Counted(n, &formatted)).collect();
+    let first: Vec<String> = sorted_lines(items).take(2).collect();
+    assert_eq!(first, ["0", "1"]);
+    assert_eq!(formatted.get(), 2);
+}
+
+fn sorted_output_dyn(collection: &mut dyn SortAndIter) -> String {
+    let mut out = Vec::new();
+    write_sorted_dyn(collection, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn write_sorted_dyn_matches_the_generic_version() {
+    assert_eq!(sorted_output_dyn(&mut vec![3, -1, 2]), "-1\n2\n3\n");
+    let list: SortedList<_> = ["pear", "apple", "fig"].into_iter().collect();
+    assert_eq!(
+        sorted_output_dyn(&mut list.clone()),
+        sorted_output(list.clone())
+    );
+    // One function, called with different types in the same collection.
+    let mut collections: Vec<Box<dyn SortAndIter>> = vec![Box::new(vec![2u64, 1]), Box::new(list)];
+    let outputs: Vec<String> = collections
+        .iter_mut()
+        .map(|c| sorted_output_dyn(c.as_mut()))
+        .collect();
+    assert_eq!(outputs, ["1\n2\n", "\"apple\"\n\"fig\"\n\"pear\"\n"]);
+}
+
+#[test]
+fn write_sorted_dyn_sorts_in_place() {
+    let mut v = vec![5, 4, 3];
+    sorted_output_dyn(&mut v);
+    assert_eq!(v, [3, 4, 5]);
+}
@@ -0,0 +1,42 @@
+use item_1_2::{modify_all, par_modify_all};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn parallel_matches_sequential() {
+    let original: Vec<f64> = (0..100_000).map(|i| f64::from(i) * 0.25 - 1000.0).collect();
+    let mut sequential = original.clone();
+    let mut parallel = original;
+    modify_all(&mut sequential, |x| x.mul_add(1.5, 2.0).sqrt());
+    par_modify_all(&mut parallel, |x| x.mul_add(1.5, 2.0).sqrt());
+    // Each element goes through the same operations either way, so the results are identical,
+    // NaNs from the negative square roots included.
+    assert_eq!(
+        sequential.iter().map(|x| x.to_bits()).collect::<Vec<_>>(),
+        parallel.iter().map(|x| x.to_bits()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn parallel_works_on_values_that_are_not_copy() {
+    let mut words: Vec<String> = ["one", "two", "three"].map(String::from).to_vec();
+    par_modify_all(&mut words, |word| word.to_uppercase());
+    assert_eq!(words, ["ONE", "TWO", "THREE"]);
+}
+
+#[test]
+fn parallel_counts_calls_atomically() {
+    let mut data: Vec<u32> = (0..10_000).collect();
+    let calls = AtomicUsize::new(0);
+    par_modify_all(&mut data, |&value| {
+        calls.fetch_add(1, Ordering::Relaxed);
+        value * 2
+    });
+    assert_eq!(calls.into_inner(), 10_000);
+    assert!(data.iter().zip(0..).all(|(&v, i)| v == i * 2));
+}
+
+#[test]
+fn parallel_on_an_empty_slice() {
+    let mut data: [u8; 0] = [];
+    par_modify_all(&mut data, |&value| value + 1);
+}
@@ -0,0 +1,93 @@
+use item_1_2::scene::{load_scene, save_scene, SceneError};
+use item_1_2::Shape;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use test_support::temp_file::TempFile;
+
+fn every_variant() -> Vec<Shape> {
+    vec![
+        Shape::Rectangle {
+            width: 3.0,
+            height: 4.5,
+        },
+        Shape::Circle { radius: 2.0 },
+        Shape::Triangle {
+            a: 3.0,
+            b: 4.0,
+            c: 5.0,
+        },
+    ]
+}
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+#[test]
+fn every_variant_round_trips() {
+    for shape in every_variant() {
+        let json = serde_json::to_string(&shape).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Shape>(&json).unwrap(),
+            shape,
+            "{json}"
+        );
+    }
+}
+
+#[test]
+fn json_is_tagged_with_the_variant() {
+    assert_eq!(
+        serde_json::to_string(&Shape::Circle { radius: 2.0 }).unwrap(),
+        r#"{"kind":"Circle","radius":2.0}"#
+    );
+    let shape: Shape =
+        serde_json::from_str(r#"{"width": 1, "kind": "Rectangle", "height": 2}"#).unwrap();
+    assert_eq!(
+        shape,
+        Shape::Rectangle {
+            width: 1.0,
+            height: 2.0
+        }
+    );
+}
+
+#[test]
+fn scenes_round_trip_through_a_file() {
+    let file = TempFile::new("");
+    save_scene(file.path(), &every_variant()).unwrap();
+    assert_eq!(load_scene(file.path()).unwrap(), every_variant());
+
+    save_scene(file.path(), &[]).unwrap();
+    assert_eq!(load_scene(file.path()).unwrap(), []);
+}
+
+#[test]
+fn malformed_json() {
+    let err = load_scene(fixture("malformed_scene.json")).unwrap_err();
+    let SceneError::Json(json) = &err else {
+        panic!("expected a JSON error, got {err:?}");
+    };
+    assert!(json.is_data());
+    assert_eq!(json.line(), 3);
+    assert!(err
+        .to_string()
+        .starts_with("the scene file is not a list of shapes (line 3,"));
+    assert!(err
+        .source()
+        .unwrap()
+        .to_string()
+        .contains("unknown variant `Hexagon`"));
+
+    let file = TempFile::new("[{\"kind\": \"Circle\", ");
+    assert!(matches!(load_scene(file.path()), Err(SceneError::Json(e)) if e.is_eof()));
+}
+
+#[test]
+fn missing_file() {
+    let err = load_scene(fixture("no_such_scene.json")).unwrap_err();
+    assert!(matches!(&err, SceneError::Io(e) if e.kind() == std::io::ErrorKind::NotFound));
+    assert_eq!(err.to_string(), "failed to read or write the scene file");
+}
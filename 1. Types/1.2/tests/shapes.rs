@@ -0,0 +1,311 @@
+use item_1_2::{parse_shapes, ParseShapeError, Shape, ShapeError};
+use std::f64::consts::PI;
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() <= 1e-9 * expected.abs().max(1.0),
+        "{actual} is not close to {expected}"
+    );
+}
+
+fn rectangle() -> Shape {
+    Shape::Rectangle {
+        width: 3.0,
+        height: 4.5,
+    }
+}
+
+fn circle() -> Shape {
+    Shape::Circle { radius: 2.0 }
+}
+
+fn right_triangle() -> Shape {
+    Shape::Triangle {
+        a: 3.0,
+        b: 4.0,
+        c: 5.0,
+    }
+}
+
+#[test]
+fn rectangle_area_and_perimeter() {
+    assert_eq!(rectangle().area(), 13.5);
+    assert_eq!(rectangle().perimeter(), 15.0);
+}
+
+#[test]
+fn circle_area_and_perimeter() {
+    assert_eq!(Shape::Circle { radius: 1.0 }.area(), PI);
+    assert_close(circle().area(), 12.566_370_614_359_172);
+    assert_close(circle().perimeter(), 12.566_370_614_359_172);
+}
+
+#[test]
+fn triangle_area_and_perimeter() {
+    assert_close(right_triangle().area(), 6.0);
+    assert_eq!(right_triangle().perimeter(), 12.0);
+    // Equilateral with side 2: area is sqrt(3).
+    let equilateral = Shape::try_new_triangle(2.0, 2.0, 2.0).unwrap();
+    assert_close(equilateral.area(), 3f64.sqrt());
+    assert_eq!(equilateral.perimeter(), 6.0);
+}
+
+#[test]
+fn scaling_multiplies_area_by_the_square() {
+    for shape in [rectangle(), circle(), right_triangle()] {
+        for k in [0.5, 2.0, 3.0, 10.0] {
+            let mut scaled = shape.clone();
+            scaled.scale(k);
+            assert_close(scaled.area(), shape.area() * k * k);
+            assert_close(scaled.perimeter(), shape.perimeter() * k);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "scale factor must be positive and finite, not -1")]
+fn scaling_by_a_negative_factor_panics() {
+    circle().scale(-1.0);
+}
+
+#[test]
+fn bounding_boxes() {
+    let size = |shape: Shape| match shape.bounding_box() {
+        Shape::Rectangle { width, height } => (width, height),
+        other => panic!("bounding box {other:?} is not a rectangle"),
+    };
+    assert_eq!(size(rectangle()), (3.0, 4.5));
+    assert_eq!(size(circle()), (4.0, 4.0));
+    // Lying on its hypotenuse, the 3-4-5 triangle is 12/5 high.
+    let (width, height) = size(right_triangle());
+    assert_close(width, 5.0);
+    assert_close(height, 2.4);
+    // An obtuse triangle overhangs its base: corners (0, 0), (1, 0) and (-1.5, 1).
+    let obtuse = Shape::try_new_triangle(2.5f64.hypot(1.0), 1.5f64.hypot(1.0), 1.0).unwrap();
+    let (width, height) = size(obtuse);
+    assert_close(width, 2.5);
+    assert_close(height, 1.0);
+}
+
+#[test]
+fn constructors_accept_good_lengths() {
+    assert_eq!(Shape::try_new_rectangle(3.0, 4.5), Ok(rectangle()));
+    assert_eq!(Shape::try_new_circle(2.0), Ok(circle()));
+    assert_eq!(Shape::try_new_triangle(3.0, 4.0, 5.0), Ok(right_triangle()));
+}
+
+#[test]
+fn constructors_reject_bad_lengths() {
+    assert_eq!(
+        Shape::try_new_rectangle(-1.0, 2.0),
+        Err(ShapeError::InvalidLength(-1.0))
+    );
+    assert_eq!(
+        Shape::try_new_rectangle(1.0, 0.0),
+        Err(ShapeError::InvalidLength(0.0))
+    );
+    assert_eq!(
+        Shape::try_new_circle(f64::INFINITY),
+        Err(ShapeError::InvalidLength(f64::INFINITY))
+    );
+    assert!(matches!(
+        Shape::try_new_circle(f64::NAN),
+        Err(ShapeError::InvalidLength(len)) if len.is_nan()
+    ));
+    assert_eq!(
+        Shape::try_new_triangle(3.0, -4.0, 5.0),
+        Err(ShapeError::InvalidLength(-4.0))
+    );
+}
+
+#[test]
+fn constructors_reject_impossible_triangles() {
+    // Too long a side, in each position, and a triangle flattened into a line.
+    for (a, b, c) in [
+        (10.0, 1.0, 1.0),
+        (1.0, 10.0, 1.0),
+        (1.0, 1.0, 10.0),
+        (1.0, 2.0, 3.0),
+    ] {
+        assert_eq!(
+            Shape::try_new_triangle(a, b, c),
+            Err(ShapeError::TriangleInequality { a, b, c })
+        );
+    }
+    assert_eq!(
+        ShapeError::TriangleInequality {
+            a: 1.0,
+            b: 2.0,
+            c: 3.0
+        }
+        .to_string(),
+        "sides 1, 2 and 3 don't make a triangle"
+    );
+    assert_eq!(
+        ShapeError::InvalidLength(-2.5).to_string(),
+        "length -2.5 is not a positive, finite number"
+    );
+}
+
+// TEXT FORM
+
+#[test]
+fn display_writes_the_text_form() {
+    assert_eq!(rectangle().to_string(), "rect 3.0x4.5");
+    assert_eq!(circle().to_string(), "circle r=2.0");
+    assert_eq!(right_triangle().to_string(), "triangle 3.0,4.0,5.0");
+}
+
+#[test]
+fn every_variant_round_trips() {
+    let shapes = [
+        rectangle(),
+        circle(),
+        right_triangle(),
+        Shape::Circle { radius: 0.1 + 0.2 },
+        Shape::Rectangle {
+            width: 1e-7,
+            height: 2.5e21,
+        },
+    ];
+    for shape in shapes {
+        assert_eq!(shape.to_string().parse::<Shape>(), Ok(shape.clone()));
+    }
+}
+
+#[test]
+fn parsing_ignores_case_and_spacing() {
+    assert_eq!("  circle   r=2 ".parse(), Ok(circle()));
+    assert_eq!("CIRCLE R = 2".parse(), Ok(circle()));
+    assert_eq!("circle 2".parse(), Ok(circle()));
+    assert_eq!("Rect 3 X 4.5".parse(), Ok(rectangle()));
+    assert_eq!("rect\t3x4.5".parse(), Ok(rectangle()));
+    assert_eq!("triangle 3, 4 ,5".parse(), Ok(right_triangle()));
+}
+
+fn parse(text: &str) -> ParseShapeError {
+    text.parse::<Shape>().unwrap_err()
+}
+
+#[test]
+fn unknown_and_empty() {
+    assert_eq!(
+        parse("square 2"),
+        ParseShapeError::UnknownShape("square".to_owned())
+    );
+    assert_eq!(
+        parse("rectangle 2x3"),
+        ParseShapeError::UnknownShape("rectangle".to_owned())
+    );
+    assert_eq!(parse(""), ParseShapeError::Empty);
+    assert_eq!(parse(" \t "), ParseShapeError::Empty);
+}
+
+#[test]
+fn missing_dimensions() {
+    assert_eq!(parse("rect"), ParseShapeError::MissingDimension("width"));
+    assert_eq!(parse("rect 3"), ParseShapeError::MissingDimension("height"));
+    assert_eq!(
+        parse("rect 3x "),
+        ParseShapeError::MissingDimension("height")
+    );
+    assert_eq!(parse("circle"), ParseShapeError::MissingDimension("radius"));
+    assert_eq!(
+        parse("circle r="),
+        ParseShapeError::MissingDimension("radius")
+    );
+    assert_eq!(
+        parse("triangle 3,4"),
+        ParseShapeError::MissingDimension("side c")
+    );
+    assert_eq!(
+        parse("triangle ,4,5"),
+        ParseShapeError::MissingDimension("side a")
+    );
+}
+
+#[test]
+fn values_that_arent_numbers() {
+    let e = parse("rect 3xfour");
+    assert!(
+        matches!(&e, ParseShapeError::NotANumber { dimension: "height", text, .. } if text == "four"),
+        "{e:?}"
+    );
+    assert_eq!(e.to_string(), "height \"four\" is not a number");
+    // The float's own error is the cause.
+    let source = std::error::Error::source(&e).unwrap();
+    assert_eq!(
+        source.to_string(),
+        "four".parse::<f64>().unwrap_err().to_string()
+    );
+
+    assert!(matches!(
+        parse("circle r=2 3"),
+        ParseShapeError::NotANumber {
+            dimension: "radius",
+            ..
+        }
+    ));
+    assert!(matches!(
+        parse("triangle 3,4,5,6"),
+        ParseShapeError::NotANumber {
+            dimension: "side c",
+            ..
+        }
+    ));
+}
+
+#[test]
+fn numbers_that_dont_make_a_shape() {
+    assert_eq!(
+        parse("circle r=-1"),
+        ParseShapeError::Invalid(ShapeError::InvalidLength(-1.0))
+    );
+    let e = parse("triangle 1,2,3");
+    assert!(matches!(
+        e,
+        ParseShapeError::Invalid(ShapeError::TriangleInequality { .. })
+    ));
+    assert_eq!(e.to_string(), "not a valid shape");
+    assert_eq!(
+        std::error::Error::source(&e).unwrap().to_string(),
+        "sides 1, 2 and 3 don't make a triangle"
+    );
+}
+
+#[test]
+fn parse_error_messages() {
+    assert_eq!(ParseShapeError::Empty.to_string(), "no shape given");
+    assert_eq!(
+        ParseShapeError::UnknownShape("hexagon".to_owned()).to_string(),
+        "unknown shape \"hexagon\""
+    );
+    assert_eq!(
+        ParseShapeError::MissingDimension("width").to_string(),
+        "missing width"
+    );
+}
+
+#[test]
+fn parse_shapes_reads_one_per_line() {
+    let input = "rect 3x4.5\n\n  circle r=2\n   \ntriangle 3,4,5\n";
+    assert_eq!(
+        parse_shapes(input),
+        Ok(vec![rectangle(), circle(), right_triangle()])
+    );
+    assert_eq!(parse_shapes(""), Ok(Vec::new()));
+}
+
+#[test]
+fn parse_shapes_reports_the_failing_line() {
+    // Line numbers count the blank lines too.
+    let input = "circle r=1\n\nrect 2x\ncircle r=oops";
+    assert_eq!(
+        parse_shapes(input),
+        Err((3, ParseShapeError::MissingDimension("height")))
+    );
+    assert_eq!(
+        parse_shapes("circle 1\nblob").unwrap_err(),
+        (2, ParseShapeError::UnknownShape("blob".to_owned()))
+    );
+}
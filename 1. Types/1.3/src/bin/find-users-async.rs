@@ -0,0 +1,38 @@
+// Looking up several users in the same password file at once, each with its own timeout.
+// Needs the `async` feature:
+//
+//     cargo run -p item-1-3 --features async --bin find-users-async
+
+use futures::future::join_all;
+use item_1_3::passwd_async::{find_user_async, with_timeout};
+use std::path::Path;
+use std::time::Duration;
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::env::temp_dir().join(format!("find-users-async-{}", std::process::id()));
+    tokio::fs::write(
+        &path,
+        "root:x:0:0:root:/root:/bin/sh\nalice:x:1000:1000:Alice:/home/alice:/bin/sh\n",
+    )
+    .await?;
+
+    let usernames = ["root", "alice", "bob"];
+    let lookups = usernames.map(|username| lookup(&path, username));
+    for (username, result) in usernames.iter().zip(join_all(lookups).await) {
+        match result {
+            Ok(uid) => println!("{username} has uid {}", uid.0),
+            Err(e) => println!("{username}: {e}"),
+        }
+    }
+
+    tokio::fs::remove_file(&path).await?;
+    Ok(())
+}
+
+async fn lookup(
+    path: &Path,
+    username: &str,
+) -> Result<item_1_3::UserId, item_1_3::passwd_async::FindUserAsyncError> {
+    with_timeout(Duration::from_secs(1), find_user_async(path, username)).await
+}
@@ -0,0 +1,144 @@
+// Ciphers for the `InputData::encrypted` example. Neither of these is secure; they are here so
+// that the example encrypts something and the result can be checked by decrypting it again.
+//
+// `encrypt` always builds a new `Vec`, even when the ciphertext would be the same bytes as the
+// input. `encrypt_cow` returns a `Cow` instead, which a cipher can use to hand back a borrow
+// of its input when there is nothing to change: `IdentityCipher` always, `XorCipher` with a
+// zero key, and `Rot13Cipher` for data without a letter in it.
+
+use std::borrow::Cow;
+
+pub trait Cipher {
+    fn encrypt(&self, data: &[u8]) -> Vec<u8>;
+    fn decrypt(&self, data: &[u8]) -> Vec<u8>;
+
+    /// `encrypt`, borrowing `data` rather than copying it if it comes out unchanged. By
+    /// default it is always copied.
+    fn encrypt_cow<'a>(&self, data: &'a [u8]) -> Cow<'a, [u8]> {
+        Cow::Owned(self.encrypt(data))
+    }
+}
+
+/// Leaves the data as it is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IdentityCipher;
+
+impl Cipher for IdentityCipher {
+    fn encrypt(&self, data: &[u8]) -> Vec<u8> {
+        data.to_vec()
+    }
+
+    fn decrypt(&self, data: &[u8]) -> Vec<u8> {
+        data.to_vec()
+    }
+
+    fn encrypt_cow<'a>(&self, data: &'a [u8]) -> Cow<'a, [u8]> {
+        Cow::Borrowed(data)
+    }
+}
+
+/// XORs every byte with `key`, which makes it its own inverse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XorCipher {
+    pub key: u8,
+}
+
+impl Cipher for XorCipher {
+    fn encrypt(&self, data: &[u8]) -> Vec<u8> {
+        data.iter().map(|byte| byte ^ self.key).collect()
+    }
+
+    fn decrypt(&self, data: &[u8]) -> Vec<u8> {
+        self.encrypt(data)
+    }
+
+    fn encrypt_cow<'a>(&self, data: &'a [u8]) -> Cow<'a, [u8]> {
+        if self.key == 0 {
+            Cow::Borrowed(data)
+        } else {
+            Cow::Owned(self.encrypt(data))
+        }
+    }
+}
+
+/// Rotates ASCII letters 13 places through the alphabet, leaving every other byte alone.
+/// Rotating twice goes all the way round, so this is its own inverse too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Rot13Cipher;
+
+fn rot13(byte: u8) -> u8 {
+    match byte {
+        b'a'..=b'z' => (byte - b'a' + 13) % 26 + b'a',
+        b'A'..=b'Z' => (byte - b'A' + 13) % 26 + b'A',
+        _ => byte,
+    }
+}
+
+impl Cipher for Rot13Cipher {
+    fn encrypt(&self, data: &[u8]) -> Vec<u8> {
+        data.iter().copied().map(rot13).collect()
+    }
+
+    fn decrypt(&self, data: &[u8]) -> Vec<u8> {
+        self.encrypt(data)
+    }
+
+    fn encrypt_cow<'a>(&self, data: &'a [u8]) -> Cow<'a, [u8]> {
+        if data.iter().any(u8::is_ascii_alphabetic) {
+            Cow::Owned(self.encrypt(data))
+        } else {
+            Cow::Borrowed(data)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InputData {
+    pub payload: Option<Vec<u8>>,
+}
+
+impl InputData {
+    /// The payload encrypted with `cipher`; a missing payload encrypts like an empty one.
+    pub fn encrypted(&self, cipher: &dyn Cipher) -> Vec<u8> {
+        // `as_deref` turns the `&Option<Vec<u8>>` into an `Option<&[u8]>`, and the empty slice
+        // is `&[u8]`'s default, so no `Vec` is needed to stand in for a missing payload.
+        cipher.encrypt(self.payload.as_deref().unwrap_or_default())
+    }
+
+    /// The payload, borrowed, or an empty one if there is none. An empty `Vec` doesn't
+    /// allocate, so neither case copies anything.
+    pub fn payload_or_default(&self) -> Cow<'_, [u8]> {
+        match &self.payload {
+            Some(payload) => Cow::Borrowed(payload),
+            None => Cow::Owned(Vec::new()),
+        }
+    }
+
+    /// `encrypted`, without a copy of the payload if the cipher leaves it unchanged.
+    pub fn encrypted_cow(&self, cipher: &dyn Cipher) -> Cow<'_, [u8]> {
+        match self.payload_or_default() {
+            Cow::Borrowed(payload) => cipher.encrypt_cow(payload),
+            Cow::Owned(payload) => Cow::Owned(cipher.encrypt(&payload)),
+        }
+    }
+}
+
+/// Encrypt one payload with each cipher, and say which of them had to copy it.
+pub fn allocations_demo() {
+    let input = InputData {
+        payload: Some(b"1234-5678".to_vec()),
+    };
+    let ciphers: [(&str, &dyn Cipher); 4] = [
+        ("identity", &IdentityCipher),
+        ("xor 0x00", &XorCipher { key: 0 }),
+        ("xor 0x2a", &XorCipher { key: 0x2a }),
+        ("rot13", &Rot13Cipher),
+    ];
+    for (name, cipher) in ciphers {
+        let how = match input.encrypted_cow(cipher) {
+            Cow::Borrowed(_) => "borrowed",
+            Cow::Owned(_) => "copied",
+        };
+        println!("{name}: {how}");
+    }
+}
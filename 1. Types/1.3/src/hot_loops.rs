@@ -0,0 +1,133 @@
+// The loops behind the `transforms-bench` criterion benchmarks, and bench-lite's `option_sum/`
+// and `result_pipeline/` groups, which test the claim that a combinator costs no more than the
+// `match` it replaces. Each loop comes in three versions: `*_match` written out longhand,
+// `*_combinator` using the `Option` and `Result` methods, and `*_inline_never`, which uses the
+// same methods but calls a function that the compiler isn't allowed to inline for every
+// element. The first two should take the same time; the third shows what inlining is worth.
+//
+// Clippy would write the `match` versions with combinators; here the longhand is the point.
+#![allow(clippy::manual_unwrap_or, clippy::manual_unwrap_or_default)]
+
+use std::fmt;
+
+// OPTION: SUMMING THE VALUES THAT ARE THERE
+
+pub fn sum_some_match(values: &[Option<u64>]) -> u64 {
+    let mut total = 0;
+    for value in values {
+        total += match value {
+            Some(v) => *v,
+            None => 0,
+        };
+    }
+    total
+}
+
+pub fn sum_some_combinator(values: &[Option<u64>]) -> u64 {
+    values.iter().map(|value| value.unwrap_or(0)).sum()
+}
+
+#[inline(never)]
+fn value_or_zero(value: &Option<u64>) -> u64 {
+    value.unwrap_or(0)
+}
+
+pub fn sum_some_inline_never(values: &[Option<u64>]) -> u64 {
+    values.iter().map(value_or_zero).sum()
+}
+
+// RESULT: A THREE-STAGE PIPELINE
+// Each number is scaled, offset and checked against a limit, and any stage can fail. The
+// loops add up the numbers that make it through and count the ones that don't.
+
+/// Numbers at or above this fail the last stage.
+pub const LIMIT: u64 = 1 << 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PipelineError {
+    /// Scaling or offsetting the number overflowed.
+    Overflow,
+    /// The number came out at or above [`LIMIT`].
+    TooLarge(u64),
+}
+
+impl fmt::Display for PipelineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PipelineError::Overflow => write!(f, "arithmetic overflow"),
+            PipelineError::TooLarge(n) => write!(f, "{n} is not below {LIMIT}"),
+        }
+    }
+}
+
+impl std::error::Error for PipelineError {}
+
+#[inline]
+pub fn scale(n: u64) -> Result<u64, PipelineError> {
+    n.checked_mul(3).ok_or(PipelineError::Overflow)
+}
+
+#[inline]
+pub fn offset(n: u64) -> Result<u64, PipelineError> {
+    n.checked_add(7).ok_or(PipelineError::Overflow)
+}
+
+#[inline]
+pub fn limit(n: u64) -> Result<u64, PipelineError> {
+    if n < LIMIT {
+        Ok(n)
+    } else {
+        Err(PipelineError::TooLarge(n))
+    }
+}
+
+/// All three stages on one number, longhand.
+pub fn process_match(n: u64) -> Result<u64, PipelineError> {
+    match scale(n) {
+        Ok(n) => match offset(n) {
+            Ok(n) => limit(n),
+            Err(e) => Err(e),
+        },
+        Err(e) => Err(e),
+    }
+}
+
+/// All three stages on one number, chained with `and_then`.
+pub fn process_combinator(n: u64) -> Result<u64, PipelineError> {
+    scale(n).and_then(offset).and_then(limit)
+}
+
+#[inline(never)]
+fn process_not_inlined(n: u64) -> Result<u64, PipelineError> {
+    process_combinator(n)
+}
+
+/// The total of the numbers that made it through, and how many didn't.
+pub fn pipeline_match(numbers: &[u64]) -> (u64, usize) {
+    let (mut total, mut failed) = (0, 0);
+    for &n in numbers {
+        match process_match(n) {
+            Ok(n) => total += n,
+            Err(_) => failed += 1,
+        }
+    }
+    (total, failed)
+}
+
+pub fn pipeline_combinator(numbers: &[u64]) -> (u64, usize) {
+    numbers
+        .iter()
+        .map(|&n| process_combinator(n))
+        .fold((0, 0), |(total, failed), result| {
+            result.map_or((total, failed + 1), |n| (total + n, failed))
+        })
+}
+
+pub fn pipeline_inline_never(numbers: &[u64]) -> (u64, usize) {
+    numbers
+        .iter()
+        .map(|&n| process_not_inlined(n))
+        .fold((0, 0), |(total, failed), result| {
+            result.map_or((total, failed + 1), |n| (total + n, failed))
+        })
+}
@@ -0,0 +1,137 @@
+// Item 1.3: Prefer Option and Result transforms over explicit match expressions.
+
+pub mod cipher;
+pub mod hot_loops;
+mod logging;
+pub mod option_transforms;
+pub mod passwd;
+#[cfg(feature = "async")]
+pub mod passwd_async;
+pub mod result_transforms;
+
+pub use cipher::{Cipher, IdentityCipher, InputData, Rot13Cipher, XorCipher};
+pub use passwd::{find_user, FindUserError, ParseError, PasswdEntry, UserId};
+
+pub const DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
+
+// Several snippets below only show what a definition looks like, and are never used.
+#[allow(dead_code, unused_variables, clippy::single_match)]
+pub fn run() -> Result<(), Box<dyn std::error::Error>> {
+    // Sometimes match statements are unnecessary. For example if only the value is relevant and
+    // the absence of the value can be ignored:
+    struct File {
+        title: Option<String>,
+    }
+
+    let file = File {
+        title: Some(String::from("report.pdf")),
+    };
+    match &file.title {
+        Some(t) => println!("fields is {t}"),
+        None => {}
+    }
+
+    // Use an if let expression in this case:
+    if let Some(t) = &file.title {
+        println!("fields is {t}");
+    }
+
+    // Most other things done with an `Option` have a method for them. `option_transforms` has
+    // ten, each next to the `match` it replaces.
+    let file = option_transforms::File {
+        title: Some("report.pdf".to_owned()),
+        size: None,
+    };
+    println!(
+        "extension {:?}, title and size {:?}",
+        file.extension_combinator(),
+        file.title_and_size_combinator()
+    );
+
+    // In the case of a Result<T, E> a match expressions can be used to handle errors:
+    let result = std::fs::File::open("/etc/passwd");
+    let f = match result {
+        Ok(f) => f,
+        Err(_e) => panic!("Failed to open /etc/passwd!"),
+    };
+    // Assume `f` is a valid `std::fs::File` from here onward.
+
+    // Both Option and Result provide a pair of methods that extract their inner value and panic!
+    // unwrap and expect.
+    let f = std::fs::File::open("/etc/passwd").unwrap();
+    // expect is the same but with a custom error message:
+    let f = std::fs::File::open("/etc/passwd").expect("Could not open /etc/passwd");
+
+    // The key ingredient for reducing boilerplate code in Rust's question mark operator. This
+    // piece of syntactic sugar takes care of matching the Err arm, transforming the error type
+    // if necessary and building the return Err(...) expression, all in a single character:
+    {
+        pub fn find_user(username: &str) -> Result<UserId, FindUserError> {
+            // `io::Error` becomes `FindUserError` through its `From` impl.
+            let f = std::fs::File::open("/etc/passwd")?;
+            passwd::find_user(std::io::BufReader::new(f), username)
+        }
+    }
+    // The whole of `passwd::find_user` is in the `passwd` module; here it is on a file in memory.
+    let fixture = "root:x:0:0:root:/root:/bin/sh\nalice:x:1000:1000:Alice:/home/alice:/bin/sh\n";
+    let uid = passwd::find_user(fixture.as_bytes(), "alice")?;
+    println!("alice has uid {}", uid.0);
+    if let Err(e) = passwd::find_user(fixture.as_bytes(), "bob") {
+        println!("{e}");
+    }
+
+    // There generally no cost to these apparent method invocations. They are all generic functions
+    // marked as #[inline] so the generated code will typically compile to machine code that's
+    // identical to the manual version. `cargo bench -p transforms-bench` times the loops in
+    // `hot_loops` with criterion to check, alongside versions where inlining is ruled out, as
+    // do bench-lite's `option_sum/` and `result_pipeline/` groups.
+    //
+    // This means that you should always prefer Option and Result
+    // transforms over explicit match expressions.
+
+    // If a function accumulates errors from a variety of different libraries, use map_err:
+    {
+        pub fn find_user(username: &str) -> Result<UserId, String> {
+            let f = std::fs::File::open("/etc/passwd")
+                .map_err(|e| format!("Failed to open password file: {:?}", e))?;
+            passwd::find_user(std::io::BufReader::new(f), username).map_err(|e| e.to_string())
+        }
+    }
+
+    // `Result` has as many transforms as `Option`, plus the bridges between the two; see
+    // `result_transforms`.
+    let user = result_transforms::optional_user_combinator(Some("1000"))?;
+    println!("optional user: {user:?}");
+    if let Some(e) = result_transforms::failure_combinator("42") {
+        println!("{e}");
+    }
+
+    // Use .as_ref() as needed when transformations involve references. A first attempt at
+    // encrypting an optional payload might be:
+    //
+    //     encrypt(self.payload.as_ref().unwrap_or(&vec![]))
+    //
+    // which works, because the temporary `Vec` lives until the end of the statement, but builds
+    // a `Vec` just to borrow it as a slice. `as_deref` goes straight from `&Option<Vec<u8>>` to
+    // `Option<&[u8]>`, and `unwrap_or_default` supplies the empty slice; see
+    // `InputData::encrypted` in the `cipher` module.
+    let input = InputData {
+        payload: Some(b"Hello".to_vec()),
+    };
+    let secret = input.encrypted(&Rot13Cipher);
+    println!("rot13: {}", String::from_utf8_lossy(&secret));
+    let xor = XorCipher { key: 0x2a };
+    let secret = input.encrypted(&xor);
+    println!(
+        "xor: {secret:02x?}, decrypted {:?}",
+        String::from_utf8_lossy(&xor.decrypt(&secret))
+    );
+    assert!(InputData { payload: None }.encrypted(&xor).is_empty());
+
+    // Even `as_deref` leaves `encrypted` copying the payload into a new `Vec` when the cipher
+    // changes nothing. Returning a `Cow` lets it hand back the original instead; see
+    // `InputData::encrypted_cow`.
+    cipher::allocations_demo();
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+// `log_debug!` and `log_warn!`, which go to the `log` facade with the `logging` feature, and do
+// nothing without it. Either way the arguments are type-checked and count as used, so the code
+// that logs needs no `#[cfg]` of its own, and a message can't go stale while the feature is off.
+
+macro_rules! log_debug {
+    ($($arg:tt)+) => {{
+        #[cfg(feature = "logging")]
+        log::debug!($($arg)+);
+        #[cfg(not(feature = "logging"))]
+        let _ = format_args!($($arg)+);
+    }};
+}
+
+macro_rules! log_warn {
+    ($($arg:tt)+) => {{
+        #[cfg(feature = "logging")]
+        log::warn!($($arg)+);
+        #[cfg(not(feature = "logging"))]
+        let _ = format_args!($($arg)+);
+    }};
+}
+
+pub(crate) use {log_debug, log_warn};
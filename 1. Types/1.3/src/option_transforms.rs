@@ -0,0 +1,168 @@
+// Ten things to do with a `File`'s optional fields, each written twice: `*_match` spells it
+// out with `match`, and `*_combinator` uses the `Option` method that does the same thing. The
+// tests check that every pair agrees on every input, `None`s included, so this is a table of
+// equivalences that can be trusted.
+//
+// Clippy would suggest the combinator for several of the `match` versions; here the longhand is
+// the point.
+#![allow(
+    clippy::manual_map,
+    clippy::mem_replace_option_with_none,
+    clippy::mem_replace_option_with_some
+)]
+
+/// A field that had to be there but wasn't.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MissingField(pub &'static str);
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct File {
+    pub title: Option<String>,
+    /// In bytes.
+    pub size: Option<u64>,
+}
+
+impl File {
+    // `map`: transform the value, if there is one.
+
+    pub fn title_len_match(&self) -> Option<usize> {
+        match &self.title {
+            Some(title) => Some(title.len()),
+            None => None,
+        }
+    }
+
+    pub fn title_len_combinator(&self) -> Option<usize> {
+        self.title.as_ref().map(String::len)
+    }
+
+    // `and_then`: a transform that may itself have nothing to return.
+
+    /// What follows the last `.` in the title.
+    pub fn extension_match(&self) -> Option<&str> {
+        match &self.title {
+            Some(title) => match title.rsplit_once('.') {
+                Some((_, extension)) => Some(extension),
+                None => None,
+            },
+            None => None,
+        }
+    }
+
+    pub fn extension_combinator(&self) -> Option<&str> {
+        self.title
+            .as_ref()
+            .and_then(|title| title.rsplit_once('.'))
+            .map(|(_, extension)| extension)
+    }
+
+    // `ok_or`: no value becomes an error.
+
+    pub fn size_or_err_match(&self) -> Result<u64, MissingField> {
+        match self.size {
+            Some(size) => Ok(size),
+            None => Err(MissingField("size")),
+        }
+    }
+
+    pub fn size_or_err_combinator(&self) -> Result<u64, MissingField> {
+        self.size.ok_or(MissingField("size"))
+    }
+
+    // `filter`: keep the value only if it passes a test.
+
+    pub fn nonempty_title_match(&self) -> Option<&String> {
+        match &self.title {
+            Some(title) if !title.is_empty() => Some(title),
+            _ => None,
+        }
+    }
+
+    pub fn nonempty_title_combinator(&self) -> Option<&String> {
+        self.title.as_ref().filter(|title| !title.is_empty())
+    }
+
+    // `unwrap_or_else`: a fallback that is only worked out when it's needed.
+
+    pub fn display_title_match(&self) -> String {
+        match &self.title {
+            Some(title) => title.clone(),
+            None => format!("untitled ({} bytes)", self.size.unwrap_or(0)),
+        }
+    }
+
+    pub fn display_title_combinator(&self) -> String {
+        self.title
+            .clone()
+            .unwrap_or_else(|| format!("untitled ({} bytes)", self.size.unwrap_or(0)))
+    }
+
+    // `zip`: both values, or neither.
+
+    pub fn title_and_size_match(&self) -> Option<(&str, u64)> {
+        match (&self.title, self.size) {
+            (Some(title), Some(size)) => Some((title, size)),
+            _ => None,
+        }
+    }
+
+    pub fn title_and_size_combinator(&self) -> Option<(&str, u64)> {
+        self.title.as_deref().zip(self.size)
+    }
+
+    // `as_deref`: from `&Option<String>` to `Option<&str>`.
+
+    pub fn title_str_match(&self) -> Option<&str> {
+        match &self.title {
+            Some(title) => Some(title.as_str()),
+            None => None,
+        }
+    }
+
+    pub fn title_str_combinator(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    // `take`: move the value out, leaving `None` behind.
+
+    pub fn take_title_match(&mut self) -> Option<String> {
+        match std::mem::replace(&mut self.title, None) {
+            Some(title) => Some(title),
+            None => None,
+        }
+    }
+
+    pub fn take_title_combinator(&mut self) -> Option<String> {
+        self.title.take()
+    }
+
+    // `replace`: put a new value in, and get the old one (if any) back.
+
+    pub fn rename_match(&mut self, title: &str) -> Option<String> {
+        let old = std::mem::replace(&mut self.title, Some(title.to_owned()));
+        match old {
+            Some(old) => Some(old),
+            None => None,
+        }
+    }
+
+    pub fn rename_combinator(&mut self, title: &str) -> Option<String> {
+        self.title.replace(title.to_owned())
+    }
+
+    // `get_or_insert_with`: fill in a default if there is nothing there, and use what is.
+
+    pub fn title_or_insert_match(&mut self) -> &mut String {
+        if self.title.is_none() {
+            self.title = Some("untitled".to_owned());
+        }
+        match &mut self.title {
+            Some(title) => title,
+            None => unreachable!("the title was just filled in"),
+        }
+    }
+
+    pub fn title_or_insert_combinator(&mut self) -> &mut String {
+        self.title.get_or_insert_with(|| "untitled".to_owned())
+    }
+}
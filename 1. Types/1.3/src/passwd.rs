@@ -0,0 +1,151 @@
+// Looking a user up in `/etc/passwd`-format input, with errors from two sources: reading the
+// input, and making sense of what was read. `FindUserError` has a variant for each, plus one for
+// a user who isn't there, and the `From` impls are what let `?` convert to it.
+//
+// Each line holds seven colon-separated fields:
+//
+//     name:password:uid:gid:gecos:home:shell
+//
+// With the `logging` feature, `find_user` logs through the `log` facade: a warning for a line
+// it can't parse, and at debug level where the user was found, or how far it looked.
+
+use crate::logging::{log_debug, log_warn};
+use std::fmt;
+use std::io::{self, BufRead};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UserId(pub u32);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswdEntry {
+    pub name: String,
+    /// Usually just `x`, with the real hash kept in `/etc/shadow`.
+    pub password: String,
+    pub uid: UserId,
+    pub gid: u32,
+    /// Free text, typically the user's full name.
+    pub gecos: String,
+    pub home: String,
+    pub shell: String,
+}
+
+impl PasswdEntry {
+    /// The entry as a line of the password file, without the newline. `parse_line` reads it
+    /// back as the same entry, provided no field has a `:` in it: there is no way to escape one.
+    pub fn to_line(&self) -> String {
+        format!(
+            "{}:{}:{}:{}:{}:{}:{}",
+            self.name, self.password, self.uid.0, self.gid, self.gecos, self.home, self.shell
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The line doesn't have exactly seven fields.
+    FieldCount(usize),
+    EmptyName,
+    /// The uid or gid field isn't a number.
+    InvalidId {
+        field: &'static str,
+        value: String,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::FieldCount(count) => write!(f, "expected 7 fields, found {count}"),
+            ParseError::EmptyName => write!(f, "empty user name"),
+            ParseError::InvalidId { field, value } => write!(f, "invalid {field} {value:?}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn parse_line(line: &str) -> Result<PasswdEntry, ParseError> {
+    let fields: Vec<&str> = line.split(':').collect();
+    let [name, password, uid, gid, gecos, home, shell] = fields[..] else {
+        return Err(ParseError::FieldCount(fields.len()));
+    };
+    if name.is_empty() {
+        return Err(ParseError::EmptyName);
+    }
+    let id = |field, value: &str| {
+        value.parse().map_err(|_| ParseError::InvalidId {
+            field,
+            value: value.to_owned(),
+        })
+    };
+    Ok(PasswdEntry {
+        name: name.to_owned(),
+        password: password.to_owned(),
+        uid: UserId(id("uid", uid)?),
+        gid: id("gid", gid)?,
+        gecos: gecos.to_owned(),
+        home: home.to_owned(),
+        shell: shell.to_owned(),
+    })
+}
+
+#[derive(Debug)]
+pub enum FindUserError {
+    Io(io::Error),
+    /// A line before the user's (or the user's own) couldn't be parsed.
+    Parse(ParseError),
+    NotFound(String),
+}
+
+impl fmt::Display for FindUserError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FindUserError::Io(e) => write!(f, "failed to read password file: {e}"),
+            FindUserError::Parse(e) => write!(f, "malformed password file: {e}"),
+            FindUserError::NotFound(username) => write!(f, "no user named {username:?}"),
+        }
+    }
+}
+
+impl std::error::Error for FindUserError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FindUserError::Io(e) => Some(e),
+            FindUserError::Parse(e) => Some(e),
+            FindUserError::NotFound(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for FindUserError {
+    fn from(e: io::Error) -> Self {
+        FindUserError::Io(e)
+    }
+}
+
+impl From<ParseError> for FindUserError {
+    fn from(e: ParseError) -> Self {
+        FindUserError::Parse(e)
+    }
+}
+
+/// The uid of `username`, from the first line that names them. Blank lines are skipped.
+pub fn find_user(reader: impl BufRead, username: &str) -> Result<UserId, FindUserError> {
+    let mut lines = 0;
+    for line in reader.lines() {
+        lines += 1;
+        // Both `?`s convert into `FindUserError` through the `From` impls above.
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry = parse_line(&line)
+            .inspect_err(|e| log_warn!("line {lines} of the password file is malformed: {e}"))?;
+        if entry.name == username {
+            log_debug!("found {username:?} on line {lines}, uid {}", entry.uid.0);
+            return Ok(entry.uid);
+        }
+    }
+    log_debug!("no {username:?} in {lines} lines");
+    Err(FindUserError::NotFound(username.to_owned()))
+}
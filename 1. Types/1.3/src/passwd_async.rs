@@ -0,0 +1,114 @@
+// `find_user`, on Tokio. Reading the file is the slow part, so that is what is made async:
+// `find_user_async` opens it with `tokio::fs`, and `find_user_in` reads it a line at a time
+// through `AsyncBufReadExt::lines`, parsing each with the same `parse_line` as the synchronous
+// version. Nothing else about the lookup changes, so neither do the errors.
+//
+// `FindUserAsyncError` has every variant `FindUserError` has, and `From<FindUserError>` maps
+// each one across, so code that handles both can match on the same cases. Its one addition is
+// `Timeout`, from `with_timeout`, which gives up on a lookup that takes too long. Async code
+// can do that without help from the lookup itself: the future is dropped the next time it
+// waits, which cancels it.
+
+use crate::logging::{log_debug, log_warn};
+use crate::passwd::{parse_line, FindUserError, ParseError, UserId};
+use std::fmt;
+use std::future::Future;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, BufReader};
+
+#[derive(Debug)]
+pub enum FindUserAsyncError {
+    Io(io::Error),
+    /// A line before the user's (or the user's own) couldn't be parsed.
+    Parse(ParseError),
+    NotFound(String),
+    /// The lookup took longer than this.
+    Timeout(Duration),
+}
+
+impl fmt::Display for FindUserAsyncError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FindUserAsyncError::Io(e) => write!(f, "failed to read password file: {e}"),
+            FindUserAsyncError::Parse(e) => write!(f, "malformed password file: {e}"),
+            FindUserAsyncError::NotFound(username) => write!(f, "no user named {username:?}"),
+            FindUserAsyncError::Timeout(timeout) => {
+                write!(f, "gave up looking after {timeout:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FindUserAsyncError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FindUserAsyncError::Io(e) => Some(e),
+            FindUserAsyncError::Parse(e) => Some(e),
+            FindUserAsyncError::NotFound(_) | FindUserAsyncError::Timeout(_) => None,
+        }
+    }
+}
+
+impl From<FindUserError> for FindUserAsyncError {
+    fn from(e: FindUserError) -> Self {
+        match e {
+            FindUserError::Io(e) => FindUserAsyncError::Io(e),
+            FindUserError::Parse(e) => FindUserAsyncError::Parse(e),
+            FindUserError::NotFound(username) => FindUserAsyncError::NotFound(username),
+        }
+    }
+}
+
+impl From<io::Error> for FindUserAsyncError {
+    fn from(e: io::Error) -> Self {
+        FindUserAsyncError::Io(e)
+    }
+}
+
+impl From<ParseError> for FindUserAsyncError {
+    fn from(e: ParseError) -> Self {
+        FindUserAsyncError::Parse(e)
+    }
+}
+
+/// The uid of `username`, from the password file at `path`.
+pub async fn find_user_async(path: &Path, username: &str) -> Result<UserId, FindUserAsyncError> {
+    let file = tokio::fs::File::open(path).await?;
+    find_user_in(BufReader::new(file), username).await
+}
+
+/// `find_user`, reading from `reader` without blocking the thread while it waits for input.
+pub async fn find_user_in(
+    reader: impl AsyncBufRead + Unpin,
+    username: &str,
+) -> Result<UserId, FindUserAsyncError> {
+    let mut lines = reader.lines();
+    let mut count = 0;
+    while let Some(line) = lines.next_line().await? {
+        count += 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry = parse_line(&line)
+            .inspect_err(|e| log_warn!("line {count} of the password file is malformed: {e}"))?;
+        if entry.name == username {
+            log_debug!("found {username:?} on line {count}, uid {}", entry.uid.0);
+            return Ok(entry.uid);
+        }
+    }
+    log_debug!("no {username:?} in {count} lines");
+    Err(FindUserAsyncError::NotFound(username.to_owned()))
+}
+
+/// `lookup`, unless it takes longer than `timeout`, in which case it is cancelled and the
+/// result is a `Timeout` error.
+pub async fn with_timeout<T>(
+    timeout: Duration,
+    lookup: impl Future<Output = Result<T, FindUserAsyncError>>,
+) -> Result<T, FindUserAsyncError> {
+    tokio::time::timeout(timeout, lookup)
+        .await
+        .map_err(|_| FindUserAsyncError::Timeout(timeout))?
+}
@@ -0,0 +1,212 @@
+// `Result`'s combinators, and the bridges between `Result` and `Option`, on a small pipeline:
+// text is parsed into a number, and the number looked up to give a `UserId`. As in
+// `option_transforms`, each `*_combinator` function has a `*_match` twin that does the same
+// longhand, and the tests check they agree. Clippy would point at most of the `match`
+// versions; here the longhand is the point.
+#![allow(
+    clippy::manual_map,
+    clippy::manual_ok_err,
+    clippy::manual_unwrap_or,
+    clippy::manual_unwrap_or_default
+)]
+
+use crate::passwd::UserId;
+use std::fmt;
+use std::num::ParseIntError;
+
+/// The users that exist.
+pub const KNOWN_USERS: &[u32] = &[0, 1000, 1001];
+
+/// Who a user id that doesn't exist falls back to, in `find_user_or_guest`.
+pub const GUEST: UserId = UserId(65534);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LookupError {
+    /// There was no text to parse.
+    Missing,
+    Parse(ParseIntError),
+    UnknownUser(u32),
+}
+
+impl fmt::Display for LookupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LookupError::Missing => write!(f, "no user id given"),
+            LookupError::Parse(_) => write!(f, "the user id is not a number"),
+            LookupError::UnknownUser(id) => write!(f, "no user with id {id}"),
+        }
+    }
+}
+
+impl std::error::Error for LookupError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LookupError::Parse(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+pub fn lookup(id: u32) -> Result<UserId, LookupError> {
+    if KNOWN_USERS.contains(&id) {
+        Ok(UserId(id))
+    } else {
+        Err(LookupError::UnknownUser(id))
+    }
+}
+
+// `map_err`: change the error, leaving a success alone.
+
+pub fn parse_id_match(text: &str) -> Result<u32, LookupError> {
+    match text.trim().parse() {
+        Ok(id) => Ok(id),
+        Err(e) => Err(LookupError::Parse(e)),
+    }
+}
+
+pub fn parse_id_combinator(text: &str) -> Result<u32, LookupError> {
+    text.trim().parse().map_err(LookupError::Parse)
+}
+
+// `and_then`: the next step, which can fail too, only if this one worked.
+
+pub fn find_user_match(text: &str) -> Result<UserId, LookupError> {
+    match parse_id_combinator(text) {
+        Ok(id) => lookup(id),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn find_user_combinator(text: &str) -> Result<UserId, LookupError> {
+    parse_id_combinator(text).and_then(lookup)
+}
+
+// `or_else`: a second chance, only if this one failed.
+
+/// Someone who isn't a known user gets the guest account; text that isn't a user id at all is
+/// still an error.
+pub fn find_user_or_guest_match(text: &str) -> Result<UserId, LookupError> {
+    match find_user_combinator(text) {
+        Ok(user) => Ok(user),
+        Err(LookupError::UnknownUser(_)) => Ok(GUEST),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn find_user_or_guest_combinator(text: &str) -> Result<UserId, LookupError> {
+    find_user_combinator(text).or_else(|e| match e {
+        LookupError::UnknownUser(_) => Ok(GUEST),
+        e => Err(e),
+    })
+}
+
+// `ok`: the success as an `Option`, dropping the error.
+
+pub fn user_if_any_match(text: &str) -> Option<UserId> {
+    match find_user_combinator(text) {
+        Ok(user) => Some(user),
+        Err(_) => None,
+    }
+}
+
+pub fn user_if_any_combinator(text: &str) -> Option<UserId> {
+    find_user_combinator(text).ok()
+}
+
+// `err`: the error as an `Option`, dropping the success.
+
+pub fn failure_match(text: &str) -> Option<LookupError> {
+    match find_user_combinator(text) {
+        Ok(_) => None,
+        Err(e) => Some(e),
+    }
+}
+
+pub fn failure_combinator(text: &str) -> Option<LookupError> {
+    find_user_combinator(text).err()
+}
+
+// `unwrap_or_default`: the success, or the type's default on failure.
+
+pub fn id_or_zero_match(text: &str) -> u32 {
+    match parse_id_combinator(text) {
+        Ok(id) => id,
+        Err(_) => 0,
+    }
+}
+
+pub fn id_or_zero_combinator(text: &str) -> u32 {
+    parse_id_combinator(text).unwrap_or_default()
+}
+
+// `ok_or_else`: from `Option` to `Result`, building the error only when there is none.
+
+/// The user whose id is the first word of `line`.
+pub fn first_user_match(line: &str) -> Result<UserId, LookupError> {
+    match line.split_whitespace().next() {
+        Some(word) => find_user_combinator(word),
+        None => Err(LookupError::Missing),
+    }
+}
+
+// A unit variant costs nothing to build, so `ok_or` would do as well here; `ok_or_else` is the
+// one to reach for when making the error takes work.
+#[allow(clippy::unnecessary_lazy_evaluations)]
+pub fn first_user_combinator(line: &str) -> Result<UserId, LookupError> {
+    line.split_whitespace()
+        .next()
+        .ok_or_else(|| LookupError::Missing)
+        .and_then(find_user_combinator)
+}
+
+// `transpose`: an `Option` of a `Result` turned inside out, so that a missing input is fine
+// but a bad one is still an error.
+
+pub fn optional_user_match(text: Option<&str>) -> Result<Option<UserId>, LookupError> {
+    match text {
+        Some(text) => match find_user_combinator(text) {
+            Ok(user) => Ok(Some(user)),
+            Err(e) => Err(e),
+        },
+        None => Ok(None),
+    }
+}
+
+pub fn optional_user_combinator(text: Option<&str>) -> Result<Option<UserId>, LookupError> {
+    text.map(find_user_combinator).transpose()
+}
+
+// `?` IN `OPTION` AND `RESULT` FUNCTIONS
+// `?` returns early with whatever the function returns: `None` from a function returning
+// `Option`, and the error (converted with `From`) from one returning `Result`. So the same
+// steps tell the caller different amounts about what went wrong.
+
+/// `first_user`, returning `Option`. A line with no words and a line with a bad id both give
+/// `None`, and the caller can't tell which it was.
+///
+/// Nor can `?` be used on the `Result` from `find_user_combinator` here: it only converts
+/// between `Result`s, or between `Option`s, so the error has to be dropped with `.ok()` first.
+///
+/// ```compile_fail,E0277
+/// use item_1_3::passwd::UserId;
+/// use item_1_3::result_transforms::find_user_combinator;
+///
+/// fn first_user(line: &str) -> Option<UserId> {
+///     let word = line.split_whitespace().next()?;
+///     let user = find_user_combinator(word)?;
+///     Some(user)
+/// }
+/// ```
+pub fn first_user_question_option(line: &str) -> Option<UserId> {
+    let word = line.split_whitespace().next()?;
+    let user = find_user_combinator(word).ok()?;
+    Some(user)
+}
+
+/// `first_user`, returning `Result`: a missing word has to be turned into an error before `?`
+/// can return it, and each failure keeps its reason.
+pub fn first_user_question_result(line: &str) -> Result<UserId, LookupError> {
+    let word = line.split_whitespace().next().ok_or(LookupError::Missing)?;
+    let user = find_user_combinator(word)?;
+    Ok(user)
+}
@@ -0,0 +1,121 @@
+use item_1_3::{Cipher, IdentityCipher, InputData, Rot13Cipher, XorCipher};
+
+fn samples() -> Vec<Vec<u8>> {
+    vec![
+        Vec::new(),
+        b"Hello, World!".to_vec(),
+        b"the quick brown fox jumps over the lazy dog 0123456789".to_vec(),
+        (0..=255).collect(),
+    ]
+}
+
+fn check_round_trip(cipher: &dyn Cipher) {
+    for sample in samples() {
+        assert_eq!(cipher.decrypt(&cipher.encrypt(&sample)), sample);
+    }
+}
+
+#[test]
+fn xor_round_trips() {
+    for key in [0, 1, 0x2a, 0xff] {
+        check_round_trip(&XorCipher { key });
+    }
+}
+
+#[test]
+fn rot13_round_trips() {
+    check_round_trip(&Rot13Cipher);
+}
+
+#[test]
+fn identity_round_trips() {
+    check_round_trip(&IdentityCipher);
+}
+
+#[test]
+fn xor_encrypts() {
+    let cipher = XorCipher { key: 0x0f };
+    assert_eq!(cipher.encrypt(&[0x00, 0xf0, 0x0f]), [0x0f, 0xff, 0x00]);
+    // A zero key changes nothing.
+    assert_eq!(XorCipher { key: 0 }.encrypt(b"abc"), b"abc");
+}
+
+#[test]
+fn rot13_only_rotates_letters() {
+    assert_eq!(Rot13Cipher.encrypt(b"Hello, World!"), b"Uryyb, Jbeyq!");
+    assert_eq!(Rot13Cipher.encrypt(b"az AZ mn MN"), b"nm NM za ZA");
+    assert_eq!(Rot13Cipher.encrypt(b"0123 \xff"), b"0123 \xff");
+}
+
+#[test]
+fn input_data_is_encrypted_with_the_given_cipher() {
+    let input = InputData {
+        payload: Some(b"abc".to_vec()),
+    };
+    assert_eq!(input.encrypted(&Rot13Cipher), b"nop");
+    assert_eq!(input.encrypted(&XorCipher { key: 1 }), b"`cb");
+}
+
+#[test]
+fn missing_and_empty_payloads_encrypt_to_nothing() {
+    for payload in [None, Some(Vec::new())] {
+        let input = InputData { payload };
+        assert!(input.encrypted(&Rot13Cipher).is_empty());
+        assert!(input.encrypted(&XorCipher { key: 7 }).is_empty());
+    }
+}
+
+#[test]
+fn encrypt_cow_agrees_with_encrypt() {
+    let ciphers: [&dyn Cipher; 5] = [
+        &IdentityCipher,
+        &XorCipher { key: 0 },
+        &XorCipher { key: 0x2a },
+        &Rot13Cipher,
+        // A cipher that doesn't override `encrypt_cow` gets the copying default.
+        &Reversed,
+    ];
+    for cipher in ciphers {
+        for sample in samples() {
+            assert_eq!(*cipher.encrypt_cow(&sample), *cipher.encrypt(&sample));
+        }
+    }
+}
+
+struct Reversed;
+
+impl Cipher for Reversed {
+    fn encrypt(&self, data: &[u8]) -> Vec<u8> {
+        data.iter().rev().copied().collect()
+    }
+
+    fn decrypt(&self, data: &[u8]) -> Vec<u8> {
+        self.encrypt(data)
+    }
+}
+
+#[test]
+fn payload_or_default() {
+    assert_eq!(*input_data(Some(b"abc")).payload_or_default(), *b"abc");
+    assert!(input_data(None).payload_or_default().is_empty());
+}
+
+#[test]
+fn encrypted_cow_matches_encrypted() {
+    for payload in [None, Some(&b""[..]), Some(b"abc"), Some(b"123")] {
+        let input = input_data(payload);
+        for cipher in [
+            &Rot13Cipher as &dyn Cipher,
+            &XorCipher { key: 3 },
+            &IdentityCipher,
+        ] {
+            assert_eq!(*input.encrypted_cow(cipher), *input.encrypted(cipher));
+        }
+    }
+}
+
+fn input_data(payload: Option<&[u8]>) -> InputData {
+    InputData {
+        payload: payload.map(<[u8]>::to_vec),
+    }
+}
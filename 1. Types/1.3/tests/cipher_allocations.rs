@@ -0,0 +1,56 @@
+// Allocation counts for `InputData::encrypted_cow`, which should only copy the payload when
+// the cipher changes it. The counting allocator is installed in this test binary only.
+
+use item_1_3::{Cipher, IdentityCipher, InputData, Rot13Cipher, XorCipher};
+use std::borrow::Cow;
+use test_support::alloc::{assert_allocations, CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn input(payload: &[u8]) -> InputData {
+    InputData {
+        payload: Some(payload.to_vec()),
+    }
+}
+
+#[test]
+fn borrowed_path_does_not_copy() {
+    // Nothing for rot13 to rotate, either.
+    let data = input(b"0123-4567 $%");
+    let payload = data.payload.as_deref().unwrap();
+    let ciphers: [(&str, &dyn Cipher); 3] = [
+        ("identity", &IdentityCipher),
+        ("xor with a zero key", &XorCipher { key: 0 }),
+        ("rot13 without letters", &Rot13Cipher),
+    ];
+    for (name, cipher) in ciphers {
+        assert_allocations(name, 0, || data.encrypted_cow(cipher));
+        let encrypted = data.encrypted_cow(cipher);
+        assert!(matches!(encrypted, Cow::Borrowed(_)), "{name}");
+        // The very same bytes, not a copy of them.
+        assert!(std::ptr::eq(&*encrypted, payload), "{name}");
+    }
+
+    assert_allocations("payload_or_default", 0, || data.payload_or_default());
+    // A missing payload stands in with an empty `Vec`, which doesn't allocate either.
+    let missing = InputData { payload: None };
+    assert_allocations("payload_or_default without a payload", 0, || {
+        missing.payload_or_default()
+    });
+    assert_allocations("encrypted_cow without a payload", 0, || {
+        missing.encrypted_cow(&IdentityCipher)
+    });
+}
+
+#[test]
+fn changing_ciphers_copy_once() {
+    let data = input(b"Hello");
+    assert_allocations("xor", 1, || data.encrypted_cow(&XorCipher { key: 1 }));
+    assert_allocations("rot13", 1, || data.encrypted_cow(&Rot13Cipher));
+    assert!(matches!(data.encrypted_cow(&Rot13Cipher), Cow::Owned(_)));
+    // The plain version copies even when nothing changes.
+    assert_allocations("encrypted with identity", 1, || {
+        data.encrypted(&IdentityCipher)
+    });
+}
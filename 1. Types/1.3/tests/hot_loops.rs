@@ -0,0 +1,67 @@
+use item_1_3::hot_loops::{
+    limit, offset, pipeline_combinator, pipeline_inline_never, pipeline_match, process_combinator,
+    process_match, scale, sum_some_combinator, sum_some_inline_never, sum_some_match,
+    PipelineError, LIMIT,
+};
+
+#[test]
+fn sums_only_the_values_that_are_there() {
+    let values = [Some(1), None, Some(20), None, None, Some(300)];
+    assert_eq!(sum_some_match(&values), 321);
+    assert_eq!(sum_some_combinator(&values), 321);
+    assert_eq!(sum_some_inline_never(&values), 321);
+}
+
+#[test]
+fn sums_of_nothing() {
+    for values in [&[][..], &[None, None]] {
+        assert_eq!(sum_some_match(values), 0);
+        assert_eq!(sum_some_combinator(values), 0);
+        assert_eq!(sum_some_inline_never(values), 0);
+    }
+}
+
+#[test]
+fn each_stage_fails_on_its_own() {
+    assert_eq!(scale(u64::MAX / 2), Err(PipelineError::Overflow));
+    assert_eq!(offset(u64::MAX - 3), Err(PipelineError::Overflow));
+    assert_eq!(limit(LIMIT), Err(PipelineError::TooLarge(LIMIT)));
+    assert_eq!(limit(LIMIT - 1), Ok(LIMIT - 1));
+}
+
+#[test]
+fn the_first_failing_stage_gives_the_error() {
+    // Scaling overflows, so the limit is never checked.
+    assert_eq!(process_match(u64::MAX), Err(PipelineError::Overflow));
+    assert_eq!(process_combinator(u64::MAX), Err(PipelineError::Overflow));
+    // 3 * 2^31 + 7 scales and offsets fine, but is over the limit.
+    let n = 1 << 31;
+    assert_eq!(process_match(n), Err(PipelineError::TooLarge(3 * n + 7)));
+    assert_eq!(
+        process_combinator(n),
+        Err(PipelineError::TooLarge(3 * n + 7))
+    );
+    assert_eq!(process_match(5), Ok(22));
+    assert_eq!(process_combinator(5), Ok(22));
+}
+
+#[test]
+fn pipelines_agree() {
+    let numbers: Vec<u64> = (0..1000u64)
+        .map(|i| i.wrapping_mul(0x9e37_79b9_7f4a_7c15) >> (i % 40))
+        .chain([u64::MAX, 0, 1 << 31])
+        .collect();
+    let expected = pipeline_match(&numbers);
+    assert!(expected.1 > 0 && expected.1 < numbers.len(), "{expected:?}");
+    assert_eq!(pipeline_combinator(&numbers), expected);
+    assert_eq!(pipeline_inline_never(&numbers), expected);
+}
+
+#[test]
+fn pipeline_totals_and_failures() {
+    let numbers = [0, 1, u64::MAX, LIMIT];
+    assert_eq!(pipeline_match(&numbers), (7 + 10, 2));
+    assert_eq!(pipeline_combinator(&numbers), (7 + 10, 2));
+    assert_eq!(pipeline_inline_never(&numbers), (7 + 10, 2));
+    assert_eq!(pipeline_match(&[]), (0, 0));
+}
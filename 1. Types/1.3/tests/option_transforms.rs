@@ -0,0 +1,128 @@
+use item_1_3::option_transforms::{File, MissingField};
+
+// Every combination of these titles and sizes.
+fn files() -> Vec<File> {
+    let titles = [
+        None,
+        Some(""),
+        Some("report.pdf"),
+        Some("notes"),
+        Some("archive.tar.gz"),
+        Some(".hidden"),
+        Some("naïve.txt"),
+    ];
+    let sizes = [None, Some(0), Some(1024), Some(u64::MAX)];
+    titles
+        .iter()
+        .flat_map(|title| {
+            sizes.iter().map(move |&size| File {
+                title: title.map(str::to_owned),
+                size,
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn read_only_pairs_agree() {
+    for file in files() {
+        assert_eq!(
+            file.title_len_match(),
+            file.title_len_combinator(),
+            "{file:?}"
+        );
+        assert_eq!(
+            file.extension_match(),
+            file.extension_combinator(),
+            "{file:?}"
+        );
+        assert_eq!(
+            file.size_or_err_match(),
+            file.size_or_err_combinator(),
+            "{file:?}"
+        );
+        assert_eq!(
+            file.nonempty_title_match(),
+            file.nonempty_title_combinator(),
+            "{file:?}"
+        );
+        assert_eq!(
+            file.display_title_match(),
+            file.display_title_combinator(),
+            "{file:?}"
+        );
+        assert_eq!(
+            file.title_and_size_match(),
+            file.title_and_size_combinator(),
+            "{file:?}"
+        );
+        assert_eq!(
+            file.title_str_match(),
+            file.title_str_combinator(),
+            "{file:?}"
+        );
+    }
+}
+
+// Run `a` and `b` on copies of `file`, and check they return the same and leave the same.
+fn check_mut<R: PartialEq + std::fmt::Debug>(
+    file: &File,
+    a: impl FnOnce(&mut File) -> R,
+    b: impl FnOnce(&mut File) -> R,
+) {
+    let (mut x, mut y) = (file.clone(), file.clone());
+    assert_eq!(a(&mut x), b(&mut y), "{file:?}");
+    assert_eq!(x, y, "{file:?}");
+}
+
+#[test]
+fn mutating_pairs_agree() {
+    for file in files() {
+        check_mut(&file, File::take_title_match, File::take_title_combinator);
+        check_mut(
+            &file,
+            |f| f.rename_match("renamed"),
+            |f| f.rename_combinator("renamed"),
+        );
+        check_mut(
+            &file,
+            |f| f.title_or_insert_match().clone(),
+            |f| f.title_or_insert_combinator().clone(),
+        );
+    }
+}
+
+#[test]
+fn what_the_operations_do() {
+    let mut file = File {
+        title: Some("archive.tar.gz".to_owned()),
+        size: Some(10),
+    };
+    assert_eq!(file.title_len_combinator(), Some(14));
+    assert_eq!(file.extension_combinator(), Some("gz"));
+    assert_eq!(
+        file.title_and_size_combinator(),
+        Some(("archive.tar.gz", 10))
+    );
+    assert_eq!(
+        file.rename_combinator("a.zip").as_deref(),
+        Some("archive.tar.gz")
+    );
+    assert_eq!(file.take_title_combinator().as_deref(), Some("a.zip"));
+    assert_eq!(file.title, None);
+    assert_eq!(file.display_title_combinator(), "untitled (10 bytes)");
+    file.title_or_insert_combinator().push_str(" draft");
+    assert_eq!(file.title.as_deref(), Some("untitled draft"));
+
+    let empty = File::default();
+    assert_eq!(empty.size_or_err_combinator(), Err(MissingField("size")));
+    assert_eq!(empty.nonempty_title_combinator(), None);
+    assert_eq!(
+        File {
+            title: Some(String::new()),
+            size: None
+        }
+        .nonempty_title_combinator(),
+        None
+    );
+}
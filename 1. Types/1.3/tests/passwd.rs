@@ -0,0 +1,103 @@
+use item_1_3::passwd::parse_line;
+use item_1_3::{find_user, FindUserError, ParseError, PasswdEntry, UserId};
+
+const FIXTURE: &str = "\
+root:x:0:0:root:/root:/bin/bash
+daemon:x:1:1:daemon:/usr/sbin:/usr/sbin/nologin
+
+alice:x:1000:1000:Alice Liddell,,,:/home/alice:/bin/zsh
+";
+
+#[test]
+fn parses_a_line() {
+    assert_eq!(
+        parse_line("alice:x:1000:100:Alice Liddell,,,:/home/alice:/bin/zsh"),
+        Ok(PasswdEntry {
+            name: "alice".to_owned(),
+            password: "x".to_owned(),
+            uid: UserId(1000),
+            gid: 100,
+            gecos: "Alice Liddell,,,".to_owned(),
+            home: "/home/alice".to_owned(),
+            shell: "/bin/zsh".to_owned(),
+        })
+    );
+    // Empty fields other than the name are fine.
+    assert!(parse_line("nobody::65534:65534:::").is_ok());
+}
+
+#[test]
+fn rejects_malformed_lines() {
+    assert_eq!(parse_line("alice:x:1000"), Err(ParseError::FieldCount(3)));
+    assert_eq!(
+        parse_line("a:x:1:1:g:/h:/s:extra"),
+        Err(ParseError::FieldCount(8))
+    );
+    assert_eq!(parse_line(":x:1:1:g:/h:/s"), Err(ParseError::EmptyName));
+    assert_eq!(
+        parse_line("alice:x:-1:1:g:/h:/s"),
+        Err(ParseError::InvalidId {
+            field: "uid",
+            value: "-1".to_owned()
+        })
+    );
+    assert_eq!(
+        parse_line("alice:x:1:staff:g:/h:/s"),
+        Err(ParseError::InvalidId {
+            field: "gid",
+            value: "staff".to_owned()
+        })
+    );
+}
+
+#[test]
+fn finds_a_user() {
+    assert_eq!(find_user(FIXTURE.as_bytes(), "root").unwrap(), UserId(0));
+    assert_eq!(
+        find_user(FIXTURE.as_bytes(), "alice").unwrap(),
+        UserId(1000)
+    );
+}
+
+#[test]
+fn missing_user() {
+    let err = find_user(FIXTURE.as_bytes(), "bob").unwrap_err();
+    assert!(matches!(&err, FindUserError::NotFound(name) if name == "bob"));
+    assert_eq!(err.to_string(), "no user named \"bob\"");
+}
+
+#[test]
+fn malformed_line() {
+    let input = "root:x:0:0:root:/root:/bin/bash\nbroken line\nalice:x:1000:1000::/:/bin/sh\n";
+    let err = find_user(input.as_bytes(), "alice").unwrap_err();
+    assert!(matches!(
+        err,
+        FindUserError::Parse(ParseError::FieldCount(1))
+    ));
+    assert_eq!(
+        err.to_string(),
+        "malformed password file: expected 7 fields, found 1"
+    );
+    // Lines after the user's aren't looked at.
+    assert_eq!(find_user(input.as_bytes(), "root").unwrap(), UserId(0));
+}
+
+#[test]
+fn io_errors_are_passed_on() {
+    // Not UTF-8, which `BufRead::lines` reports as an I/O error.
+    let input: &[u8] = b"root:x:0:0:\xff:/root:/bin/sh\n";
+    let err = find_user(input, "root").unwrap_err();
+    assert!(matches!(&err, FindUserError::Io(e) if e.kind() == std::io::ErrorKind::InvalidData));
+    assert!(std::error::Error::source(&err).is_some());
+}
+
+#[test]
+fn to_line_writes_what_parse_line_reads() {
+    let line = "alice:x:1000:100:Alice Liddell,,,:/home/alice:/bin/zsh";
+    let entry = parse_line(line).unwrap();
+    assert_eq!(entry.to_line(), line);
+    assert_eq!(
+        parse_line("nobody::65534:65534:::").unwrap().to_line(),
+        "nobody::65534:65534:::"
+    );
+}
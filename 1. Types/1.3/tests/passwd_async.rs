@@ -0,0 +1,166 @@
+// Only built with the `async` feature.
+
+use item_1_3::passwd_async::{find_user_async, find_user_in, with_timeout, FindUserAsyncError};
+use item_1_3::{find_user, FindUserError, ParseError, UserId};
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use std::time::Duration;
+use test_support::temp_file::TempFile;
+use tokio::io::{AsyncBufRead, AsyncRead, ReadBuf};
+use tokio::time::{sleep, Sleep};
+
+const FIXTURE: &str = "\
+root:x:0:0:root:/root:/bin/bash
+daemon:x:1:1:daemon:/usr/sbin:/usr/sbin/nologin
+
+alice:x:1000:1000:Alice Liddell,,,:/home/alice:/bin/zsh
+";
+
+// Hands out `data` a line at a time, waiting `delay` before each one.
+struct SlowReader {
+    data: &'static [u8],
+    delay: Duration,
+    sleep: Option<Pin<Box<Sleep>>>,
+}
+
+impl SlowReader {
+    fn new(data: &'static str, delay: Duration) -> Self {
+        SlowReader {
+            data: data.as_bytes(),
+            delay,
+            sleep: None,
+        }
+    }
+}
+
+impl AsyncBufRead for SlowReader {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        let this = self.get_mut();
+        if !this.data.is_empty() {
+            let delay = this.delay;
+            let sleep = this.sleep.get_or_insert_with(|| Box::pin(sleep(delay)));
+            ready!(sleep.as_mut().poll(cx));
+        }
+        let line_end = this
+            .data
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(this.data.len(), |i| i + 1);
+        Poll::Ready(Ok(&this.data[..line_end]))
+    }
+
+    fn consume(self: Pin<&mut Self>, amount: usize) {
+        let this = self.get_mut();
+        this.data = &this.data[amount..];
+        // The next line waits a while too.
+        this.sleep = None;
+    }
+}
+
+impl AsyncRead for SlowReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let available = ready!(self.as_mut().poll_fill_buf(cx))?;
+        let n = available.len().min(buf.remaining());
+        buf.put_slice(&available[..n]);
+        self.consume(n);
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[tokio::test]
+async fn finds_the_user_in_a_file() {
+    let file = TempFile::new(FIXTURE);
+    assert_eq!(
+        find_user_async(file.path(), "alice").await.unwrap(),
+        UserId(1000)
+    );
+    assert_eq!(
+        find_user_async(file.path(), "root").await.unwrap(),
+        UserId(0)
+    );
+}
+
+#[tokio::test]
+async fn a_missing_user_is_not_found() {
+    let file = TempFile::new(FIXTURE);
+    let err = find_user_async(file.path(), "bob").await.unwrap_err();
+    assert!(
+        matches!(&err, FindUserAsyncError::NotFound(name) if name == "bob"),
+        "{err:?}"
+    );
+    assert_eq!(err.to_string(), "no user named \"bob\"");
+}
+
+#[tokio::test]
+async fn a_missing_file_is_an_io_error() {
+    let path = std::env::temp_dir().join("item-1-3-test-does-not-exist");
+    let err = find_user_async(&path, "alice").await.unwrap_err();
+    let FindUserAsyncError::Io(e) = &err else {
+        panic!("expected an I/O error, got {err:?}");
+    };
+    assert_eq!(e.kind(), io::ErrorKind::NotFound);
+    assert!(std::error::Error::source(&err).is_some());
+}
+
+#[tokio::test]
+async fn stops_at_a_malformed_line_as_find_user_does() {
+    let input = "root:x:0:0:root:/root:/bin/sh\nbroken\nalice:x:1000:1000:::\n";
+    let err = find_user_in(input.as_bytes(), "alice").await.unwrap_err();
+    assert!(
+        matches!(err, FindUserAsyncError::Parse(ParseError::FieldCount(1))),
+        "{err:?}"
+    );
+    assert!(matches!(
+        find_user(input.as_bytes(), "alice"),
+        Err(FindUserError::Parse(ParseError::FieldCount(1)))
+    ));
+}
+
+#[test]
+fn synchronous_errors_convert_variant_for_variant() {
+    let not_found = FindUserAsyncError::from(FindUserError::NotFound("bob".to_owned()));
+    assert!(matches!(&not_found, FindUserAsyncError::NotFound(name) if name == "bob"));
+    let parse = FindUserAsyncError::from(FindUserError::Parse(ParseError::EmptyName));
+    assert!(matches!(
+        parse,
+        FindUserAsyncError::Parse(ParseError::EmptyName)
+    ));
+    let io = FindUserAsyncError::from(FindUserError::Io(io::ErrorKind::Other.into()));
+    assert!(matches!(io, FindUserAsyncError::Io(_)));
+    // The messages match too.
+    assert_eq!(
+        not_found.to_string(),
+        FindUserError::NotFound("bob".to_owned()).to_string()
+    );
+}
+
+// With the clock paused, Tokio skips ahead whenever every task is waiting on a timer, so these
+// take no real time.
+
+#[tokio::test(start_paused = true)]
+async fn a_slow_reader_finishes_inside_a_generous_timeout() {
+    let reader = SlowReader::new(FIXTURE, Duration::from_millis(10));
+    let lookup = find_user_in(reader, "alice");
+    let uid = with_timeout(Duration::from_secs(1), lookup).await.unwrap();
+    assert_eq!(uid, UserId(1000));
+}
+
+#[tokio::test(start_paused = true)]
+async fn a_reader_too_slow_for_the_timeout_is_cancelled() {
+    let reader = SlowReader::new(FIXTURE, Duration::from_secs(1));
+    let lookup = find_user_in(reader, "alice");
+    let err = with_timeout(Duration::from_millis(2500), lookup)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, FindUserAsyncError::Timeout(timeout) if timeout == Duration::from_millis(2500)),
+        "{err:?}"
+    );
+    assert_eq!(err.to_string(), "gave up looking after 2.5s");
+}
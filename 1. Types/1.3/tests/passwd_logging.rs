@@ -0,0 +1,45 @@
+use item_1_3::find_user;
+use log::Level;
+use test_support::logger::{init_test_logger, CapturedRecord};
+
+const FIXTURE: &str = "\
+root:x:0:0:root:/root:/bin/bash
+
+alice:x:1000:1000:Alice Liddell,,,:/home/alice:/bin/zsh
+";
+
+fn record(level: Level, message: &str) -> CapturedRecord {
+    CapturedRecord {
+        level,
+        target: "item_1_3::passwd".to_owned(),
+        message: message.to_owned(),
+    }
+}
+
+#[test]
+fn malformed_line_is_a_warning() {
+    let logger = init_test_logger();
+    let input = "root:x:0:0:root:/root:/bin/bash\nbroken line\n";
+    find_user(input.as_bytes(), "alice").unwrap_err();
+    assert_eq!(
+        logger.take(),
+        [record(
+            Level::Warn,
+            "line 2 of the password file is malformed: expected 7 fields, found 1"
+        )]
+    );
+}
+
+#[test]
+fn lookups_are_debug() {
+    let logger = init_test_logger();
+    find_user(FIXTURE.as_bytes(), "alice").unwrap();
+    find_user(FIXTURE.as_bytes(), "bob").unwrap_err();
+    assert_eq!(
+        logger.take(),
+        [
+            record(Level::Debug, "found \"alice\" on line 3, uid 1000"),
+            record(Level::Debug, "no \"bob\" in 3 lines"),
+        ]
+    );
+}
@@ -0,0 +1,133 @@
+use item_1_3::result_transforms::*;
+use item_1_3::UserId;
+
+// Successes, parse failures and lookup failures.
+const INPUTS: &[&str] = &[
+    "0",
+    " 1000\n",
+    "1001",
+    "42",
+    "4294967295",
+    "",
+    "abc",
+    "-1",
+    "4294967296",
+    "1000 1001",
+];
+
+#[test]
+fn pairs_agree() {
+    for &text in INPUTS {
+        assert_eq!(parse_id_match(text), parse_id_combinator(text), "{text:?}");
+        assert_eq!(
+            find_user_match(text),
+            find_user_combinator(text),
+            "{text:?}"
+        );
+        assert_eq!(
+            find_user_or_guest_match(text),
+            find_user_or_guest_combinator(text),
+            "{text:?}"
+        );
+        assert_eq!(
+            user_if_any_match(text),
+            user_if_any_combinator(text),
+            "{text:?}"
+        );
+        assert_eq!(failure_match(text), failure_combinator(text), "{text:?}");
+        assert_eq!(
+            id_or_zero_match(text),
+            id_or_zero_combinator(text),
+            "{text:?}"
+        );
+        assert_eq!(
+            first_user_match(text),
+            first_user_combinator(text),
+            "{text:?}"
+        );
+        assert_eq!(
+            optional_user_match(Some(text)),
+            optional_user_combinator(Some(text)),
+            "{text:?}"
+        );
+    }
+    assert_eq!(optional_user_match(None), optional_user_combinator(None));
+}
+
+#[test]
+fn success() {
+    assert_eq!(find_user_combinator(" 1000\n"), Ok(UserId(1000)));
+    assert_eq!(find_user_or_guest_combinator("0"), Ok(UserId(0)));
+    assert_eq!(user_if_any_combinator("1001"), Some(UserId(1001)));
+    assert_eq!(failure_combinator("1001"), None);
+    assert_eq!(id_or_zero_combinator("1001"), 1001);
+    assert_eq!(first_user_combinator("1000 1001"), Ok(UserId(1000)));
+    assert_eq!(optional_user_combinator(Some("0")), Ok(Some(UserId(0))));
+    assert_eq!(optional_user_combinator(None), Ok(None));
+}
+
+#[test]
+fn parse_failure() {
+    assert!(matches!(
+        find_user_combinator("abc"),
+        Err(LookupError::Parse(_))
+    ));
+    // Not a user at all, so no guest account either.
+    assert!(matches!(
+        find_user_or_guest_combinator("-1"),
+        Err(LookupError::Parse(_))
+    ));
+    assert_eq!(user_if_any_combinator("abc"), None);
+    assert_eq!(id_or_zero_combinator("abc"), 0);
+    assert_eq!(first_user_combinator("   "), Err(LookupError::Missing));
+    assert!(optional_user_combinator(Some("abc")).is_err());
+    let err = find_user_combinator("").unwrap_err();
+    assert_eq!(err.to_string(), "the user id is not a number");
+    assert_eq!(
+        std::error::Error::source(&err).unwrap().to_string(),
+        "cannot parse integer from empty string"
+    );
+}
+
+#[test]
+fn lookup_failure() {
+    assert_eq!(
+        find_user_combinator("42"),
+        Err(LookupError::UnknownUser(42))
+    );
+    assert_eq!(find_user_or_guest_combinator("42"), Ok(GUEST));
+    assert_eq!(failure_combinator("42"), Some(LookupError::UnknownUser(42)));
+    // Parsing worked, so there is an id, even though there's no such user.
+    assert_eq!(id_or_zero_combinator("42"), 42);
+    assert_eq!(
+        optional_user_combinator(Some("42")),
+        Err(LookupError::UnknownUser(42))
+    );
+    assert_eq!(
+        LookupError::UnknownUser(42).to_string(),
+        "no user with id 42"
+    );
+}
+
+#[test]
+fn question_mark_in_option_and_result_functions() {
+    for line in ["1000 x", "", "abc", "42"] {
+        assert_eq!(
+            first_user_question_option(line),
+            first_user_question_result(line).ok(),
+            "{line:?}"
+        );
+        assert_eq!(
+            first_user_question_result(line),
+            first_user_combinator(line)
+        );
+    }
+    // The `Option` version can't say why.
+    assert_eq!(first_user_question_option(""), None);
+    assert_eq!(first_user_question_option("abc"), None);
+    assert_eq!(first_user_question_result(""), Err(LookupError::Missing));
+    assert!(matches!(
+        first_user_question_result("abc"),
+        Err(LookupError::Parse(_))
+    ));
+}
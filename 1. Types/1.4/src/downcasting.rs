@@ -0,0 +1,117 @@
+//! What can be done with a `Box<dyn Error>` once it has been returned, as every item's `main`
+//! returns one. It hides which error it holds, but doesn't lose it: `downcast_ref` asks whether
+//! it is a particular type and borrows it if so, and `downcast` does the same by value, handing
+//! the box back unchanged if not. Either way the caller has to name the types it expects,
+//! which is the information a typed error enum would have given it for free.
+//!
+//! `read_limit` can fail with any of three types: an `io::Error`, a `ParseIntError`, or a
+//! `LimitTooHigh` of its own. `limit_or_default` takes the box apart to treat each differently.
+//!
+//! A plain `Box<dyn Error>` can't leave the thread it was made on, because nothing says the
+//! error inside is `Send`:
+//!
+//! ```compile_fail,E0277
+//! use std::error::Error;
+//!
+//! let err: Box<dyn Error> = "no limit".into();
+//! std::thread::spawn(move || println!("{err}"));
+//! ```
+//!
+//! `Box<dyn Error + Send + Sync>` says it, so only errors that are both can go into one, and
+//! the box can be returned from a thread or shared with one. It downcasts in the same way, and
+//! converts to the plain kind whenever it has to; `read_limit` is just `read_limit_send` with
+//! the bounds dropped.
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+/// The limit used when there is no file giving one.
+pub const DEFAULT_LIMIT: u32 = 100;
+
+/// The highest limit that is allowed.
+pub const MAX_LIMIT: u32 = 1000;
+
+/// A limit above `MAX_LIMIT`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LimitTooHigh(pub u32);
+
+impl fmt::Display for LimitTooHigh {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "limit {} is above the maximum of {MAX_LIMIT}", self.0)
+    }
+}
+
+impl Error for LimitTooHigh {}
+
+/// A message saying what was being done, wrapped around the error that stopped it. It holds
+/// any error at all, boxed, and hands it back from `source()`.
+#[derive(Debug)]
+pub struct Context {
+    pub message: String,
+    pub source: Box<dyn Error>,
+}
+
+impl Context {
+    pub fn new(message: impl Into<String>, source: impl Into<Box<dyn Error>>) -> Context {
+        Context {
+            message: message.into(),
+            source: source.into(),
+        }
+    }
+}
+
+impl fmt::Display for Context {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for Context {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+/// The limit written in the file at `path`, ignoring surrounding whitespace.
+pub fn read_limit_send(path: &Path) -> Result<u32, Box<dyn Error + Send + Sync>> {
+    // Each `?` boxes a different type.
+    let text = std::fs::read_to_string(path)?;
+    let limit = text.trim().parse()?;
+    if limit > MAX_LIMIT {
+        return Err(LimitTooHigh(limit).into());
+    }
+    Ok(limit)
+}
+
+/// `read_limit_send`, with an error that has to stay on this thread.
+pub fn read_limit(path: &Path) -> Result<u32, Box<dyn Error>> {
+    read_limit_send(path).map_err(|e| e as Box<dyn Error>)
+}
+
+/// The limit from the file at `path`, recovering from what can be recovered from: with no
+/// file, the limit is `DEFAULT_LIMIT`, and one that is too high is brought down to
+/// `MAX_LIMIT`. A limit that isn't a number gets a `Context` saying which file it was in, and
+/// any other error is returned as it was.
+pub fn limit_or_default(path: &Path) -> Result<u32, Box<dyn Error>> {
+    let err = match read_limit(path) {
+        Ok(limit) => return Ok(limit),
+        Err(err) => err,
+    };
+    // Only looking, so a borrow will do.
+    if let Some(io) = err.downcast_ref::<io::Error>() {
+        if io.kind() == io::ErrorKind::NotFound {
+            return Ok(DEFAULT_LIMIT);
+        }
+    }
+    if err.is::<std::num::ParseIntError>() {
+        let message = format!("the limit in {} is not a number", path.display());
+        return Err(Context::new(message, err).into());
+    }
+    // Taking the error by value, and getting it back if it was something else.
+    match err.downcast::<LimitTooHigh>() {
+        Ok(_) => Ok(MAX_LIMIT),
+        Err(err) => Err(err),
+    }
+}
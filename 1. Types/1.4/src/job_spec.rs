@@ -0,0 +1,212 @@
+//! Loading scheduler jobs from a file, a line per job, as `name,priority,cpu`:
+//!
+//! ```text
+//! backup,2,0
+//! restore,0,3
+//! ```
+//!
+//! Three things can go wrong, each with its own error type: reading the file (`io::Error`),
+//! a number that isn't one (`ParseIntError`), and a line that stops short (`MissingField`,
+//! defined here). Two more, a line that goes on past the CPU and a CPU this machine doesn't
+//! have, only need a variant. The errors from a line go into a `LineError`, and `LoadError`
+//! adds which line it was, or holds the I/O error. Each has a `From` impl for what it wraps,
+//! so most stages are a bare `?`. The exceptions are the numbers, whose errors are kept with
+//! the name of the field they came from, and the one `map_err` that adds the line number.
+//!
+//! `load_job_spec_stringly` does the same with `map_err` into a `String` at each step, as
+//! Item 1.3 did. Its messages can be just as good, but a caller can't tell the failures apart
+//! without parsing the text, and `source()` is gone: the root cause is flattened into the
+//! message rather than kept as an error of its own.
+
+use item_1_1::{CpuId, Job};
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::num::ParseIntError;
+use std::path::Path;
+
+/// How many CPUs jobs can be pinned to, numbered from 0.
+pub const CPU_COUNT: u32 = 8;
+
+/// A job, and the CPU it is to run on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobSpec {
+    /// Numbered by the line it came from, counting from 1.
+    pub job: Job,
+    pub cpu: CpuId,
+}
+
+/// A line without the named field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MissingField(pub &'static str);
+
+impl fmt::Display for MissingField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no {} given", self.0)
+    }
+}
+
+impl Error for MissingField {}
+
+/// Why a line isn't a job.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LineError {
+    /// The named field, the priority or the CPU, isn't a number, or is too large for one.
+    Parse {
+        field: &'static str,
+        source: ParseIntError,
+    },
+    MissingField(MissingField),
+    /// Something after the CPU, which should be the last field.
+    ExtraField,
+    /// A CPU of `CPU_COUNT` or more.
+    NoSuchCpu(CpuId),
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LineError::Parse { field, .. } => write!(f, "the {field} is not a valid number"),
+            LineError::MissingField(_) => write!(f, "the line is incomplete"),
+            LineError::ExtraField => write!(f, "the line goes on after the cpu"),
+            LineError::NoSuchCpu(cpu) => {
+                write!(f, "there is no cpu {cpu}, only 0 to {}", CPU_COUNT - 1)
+            }
+        }
+    }
+}
+
+impl Error for LineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LineError::Parse { source, .. } => Some(source),
+            LineError::MissingField(e) => Some(e),
+            LineError::ExtraField | LineError::NoSuchCpu(_) => None,
+        }
+    }
+}
+
+impl From<MissingField> for LineError {
+    fn from(e: MissingField) -> Self {
+        LineError::MissingField(e)
+    }
+}
+
+#[derive(Debug)]
+pub enum LoadError {
+    Io(io::Error),
+    /// The line, counting from 1, and what is wrong with it.
+    Line {
+        line: u32,
+        source: LineError,
+    },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io(_) => write!(f, "failed to read the job spec"),
+            LoadError::Line { line, .. } => write!(f, "line {line} of the job spec is invalid"),
+        }
+    }
+}
+
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LoadError::Io(e) => Some(e),
+            LoadError::Line { source, .. } => Some(source),
+        }
+    }
+}
+
+impl From<io::Error> for LoadError {
+    fn from(e: io::Error) -> Self {
+        LoadError::Io(e)
+    }
+}
+
+/// Every job in the file at `path`, in order. Blank lines are skipped.
+pub fn load_job_spec(path: &Path) -> Result<Vec<JobSpec>, LoadError> {
+    let text = std::fs::read_to_string(path)?;
+    let mut specs = Vec::new();
+    for (id, line) in (1..).zip(text.lines()) {
+        if !line.trim().is_empty() {
+            let spec =
+                parse_job_spec(id, line).map_err(|source| LoadError::Line { line: id, source })?;
+            specs.push(spec);
+        }
+    }
+    Ok(specs)
+}
+
+/// One line of a job spec, as the job with the given id.
+pub fn parse_job_spec(id: u32, line: &str) -> Result<JobSpec, LineError> {
+    let mut fields = line.split(',').map(str::trim);
+    let mut field = |name| {
+        fields
+            .next()
+            .filter(|f| !f.is_empty())
+            .ok_or(MissingField(name))
+    };
+    let name = field("name")?;
+    let priority = field("priority")?
+        .parse()
+        .map_err(|source| LineError::Parse {
+            field: "priority",
+            source,
+        })?;
+    let cpu = CpuId(field("cpu")?.parse().map_err(|source| LineError::Parse {
+        field: "cpu",
+        source,
+    })?);
+    if fields.next().is_some() {
+        return Err(LineError::ExtraField);
+    }
+    if cpu.0 >= CPU_COUNT {
+        return Err(LineError::NoSuchCpu(cpu));
+    }
+    Ok(JobSpec {
+        job: Job::new(id, name).with_priority(priority),
+        cpu,
+    })
+}
+
+/// `load_job_spec`, with every error turned into a message on the spot.
+pub fn load_job_spec_stringly(path: &Path) -> Result<Vec<JobSpec>, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+    let mut specs = Vec::new();
+    for (id, line) in (1..).zip(text.lines()) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut fields = line.split(',').map(str::trim);
+        let mut field = |name| {
+            fields
+                .next()
+                .filter(|f| !f.is_empty())
+                .ok_or(format!("line {id}: no {name} given"))
+        };
+        let name = field("name")?;
+        let priority = field("priority")?
+            .parse()
+            .map_err(|e| format!("line {id}: bad priority: {e}"))?;
+        let cpu = field("cpu")?
+            .parse()
+            .map_err(|e| format!("line {id}: bad cpu: {e}"))?;
+        if fields.next().is_some() {
+            return Err(format!(
+                "line {id}: more fields than name, priority and cpu"
+            ));
+        }
+        if cpu >= CPU_COUNT {
+            return Err(format!("line {id}: there is no cpu {cpu}"));
+        }
+        specs.push(JobSpec {
+            job: Job::new(id, name).with_priority(priority),
+            cpu: CpuId(cpu),
+        });
+    }
+    Ok(specs)
+}
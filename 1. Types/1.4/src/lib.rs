@@ -0,0 +1,184 @@
+// Item 1.4: Prefer idiomatic Error types.
+//
+// Item 1.3 got as far as `?` converting one error into another with `map_err`, into either a
+// `String` or a `Box<dyn Error>`. Both throw information away: a `String` can't be matched on,
+// and neither says which errors a function can actually return. The idiomatic alternative is
+// an enum with a variant per way the function can fail, which:
+//
+// - implements `Display`, for the message at this level, and `Debug`;
+// - implements `Error`, whose `source()` hands back the underlying error, if there is one, so
+//   that nothing is lost by wrapping it;
+// - has a `From` impl for each error it wraps, so that `?` does the wrapping.
+//
+// The `Display` message deliberately leaves out the wrapped error's own message. The cause
+// is available through `source()`, and whoever prints the error decides how much of the chain
+// to show; see `report`.
+//
+// `job_spec` is a longer pipeline, with three kinds of error to wrap, next to the same
+// pipeline written with `map_err` into a `String` for comparison. `downcasting` is the other
+// side of `Box<dyn Error>`: getting the concrete errors back out of one.
+
+pub mod downcasting;
+pub mod job_spec;
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::num::ParseIntError;
+use std::path::Path;
+
+pub const DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
+
+#[derive(Debug)]
+pub enum AppError {
+    Io(io::Error),
+    Parse(ParseIntError),
+    /// No user has this id, given as it was written.
+    UserNotFound(String),
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::Io(_) => write!(f, "failed to read the user id"),
+            AppError::Parse(_) => write!(f, "the user id is not a number"),
+            AppError::UserNotFound(id) => write!(f, "no user with id {id}"),
+        }
+    }
+}
+
+impl Error for AppError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AppError::Io(e) => Some(e),
+            AppError::Parse(e) => Some(e),
+            AppError::UserNotFound(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for AppError {
+    fn from(e: io::Error) -> Self {
+        AppError::Io(e)
+    }
+}
+
+impl From<ParseIntError> for AppError {
+    fn from(e: ParseIntError) -> Self {
+        AppError::Parse(e)
+    }
+}
+
+// THE PIPELINE
+// Three stages, each of which can fail in its own way, and each `?` converting the failure
+// into an `AppError` on the way out.
+
+/// The user id written in the file at `path`, ignoring surrounding whitespace.
+pub fn read_user_id(path: impl AsRef<Path>) -> Result<u32, AppError> {
+    let text = std::fs::read_to_string(path)?;
+    Ok(text.trim().parse()?)
+}
+
+pub fn lookup_user(users: &HashMap<u32, String>, id: u32) -> Result<&str, AppError> {
+    users
+        .get(&id)
+        .map(String::as_str)
+        .ok_or_else(|| AppError::UserNotFound(id.to_string()))
+}
+
+/// The name of the user whose id is in the file at `path`.
+pub fn user_from_file(
+    path: impl AsRef<Path>,
+    users: &HashMap<u32, String>,
+) -> Result<&str, AppError> {
+    let id = read_user_id(path)?;
+    lookup_user(users, id)
+}
+
+// REPORTING
+
+/// Every error in the chain from `err` down through `source()`, outermost first.
+pub fn chain(err: &(dyn Error + 'static)) -> Vec<String> {
+    std::iter::successors(Some(err), |&e| e.source())
+        .map(|e| e.to_string())
+        .collect()
+}
+
+/// The whole chain as one line each: `error: ...` and then `caused by: ...`.
+pub fn report(err: &(dyn Error + 'static)) -> String {
+    let mut out = String::new();
+    for (depth, message) in chain(err).iter().enumerate() {
+        let label = if depth == 0 { "error" } else { "caused by" };
+        out.push_str(&format!("{label}: {message}\n"));
+    }
+    out
+}
+
+pub fn run() -> Result<(), Box<dyn Error>> {
+    let users = HashMap::from([(0, "root".to_owned()), (1000, "alice".to_owned())]);
+
+    let dir = std::env::temp_dir();
+    let tag = std::process::id();
+    let good = dir.join(format!("item-1-4-good-{tag}"));
+    let bad = dir.join(format!("item-1-4-bad-{tag}"));
+    let stranger = dir.join(format!("item-1-4-stranger-{tag}"));
+    std::fs::write(&good, "1000\n")?;
+    std::fs::write(&bad, "one thousand\n")?;
+    std::fs::write(&stranger, "42\n")?;
+
+    println!(
+        "{} is user {}",
+        good.display(),
+        user_from_file(&good, &users)?
+    );
+    // Each of the ways the pipeline can fail, with the whole chain of causes.
+    let missing = dir.join(format!("item-1-4-missing-{tag}"));
+    for path in [&missing, &bad, &stranger] {
+        if let Err(e) = user_from_file(path, &users) {
+            print!("{}", report(&e));
+        }
+    }
+
+    for path in [good, bad, stranger] {
+        std::fs::remove_file(path)?;
+    }
+
+    // The typed error keeps its cause, and says which of the failures it was; the string
+    // says the same in one line, and that is all there is.
+    let spec = dir.join(format!("item-1-4-jobs-{tag}"));
+    std::fs::write(&spec, "backup,2,0\nrestore,0,3\nreindex,high,1\n")?;
+    if let Err(e) = job_spec::load_job_spec(&spec) {
+        print!("{}", report(&e));
+    }
+    if let Err(message) = job_spec::load_job_spec_stringly(&spec) {
+        println!("error: {message}");
+    }
+    std::fs::write(&spec, "backup,2,0\nrestore,0,3\n")?;
+    for job_spec::JobSpec { job, cpu } in job_spec::load_job_spec(&spec)? {
+        println!("{job} on cpu {cpu}");
+    }
+    std::fs::remove_file(spec)?;
+
+    // The same file-reading function failing in three ways, behind one `Box<dyn Error>`, and
+    // the caller telling them apart to decide what to do about each.
+    let limits = [
+        ("missing", None),
+        ("high", Some("5000\n")),
+        ("words", Some("lots\n")),
+    ];
+    for (name, contents) in limits {
+        let path = dir.join(format!("item-1-4-limit-{name}-{tag}"));
+        if let Some(contents) = contents {
+            std::fs::write(&path, contents)?;
+        }
+        match downcasting::limit_or_default(&path) {
+            Ok(limit) => println!("{name}: limit {limit}"),
+            Err(e) => print!("{}", report(e.as_ref())),
+        }
+        if contents.is_some() {
+            std::fs::remove_file(path)?;
+        }
+    }
+    Ok(())
+}
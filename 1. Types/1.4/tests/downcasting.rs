@@ -0,0 +1,123 @@
+use item_1_4::downcasting::{
+    limit_or_default, read_limit, read_limit_send, Context, LimitTooHigh, DEFAULT_LIMIT, MAX_LIMIT,
+};
+use item_1_4::{chain, report};
+use std::error::Error;
+use std::io;
+use std::num::ParseIntError;
+use std::path::PathBuf;
+use test_support::temp_file::TempFile;
+
+fn missing() -> PathBuf {
+    std::env::temp_dir().join("item-1-4-downcast-test-does-not-exist")
+}
+
+#[test]
+fn reads_the_limit() {
+    let file = TempFile::new(" 250\n");
+    assert_eq!(read_limit(file.path()).unwrap(), 250);
+    assert_eq!(limit_or_default(file.path()).unwrap(), 250);
+    assert_eq!(
+        limit_or_default(TempFile::new(&MAX_LIMIT.to_string()).path()).unwrap(),
+        MAX_LIMIT
+    );
+}
+
+#[test]
+fn downcasts_to_the_type_it_holds() {
+    let err = read_limit(&missing()).unwrap_err();
+    let io = err.downcast_ref::<io::Error>().unwrap();
+    assert_eq!(io.kind(), io::ErrorKind::NotFound);
+
+    let err = read_limit(TempFile::new("ten").path()).unwrap_err();
+    assert!(err.is::<ParseIntError>());
+
+    let err = read_limit(TempFile::new("2000").path()).unwrap_err();
+    assert_eq!(*err.downcast::<LimitTooHigh>().unwrap(), LimitTooHigh(2000));
+}
+
+#[test]
+fn fails_to_downcast_to_anything_else() {
+    let err = read_limit(TempFile::new("2000").path()).unwrap_err();
+    assert!(err.downcast_ref::<io::Error>().is_none());
+    assert!(err.downcast_ref::<ParseIntError>().is_none());
+
+    // A failed `downcast` gives the error back, no worse for it.
+    let err = err.downcast::<ParseIntError>().unwrap_err();
+    assert_eq!(err.to_string(), "limit 2000 is above the maximum of 1000");
+    assert!(err.is::<LimitTooHigh>());
+}
+
+#[test]
+fn recovers_from_what_it_can() {
+    assert_eq!(limit_or_default(&missing()).unwrap(), DEFAULT_LIMIT);
+    assert_eq!(
+        limit_or_default(TempFile::new("5000").path()).unwrap(),
+        MAX_LIMIT
+    );
+}
+
+#[test]
+fn adds_context_to_what_it_cannot() {
+    let file = TempFile::new("lots");
+    let err = limit_or_default(file.path()).unwrap_err();
+    let context = err.downcast_ref::<Context>().unwrap();
+    assert!(context.source.is::<ParseIntError>());
+    assert_eq!(
+        chain(err.as_ref()),
+        [
+            format!("the limit in {} is not a number", file.path().display()),
+            "invalid digit found in string".to_owned(),
+        ]
+    );
+}
+
+#[test]
+fn passes_other_errors_through() {
+    // A directory can be opened, but not read as text.
+    let err = limit_or_default(&std::env::temp_dir()).unwrap_err();
+    let io = err.downcast_ref::<io::Error>().unwrap();
+    assert_ne!(io.kind(), io::ErrorKind::NotFound);
+}
+
+#[test]
+fn reports_a_three_deep_chain() {
+    let file = TempFile::new("-1");
+    let err = Context::new(
+        "can't start the workers",
+        limit_or_default(file.path()).unwrap_err(),
+    );
+    assert_eq!(chain(&err).len(), 3);
+    assert_eq!(
+        report(&err),
+        format!(
+            "error: can't start the workers\n\
+             caused by: the limit in {} is not a number\n\
+             caused by: invalid digit found in string\n",
+            file.path().display()
+        )
+    );
+    // Each layer can still be downcast on the way down.
+    let middle = err.source().unwrap().downcast_ref::<Context>().unwrap();
+    assert!(middle.source().unwrap().is::<ParseIntError>());
+}
+
+#[test]
+fn sends_errors_across_threads() {
+    let file = TempFile::new("4096");
+    let path = file.path().to_owned();
+    // The closure's result has to be `Send` to come back through `join`, which the plain
+    // `Box<dyn Error>` from `read_limit` isn't.
+    let err = std::thread::spawn(move || read_limit_send(&path))
+        .join()
+        .unwrap()
+        .unwrap_err();
+    assert_eq!(*err.downcast::<LimitTooHigh>().unwrap(), LimitTooHigh(4096));
+
+    // `Sync` is what lets threads share one by reference.
+    let err = read_limit_send(&missing()).unwrap_err();
+    std::thread::scope(|s| {
+        s.spawn(|| assert!(err.is::<io::Error>()));
+        s.spawn(|| assert!(!err.is::<LimitTooHigh>()));
+    });
+}
@@ -0,0 +1,116 @@
+use item_1_4::{chain, lookup_user, read_user_id, report, user_from_file, AppError};
+use std::collections::HashMap;
+use std::error::Error;
+use test_support::temp_file::TempFile;
+
+fn users() -> HashMap<u32, String> {
+    HashMap::from([(0, "root".to_owned()), (1000, "alice".to_owned())])
+}
+
+#[test]
+fn finds_the_user() {
+    let file = TempFile::new("  1000\n");
+    assert_eq!(read_user_id(file.path()).unwrap(), 1000);
+    assert_eq!(user_from_file(file.path(), &users()).unwrap(), "alice");
+}
+
+#[test]
+fn missing_file() {
+    let path = std::env::temp_dir().join("item-1-4-test-does-not-exist");
+    let err = user_from_file(&path, &users()).unwrap_err();
+    let AppError::Io(io) = &err else {
+        panic!("expected an I/O error, got {err:?}");
+    };
+    assert_eq!(io.kind(), std::io::ErrorKind::NotFound);
+    assert_eq!(err.to_string(), "failed to read the user id");
+    let chain = chain(&err);
+    assert_eq!(chain.len(), 2);
+    assert_eq!(chain[1], io.to_string());
+}
+
+#[test]
+fn not_a_number() {
+    let file = TempFile::new("one thousand");
+    let err = user_from_file(file.path(), &users()).unwrap_err();
+    assert!(matches!(err, AppError::Parse(_)));
+    assert_eq!(
+        report(&err),
+        "error: the user id is not a number\ncaused by: invalid digit found in string\n"
+    );
+
+    let file = TempFile::new("");
+    let err = read_user_id(file.path()).unwrap_err();
+    assert_eq!(
+        chain(&err),
+        [
+            "the user id is not a number",
+            "cannot parse integer from empty string"
+        ]
+    );
+}
+
+#[test]
+fn too_large_a_number() {
+    let file = TempFile::new("4294967296");
+    let err = read_user_id(file.path()).unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "number too large to fit in target type"
+    );
+}
+
+#[test]
+fn unknown_user() {
+    let file = TempFile::new("42");
+    let err = user_from_file(file.path(), &users()).unwrap_err();
+    assert!(matches!(&err, AppError::UserNotFound(id) if id == "42"));
+    assert!(err.source().is_none());
+    assert_eq!(report(&err), "error: no user with id 42\n");
+    assert!(matches!(
+        lookup_user(&HashMap::new(), 0),
+        Err(AppError::UserNotFound(_))
+    ));
+}
+
+#[test]
+fn question_mark_converts_into_app_error() {
+    fn parse(text: &str) -> Result<u32, AppError> {
+        Ok(text.parse()?)
+    }
+    assert_eq!(parse("7").unwrap(), 7);
+    assert!(matches!(parse("x"), Err(AppError::Parse(_))));
+
+    fn open() -> Result<(), AppError> {
+        Err(std::io::Error::other("disk on fire"))?
+    }
+    assert_eq!(
+        chain(&open().unwrap_err()),
+        ["failed to read the user id", "disk on fire"]
+    );
+}
+
+#[test]
+fn chains_through_boxed_errors() {
+    // `chain` works on any error, including one that has been boxed and wrapped again.
+    #[derive(Debug)]
+    struct Outer(Box<dyn Error + 'static>);
+    impl std::fmt::Display for Outer {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "login failed")
+        }
+    }
+    impl Error for Outer {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(self.0.as_ref())
+        }
+    }
+    let inner = AppError::from("x".parse::<u32>().unwrap_err());
+    assert_eq!(
+        chain(&Outer(Box::new(inner))),
+        [
+            "login failed",
+            "the user id is not a number",
+            "invalid digit found in string"
+        ]
+    );
+}
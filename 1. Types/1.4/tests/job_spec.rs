@@ -0,0 +1,205 @@
+use item_1_1::{CpuId, Job};
+use item_1_4::chain;
+use item_1_4::job_spec::{
+    load_job_spec, load_job_spec_stringly, parse_job_spec, JobSpec, LineError, LoadError,
+    MissingField,
+};
+use std::error::Error;
+use std::num::ParseIntError;
+use test_support::temp_file::TempFile;
+
+// The error at the bottom of the `source()` chain.
+fn root_cause<'a>(err: &'a (dyn Error + 'static)) -> &'a (dyn Error + 'static) {
+    std::iter::successors(Some(err), |&e| e.source())
+        .last()
+        .unwrap()
+}
+
+#[test]
+fn loads_every_job() {
+    let file = TempFile::new("backup,2,0\n\n restore , 0 , 7 \n");
+    let specs = load_job_spec(file.path()).unwrap();
+    assert_eq!(
+        specs,
+        [
+            JobSpec {
+                job: Job::new(1, "backup").with_priority(2),
+                cpu: CpuId(0),
+            },
+            JobSpec {
+                job: Job::new(3, "restore"),
+                cpu: CpuId(7),
+            },
+        ]
+    );
+    let payloads: Vec<_> = specs.iter().map(|spec| spec.job.payload.as_str()).collect();
+    assert_eq!(payloads, ["backup", "restore"]);
+    assert_eq!(load_job_spec_stringly(file.path()).unwrap(), specs);
+}
+
+#[test]
+fn a_missing_file_fails_to_read() {
+    let path = std::env::temp_dir().join("item-1-4-job-spec-does-not-exist");
+    let err = load_job_spec(&path).unwrap_err();
+    let LoadError::Io(io) = &err else {
+        panic!("expected an I/O error, got {err:?}");
+    };
+    assert_eq!(io.kind(), std::io::ErrorKind::NotFound);
+    let root = root_cause(&err);
+    assert_eq!(
+        root.downcast_ref::<std::io::Error>().map(|e| e.kind()),
+        Some(std::io::ErrorKind::NotFound)
+    );
+    assert_eq!(chain(&err)[0], "failed to read the job spec");
+
+    let message = load_job_spec_stringly(&path).unwrap_err();
+    assert!(message.starts_with("failed to read "), "{message}");
+}
+
+#[test]
+fn a_short_line_is_missing_a_field() {
+    for (line, field) in [
+        ("backup", "priority"),
+        ("backup,2", "cpu"),
+        (",2,0", "name"),
+    ] {
+        let file = TempFile::new(&format!("restore,0,1\n{line}\n"));
+        let err = load_job_spec(file.path()).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                LoadError::Line { line: 2, source: LineError::MissingField(MissingField(f)) }
+                    if *f == field
+            ),
+            "{line:?}: {err:?}"
+        );
+        assert_eq!(
+            chain(&err),
+            [
+                "line 2 of the job spec is invalid".to_owned(),
+                "the line is incomplete".to_owned(),
+                format!("no {field} given"),
+            ]
+        );
+        assert_eq!(
+            root_cause(&err).downcast_ref::<MissingField>(),
+            Some(&MissingField(field))
+        );
+        assert_eq!(
+            load_job_spec_stringly(file.path()).unwrap_err(),
+            format!("line 2: no {field} given")
+        );
+    }
+}
+
+#[test]
+fn a_bad_number_fails_to_parse() {
+    for (line, field, cause) in [
+        ("backup,high,0", "priority", "invalid digit found in string"),
+        (
+            "backup,256,0",
+            "priority",
+            "number too large to fit in target type",
+        ),
+        ("backup,1,-1", "cpu", "invalid digit found in string"),
+    ] {
+        let file = TempFile::new(line);
+        let err = load_job_spec(file.path()).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                LoadError::Line { line: 1, source: LineError::Parse { field: f, .. } }
+                    if *f == field
+            ),
+            "{line:?}: {err:?}"
+        );
+        assert_eq!(
+            chain(&err),
+            [
+                "line 1 of the job spec is invalid".to_owned(),
+                format!("the {field} is not a valid number"),
+                cause.to_owned(),
+            ]
+        );
+        let root = root_cause(&err);
+        assert!(root.is::<ParseIntError>(), "{line:?}");
+        assert_eq!(root.to_string(), cause);
+        assert_eq!(
+            load_job_spec_stringly(file.path()).unwrap_err(),
+            format!("line 1: bad {field}: {cause}")
+        );
+    }
+}
+
+#[test]
+fn a_field_after_the_cpu_is_rejected() {
+    let file = TempFile::new("backup,2,0,junk,more\n");
+    let err = load_job_spec(file.path()).unwrap_err();
+    assert!(matches!(
+        err,
+        LoadError::Line {
+            line: 1,
+            source: LineError::ExtraField
+        }
+    ));
+    assert_eq!(
+        chain(&err),
+        [
+            "line 1 of the job spec is invalid",
+            "the line goes on after the cpu"
+        ]
+    );
+    assert_eq!(
+        load_job_spec_stringly(file.path()).unwrap_err(),
+        "line 1: more fields than name, priority and cpu"
+    );
+}
+
+#[test]
+fn a_cpu_out_of_range_is_rejected() {
+    let file = TempFile::new("backup,2,8\n");
+    let err = load_job_spec(file.path()).unwrap_err();
+    assert!(matches!(
+        err,
+        LoadError::Line {
+            line: 1,
+            source: LineError::NoSuchCpu(CpuId(8))
+        }
+    ));
+    assert_eq!(
+        chain(&err),
+        [
+            "line 1 of the job spec is invalid",
+            "there is no cpu 8, only 0 to 7"
+        ]
+    );
+    assert_eq!(
+        load_job_spec_stringly(file.path()).unwrap_err(),
+        "line 1: there is no cpu 8"
+    );
+}
+
+#[test]
+fn the_first_bad_line_stops_the_load() {
+    let file = TempFile::new("backup,2,9\nrestore\n");
+    assert!(matches!(
+        load_job_spec(file.path()),
+        Err(LoadError::Line { line: 1, .. })
+    ));
+}
+
+#[test]
+fn lines_parse_on_their_own() {
+    assert_eq!(
+        parse_job_spec(5, "backup,2,0"),
+        Ok(JobSpec {
+            job: Job::new(5, "backup").with_priority(2),
+            cpu: CpuId(0),
+        })
+    );
+    assert_eq!(
+        parse_job_spec(5, "backup,2"),
+        Err(LineError::MissingField(MissingField("cpu")))
+    );
+    assert_eq!(parse_job_spec(5, "backup,2,0,"), Err(LineError::ExtraField));
+}
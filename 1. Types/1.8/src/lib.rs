@@ -0,0 +1,37 @@
+// Item 1.8: Familiarize yourself with reference and pointer types.
+//
+// A `&T` borrows a value its owner keeps. When there is no one owner, because several
+// values each need the same thing for as long as they themselves live, `Rc<T>` shares
+// ownership instead, with the value dropped when the last `Rc` goes. `Rc` only hands out
+// shared references, so changing the value as well takes a `RefCell` inside it; and `Weak<T>`
+// is a handle that doesn't count as an owner. `widgets` has all three.
+
+pub mod widgets;
+
+pub use widgets::{Theme, Tooltip, Widget};
+
+use item_1_1::{Color, RgbColor};
+use std::rc::Rc;
+
+pub const DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
+
+pub fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let theme = Theme::shared("plain", Color::Monochrome);
+    let title = Widget::new("Title", 0, 0, &theme);
+    let status = Widget::new("Status", 0, 24, &theme);
+    let tooltip = Tooltip::new("Press q to quit", &theme);
+    // Three owners: `theme` itself and the two widgets. The tooltip doesn't count.
+    println!("{} owners", Rc::strong_count(&theme));
+
+    // Changed through one widget, seen by both.
+    title.highlight(Color::Foreground(RgbColor(0, 160, 255)));
+    println!("{} | {}", title.render(), status.render());
+    println!("revision {}", theme.borrow().revision);
+
+    drop((theme, title, status));
+    match tooltip.render() {
+        Some(text) => println!("{text}"),
+        None => println!("the tooltip's theme is gone"),
+    }
+    Ok(())
+}
@@ -0,0 +1,119 @@
+// Widgets sharing one `Theme`. Each `Widget` holds an `Rc` to it, so the theme lives as long
+// as any widget does, and a `RefCell` around it, so that any of them can change it through a
+// shared handle. A change made through one widget shows up in all of them the next time they
+// render.
+//
+// `RefCell` moves the borrow checker's rule, one `&mut` or any number of `&`, from compile time
+// to run time, and breaking it panics. `highlight_overlapping` does just that; `highlight`
+// is the same with the first borrow ended in time.
+//
+// A `Tooltip` only holds a `Weak` handle, which doesn't keep the theme alive, and has to ask
+// for it back with `upgrade` each time it renders.
+
+use item_1_1::render::styled;
+use item_1_1::{Color, DisplayProps};
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    pub name: String,
+    pub fg_color: Color,
+    /// How many times the theme has been changed through a widget.
+    pub revision: u32,
+}
+
+impl Theme {
+    /// A new theme, ready to be shared.
+    pub fn shared(name: impl Into<String>, fg_color: Color) -> Rc<RefCell<Theme>> {
+        Rc::new(RefCell::new(Theme {
+            name: name.into(),
+            fg_color,
+            revision: 0,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Widget {
+    pub label: String,
+    pub x: u32,
+    pub y: u32,
+    theme: Rc<RefCell<Theme>>,
+}
+
+impl Widget {
+    pub fn new(label: impl Into<String>, x: u32, y: u32, theme: &Rc<RefCell<Theme>>) -> Self {
+        Widget {
+            label: label.into(),
+            x,
+            y,
+            theme: Rc::clone(theme),
+        }
+    }
+
+    pub fn theme(&self) -> &Rc<RefCell<Theme>> {
+        &self.theme
+    }
+
+    pub fn props(&self) -> DisplayProps {
+        DisplayProps {
+            x: self.x,
+            y: self.y,
+            color: self.theme.borrow().fg_color,
+        }
+    }
+
+    /// The label, in the theme's color as it is now.
+    pub fn render(&self) -> String {
+        styled(&self.label, &self.props())
+    }
+
+    /// Change the theme's color for every widget that shares it.
+    pub fn highlight(&self, color: Color) {
+        {
+            let mut theme = self.theme.borrow_mut();
+            theme.fg_color = color;
+        } // The first borrow ends here, so `bump_revision` can take its own.
+        self.bump_revision();
+    }
+
+    /// `highlight`, with the first borrow still alive when `bump_revision` asks for another,
+    /// which panics.
+    pub fn highlight_overlapping(&self, color: Color) {
+        let mut theme = self.theme.borrow_mut();
+        theme.fg_color = color;
+        self.bump_revision();
+    }
+
+    fn bump_revision(&self) {
+        self.theme.borrow_mut().revision += 1;
+    }
+}
+
+/// Text that goes with a theme only while something else keeps the theme around.
+#[derive(Debug, Clone)]
+pub struct Tooltip {
+    pub text: String,
+    theme: Weak<RefCell<Theme>>,
+}
+
+impl Tooltip {
+    pub fn new(text: impl Into<String>, theme: &Rc<RefCell<Theme>>) -> Self {
+        Tooltip {
+            text: text.into(),
+            theme: Rc::downgrade(theme),
+        }
+    }
+
+    /// The text in the theme's color, or `None` once the theme has been dropped.
+    pub fn render(&self) -> Option<String> {
+        let theme = self.theme.upgrade()?;
+        let props = DisplayProps {
+            x: 0,
+            y: 0,
+            color: theme.borrow().fg_color,
+        };
+        Some(styled(&self.text, &props))
+    }
+}
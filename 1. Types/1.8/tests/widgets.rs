@@ -0,0 +1,90 @@
+use item_1_1::render::foreground;
+use item_1_1::{Color, RgbColor};
+use item_1_8::{Theme, Tooltip, Widget};
+use std::rc::Rc;
+
+const BLUE: RgbColor = RgbColor(0, 0, 255);
+const RED: RgbColor = RgbColor(255, 0, 0);
+
+fn in_color(text: &str, color: RgbColor) -> String {
+    format!("{}{text}\x1b[0m", foreground(color))
+}
+
+#[test]
+fn a_change_through_one_widget_shows_in_all_of_them() {
+    let theme = Theme::shared("plain", Color::Monochrome);
+    let a = Widget::new("a", 0, 0, &theme);
+    let b = Widget::new("b", 5, 1, &theme);
+    assert_eq!((a.render(), b.render()), ("a".to_owned(), "b".to_owned()));
+
+    a.highlight(Color::Foreground(BLUE));
+    assert_eq!(a.render(), in_color("a", BLUE));
+    assert_eq!(b.render(), in_color("b", BLUE));
+    assert_eq!(b.props().color, Color::Foreground(BLUE));
+
+    // Through the original handle too.
+    theme.borrow_mut().fg_color = Color::Foreground(RED);
+    assert_eq!(b.render(), in_color("b", RED));
+}
+
+#[test]
+fn highlight_counts_revisions() {
+    let theme = Theme::shared("plain", Color::Monochrome);
+    let widget = Widget::new("w", 0, 0, &theme);
+    widget.highlight(Color::Foreground(BLUE));
+    widget.highlight(Color::Monochrome);
+    assert_eq!(theme.borrow().revision, 2);
+    assert_eq!(theme.borrow().fg_color, Color::Monochrome);
+}
+
+#[test]
+fn widgets_keep_the_theme_alive() {
+    let theme = Theme::shared("plain", Color::Foreground(BLUE));
+    let widget = Widget::new("w", 0, 0, &theme);
+    let copy = widget.clone();
+    assert_eq!(Rc::strong_count(&theme), 3);
+    drop(theme);
+    assert_eq!(Rc::strong_count(widget.theme()), 2);
+    assert!(Rc::ptr_eq(widget.theme(), copy.theme()));
+    assert_eq!(copy.render(), in_color("w", BLUE));
+}
+
+#[test]
+#[should_panic(expected = "already borrowed")]
+fn overlapping_mutable_borrows_panic() {
+    let theme = Theme::shared("plain", Color::Monochrome);
+    Widget::new("w", 0, 0, &theme).highlight_overlapping(Color::Foreground(RED));
+}
+
+#[test]
+fn rendering_while_the_theme_is_borrowed_mutably_fails() {
+    let theme = Theme::shared("plain", Color::Monochrome);
+    let widget = Widget::new("w", 0, 0, &theme);
+    let held = theme.borrow_mut();
+    assert!(theme.try_borrow().is_err());
+    drop(held);
+    assert!(theme.try_borrow().is_ok());
+    assert_eq!(widget.render(), "w");
+}
+
+#[test]
+fn a_tooltip_renders_while_the_theme_lives() {
+    let theme = Theme::shared("plain", Color::Foreground(RED));
+    let tooltip = Tooltip::new("tip", &theme);
+    // A `Weak` isn't an owner.
+    assert_eq!(Rc::strong_count(&theme), 1);
+    assert_eq!(Rc::weak_count(&theme), 1);
+    assert_eq!(tooltip.render(), Some(in_color("tip", RED)));
+}
+
+#[test]
+fn a_tooltip_whose_theme_was_dropped_renders_nothing() {
+    let theme = Theme::shared("plain", Color::Monochrome);
+    let widget = Widget::new("w", 0, 0, &theme);
+    let tooltip = Tooltip::new("tip", &theme);
+    drop(theme);
+    // The widget still owns it.
+    assert_eq!(tooltip.render(), Some("tip".to_owned()));
+    drop(widget);
+    assert_eq!(tooltip.render(), None);
+}
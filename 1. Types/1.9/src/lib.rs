@@ -0,0 +1,53 @@
+// Item 1.9: Consider using iterator transforms instead of explicit loops.
+//
+// A `for` loop works on anything that implements `IntoIterator`, and so do the transforms
+// (`map`, `filter`, `sum` and the rest) once `into_iter()` has turned it into an `Iterator`.
+// The standard collections all get this; `ring_buffer` shows what it takes for a collection
+// of our own.
+
+pub mod ring_buffer;
+
+pub use ring_buffer::RingBuffer;
+
+pub const DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
+
+pub fn run() -> Result<(), Box<dyn std::error::Error>> {
+    // The last four readings are all that's kept; pushing a fifth drops the first.
+    let mut readings = RingBuffer::new(4);
+    for reading in [12, 7, 19, 3, 15] {
+        if let Some(dropped) = readings.push(reading) {
+            println!("dropped {dropped}");
+        }
+    }
+
+    // An explicit loop, with the state it needs kept by hand:
+    let mut total = 0;
+    let mut count = 0;
+    for reading in &readings {
+        if *reading > 5 {
+            total += reading;
+            count += 1;
+        }
+    }
+    println!("{count} readings over 5 add up to {total}");
+
+    // The same with transforms, which say what's wanted rather than how to get it.
+    let over_five: Vec<i32> = readings.iter().copied().filter(|&r| r > 5).collect();
+    println!(
+        "{} readings over 5 add up to {}",
+        over_five.len(),
+        over_five.iter().sum::<i32>()
+    );
+    println!(
+        "newest first: {:?}",
+        readings.iter().rev().collect::<Vec<_>>()
+    );
+
+    for reading in &mut readings {
+        *reading *= 10;
+    }
+
+    // A `RingBuffer` is `Sort` and `IntoIterator`, which is all Item 1.2's `dump_sorted` asks.
+    item_1_2::dump_sorted(readings);
+    Ok(())
+}
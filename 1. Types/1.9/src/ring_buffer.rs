@@ -0,0 +1,243 @@
+// A fixed-capacity buffer that overwrites its oldest item when it is full, with all three
+// kinds of iterator a collection is expected to have:
+//
+// - `iter()`, and `for x in &buffer`, borrow each item in turn, as `Iter`;
+// - `iter_mut()`, and `for x in &mut buffer`, borrow each one mutably, as `IterMut`;
+// - `for x in buffer` consumes the buffer and hands over each item, as `IntoIter`.
+//
+// Each goes from oldest to newest, and can be run from the other end too. `Iter` is written out
+// by hand; the other two lean on the iterators of the `Vec` underneath.
+
+use item_1_2::Sort;
+use std::iter::{Chain, FusedIterator};
+use std::slice;
+
+#[derive(Debug, Clone)]
+pub struct RingBuffer<T> {
+    items: Vec<T>,
+    capacity: usize,
+    // Where the oldest item is in `items`, once it has filled up and started wrapping around.
+    start: usize,
+}
+
+impl<T> RingBuffer<T> {
+    /// An empty buffer that holds up to `capacity` items.
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "a ring buffer needs room for at least one item"
+        );
+        RingBuffer {
+            items: Vec::with_capacity(capacity),
+            capacity,
+            start: 0,
+        }
+    }
+
+    /// Add `item` as the newest item. If the buffer was already full, the oldest item makes
+    /// way for it and is returned.
+    pub fn push(&mut self, item: T) -> Option<T> {
+        if self.items.len() < self.capacity {
+            self.items.push(item);
+            return None;
+        }
+        let oldest = std::mem::replace(&mut self.items[self.start], item);
+        self.start = (self.start + 1) % self.capacity;
+        Some(oldest)
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Whether the next `push` will overwrite the oldest item.
+    pub fn is_full(&self) -> bool {
+        self.items.len() == self.capacity
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            items: &self.items,
+            start: self.start,
+            front: 0,
+            back: self.items.len(),
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        // The oldest items are from `start` to the end, and then the newest wrap around to the
+        // beginning.
+        let (newest, oldest) = self.items.split_at_mut(self.start);
+        IterMut(oldest.iter_mut().chain(newest.iter_mut()))
+    }
+
+    // Move the items around so that the oldest is first in `items`.
+    fn make_contiguous(&mut self) -> &mut [T] {
+        self.items.rotate_left(self.start);
+        self.start = 0;
+        &mut self.items
+    }
+}
+
+/// Two buffers are equal if they have the same capacity and hold the same items in the same
+/// order, oldest first, however far each has wrapped around. Deriving would compare `items`
+/// and `start` as they are stored.
+impl<T: PartialEq> PartialEq for RingBuffer<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.capacity == other.capacity && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for RingBuffer<T> {}
+
+/// Sorting puts the smallest item where the oldest was, so it is the next to be overwritten.
+impl<T: Ord> Sort for RingBuffer<T> {
+    fn sort(&mut self) {
+        self.make_contiguous().sort();
+    }
+
+    fn reverse(&mut self) {
+        self.make_contiguous().reverse();
+    }
+}
+
+/// Borrows each item of a [`RingBuffer`], oldest first.
+#[derive(Debug, Clone)]
+pub struct Iter<'a, T> {
+    items: &'a [T],
+    start: usize,
+    // Counted from the oldest item; the items still to come are those from `front` up to, but
+    // not including, `back`.
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn get(&self, n: usize) -> &'a T {
+        &self.items[(self.start + n) % self.items.len()]
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.front == self.back {
+            return None;
+        }
+        let item = self.get(self.front);
+        self.front += 1;
+        Some(item)
+    }
+
+    // Exact, which is what lets `collect` allocate once and `ExactSizeIterator` give `len()`.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.get(self.back))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+// Once `next` has returned `None`, it keeps doing so.
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// Borrows each item of a [`RingBuffer`] mutably, oldest first.
+#[derive(Debug)]
+pub struct IterMut<'a, T>(Chain<slice::IterMut<'a, T>, slice::IterMut<'a, T>>);
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        self.0.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+/// Hands over each item of a [`RingBuffer`], oldest first.
+#[derive(Debug, Clone)]
+pub struct IntoIter<T>(std::vec::IntoIter<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.0.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for RingBuffer<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(mut self) -> IntoIter<T> {
+        self.make_contiguous();
+        IntoIter(self.items.into_iter())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RingBuffer<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut RingBuffer<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
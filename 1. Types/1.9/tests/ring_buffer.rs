@@ -0,0 +1,150 @@
+use item_1_2::{write_sorted, Sort};
+use item_1_9::RingBuffer;
+
+fn buffer(capacity: usize, items: impl IntoIterator<Item = i32>) -> RingBuffer<i32> {
+    let mut buffer = RingBuffer::new(capacity);
+    for item in items {
+        buffer.push(item);
+    }
+    buffer
+}
+
+#[test]
+fn push_overwrites_the_oldest_once_full() {
+    let mut b = RingBuffer::new(2);
+    assert_eq!(b.push('a'), None);
+    assert!(!b.is_full());
+    assert_eq!(b.push('b'), None);
+    assert!(b.is_full());
+    assert_eq!(b.push('c'), Some('a'));
+    assert_eq!(b.push('d'), Some('b'));
+    assert_eq!((b.len(), b.capacity()), (2, 2));
+}
+
+#[test]
+#[should_panic(expected = "room for at least one item")]
+fn zero_capacity_panics() {
+    RingBuffer::<i32>::new(0);
+}
+
+#[test]
+fn iterates_oldest_first_after_wrapping_around() {
+    // Before wrapping, after wrapping part of the way, and after wrapping exactly once.
+    assert_eq!(buffer(4, 1..=3).iter().collect::<Vec<_>>(), [&1, &2, &3]);
+    assert_eq!(
+        buffer(4, 1..=6).iter().collect::<Vec<_>>(),
+        [&3, &4, &5, &6]
+    );
+    assert_eq!(buffer(3, 1..=6).iter().collect::<Vec<_>>(), [&4, &5, &6]);
+}
+
+#[test]
+fn the_three_into_iterator_forms() {
+    let mut b = buffer(3, 1..=5);
+
+    let mut borrowed = Vec::new();
+    for item in &b {
+        borrowed.push(*item);
+    }
+    assert_eq!(borrowed, [3, 4, 5]);
+
+    for item in &mut b {
+        *item *= 10;
+    }
+    assert_eq!(b.iter().copied().collect::<Vec<_>>(), [30, 40, 50]);
+
+    let mut owned = Vec::new();
+    for item in b {
+        owned.push(item);
+    }
+    assert_eq!(owned, [30, 40, 50]);
+}
+
+#[test]
+fn owned_items_are_moved_out() {
+    let mut b = RingBuffer::new(2);
+    for word in ["one", "two", "three"] {
+        b.push(word.to_owned());
+    }
+    let words: Vec<String> = b.into_iter().collect();
+    assert_eq!(words, ["two", "three"]);
+}
+
+#[test]
+fn double_ended_from_both_ends() {
+    let b = buffer(4, 1..=6);
+    assert_eq!(b.iter().rev().collect::<Vec<_>>(), [&6, &5, &4, &3]);
+
+    let mut it = b.iter();
+    assert_eq!(it.next(), Some(&3));
+    assert_eq!(it.next_back(), Some(&6));
+    assert_eq!(it.next(), Some(&4));
+    assert_eq!(it.next_back(), Some(&5));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+
+    let mut b = b;
+    assert_eq!(b.iter_mut().next_back(), Some(&mut 6));
+    assert_eq!(b.into_iter().rev().collect::<Vec<_>>(), [6, 5, 4, 3]);
+}
+
+#[test]
+fn size_hint_counts_down_exactly() {
+    let b = buffer(3, 1..=4);
+    let mut it = b.iter();
+    assert_eq!(it.size_hint(), (3, Some(3)));
+    it.next();
+    assert_eq!(it.len(), 2);
+    it.next_back();
+    assert_eq!(it.size_hint(), (1, Some(1)));
+    it.next();
+    assert_eq!(it.size_hint(), (0, Some(0)));
+
+    let mut b = b;
+    assert_eq!(b.iter_mut().len(), 3);
+    assert_eq!(b.into_iter().len(), 3);
+}
+
+#[test]
+fn an_empty_buffer_has_nothing_to_give() {
+    let mut b = RingBuffer::<i32>::new(3);
+    assert!(b.is_empty());
+    assert_eq!(b.iter().next(), None);
+    assert_eq!(b.iter().next_back(), None);
+    assert_eq!(b.iter().size_hint(), (0, Some(0)));
+    assert_eq!(b.iter_mut().next(), None);
+    b.sort();
+    assert_eq!(b.into_iter().next(), None);
+}
+
+#[test]
+fn sorting_puts_the_smallest_next_in_line_to_be_overwritten() {
+    let mut b = buffer(3, [5, 9, 1, 7]);
+    assert_eq!(b.iter().copied().collect::<Vec<_>>(), [9, 1, 7]);
+    b.sort();
+    assert_eq!(b.iter().copied().collect::<Vec<_>>(), [1, 7, 9]);
+    assert_eq!(b.push(4), Some(1));
+    b.sort_desc();
+    assert_eq!(b.into_iter().collect::<Vec<_>>(), [9, 7, 4]);
+}
+
+#[test]
+fn feeds_straight_into_write_sorted() {
+    let mut out = Vec::new();
+    write_sorted(buffer(3, [8, 2, 6, 4]), &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "2\n4\n6\n");
+}
+
+#[test]
+fn equal_whatever_the_wrapping() {
+    let wrapped = buffer(3, [1, 2, 3, 4]);
+    let unwrapped = buffer(3, [2, 3, 4]);
+    assert_eq!(wrapped, unwrapped);
+    // Sorting moves the items around inside, but not what they are.
+    let mut sorted = buffer(3, [9, 3, 4, 2]);
+    sorted.sort();
+    assert_eq!(sorted, unwrapped);
+    assert_ne!(buffer(3, [2, 3, 4]), buffer(4, [2, 3, 4]));
+    assert_ne!(buffer(3, [1, 2, 3]), buffer(3, [1, 3, 2]));
+    assert_ne!(buffer(3, [1, 2]), buffer(3, [1, 2, 3]));
+}
@@ -0,0 +1,52 @@
+//! `Debug` and `Display`, the two traits behind `{:?}` and `{}`. They answer different
+//! questions: `Debug` is for a programmer, and should show everything, while `Display` is for
+//! a user, and should show what they need in a form they would recognize.
+//!
+//! `Debug` can almost always be derived, as it is for Item 1.1's `Job` and Item 1.2's `Shape`,
+//! and `{:#?}` then lays it out over several lines for free. It is written by hand where the
+//! derived version is unhelpful: Item 1.1's `RgbColor` shows its components in hex.
+//! `Display` is never derived, because there is no one right way to show a type to a user.
+//! Item 1.1 has it for `Job`, `RgbColor`, `Color` and `DisplayProps`; `Shape`'s is its text
+//! form, which `FromStr` reads back.
+//!
+//! A `Display` impl that forwards to a field's, passing the same `Formatter` on, gets width and
+//! precision handling with it, as `Area` does:
+//!
+//! ```
+//! use item_1_2::Shape;
+//! use item_2_1::formatting::Area;
+//!
+//! let circle = Shape::Circle { radius: 2.0 };
+//! assert_eq!(format!("[{:>10.2}]", Area(&circle)), "[     12.57]");
+//! ```
+
+use item_1_2::Shape;
+use std::fmt::{self, Display};
+
+/// The area of a shape, formatted as a number.
+#[derive(Debug, Clone, Copy)]
+pub struct Area<'a>(pub &'a Shape);
+
+impl Display for Area<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Not `write!(f, "{}", ...)`, which would start again with a fresh set of options.
+        Display::fmt(&self.0.area(), f)
+    }
+}
+
+/// A numbered list of `items`, one to a line, for showing to a user.
+///
+/// The bound is `Display` rather than `Debug` because the result is meant to be read by
+/// someone who doesn't know, or care, how the items are represented: with `Debug`, a list of
+/// jobs would have `JobId(3)` and `submitted_at: 0` in it.
+pub fn summarize<T: Display>(items: &[T]) -> String {
+    if items.is_empty() {
+        return "nothing".to_owned();
+    }
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| format!("{}. {item}", i + 1))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,74 @@
+// Item 2.1: Familiarize yourself with standard traits.
+//
+// Operators are traits too: `a + b` is `Add::add(a, b)`, and `a * b` is `Mul::mul(a, b)`, so a
+// type gets an operator by implementing the trait in `std::ops`. The orphan rule says an impl
+// must be in the crate that defines either the trait or the type, so the impls used here live
+// beside the types themselves, in core-types: `Add` and `Mul<f64>` for `RgbColor` in its
+// `color` module, and `Mul<f64>` for `Shape` in `shapes`. Items 1.1 and 1.2 only re-export the
+// types, so they couldn't add these impls either.
+//
+// Each operator is implemented twice, once on values and once on references. Operator traits
+// take their operands by value, so with only the first, adding up the colors in a slice would
+// mean copying or cloning each one out first.
+//
+// Formatting is traits as well, `Debug` for `{:?}` and `Display` for `{}`; see `formatting`.
+
+pub mod formatting;
+
+use formatting::{summarize, Area};
+use item_1_1::{Color, DisplayProps, Job, RgbColor};
+use item_1_2::Shape;
+
+pub const DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
+
+/// All of `colors`' light together, which is black if there are none.
+pub fn mix(colors: &[RgbColor]) -> RgbColor {
+    colors
+        .iter()
+        .fold(RgbColor(0, 0, 0), |total, color| &total + color)
+}
+
+/// The area `shapes` would cover if each were scaled by `factor`, leaving them as they are.
+pub fn scaled_area(shapes: &[Shape], factor: f64) -> f64 {
+    shapes.iter().map(|shape| (shape * factor).area()).sum()
+}
+
+pub fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let orange = RgbColor(255, 128, 0);
+    let blue = RgbColor(0, 0, 255);
+    println!("{orange:?} + {blue:?} = {:?}", orange + blue);
+    println!("{orange:?} + {orange:?} = {:?}", orange + orange);
+    println!("{orange:?} at half brightness is {:?}", orange * 0.5);
+    println!("mixed: {:?}", mix(&[orange, blue, RgbColor(0, 64, 0)]));
+
+    let circle = Shape::Circle { radius: 1.0 };
+    let bigger = &circle * 2.0;
+    println!(
+        "doubling the radius takes the area from {:.2} to {:.2}",
+        circle.area(),
+        bigger.area()
+    );
+    let shapes = [circle, Shape::try_new_rectangle(2.0, 3.0)?];
+    println!(
+        "area {:.2}, or {:.2} at three times the size",
+        scaled_area(&shapes, 1.0),
+        scaled_area(&shapes, 3.0)
+    );
+
+    // The same values, for a programmer and for a user.
+    let job = Job::new(3, "backup").with_priority(2);
+    println!("{job:?}");
+    println!("{job}");
+    let props = DisplayProps {
+        x: 640,
+        y: 480,
+        color: Color::Foreground(orange),
+    };
+    println!("{props:#?}");
+    println!("{props}");
+    for shape in &shapes {
+        println!("area {:>8.2}: {shape}", Area(shape));
+    }
+    println!("{}", summarize(&[job, Job::new(4, "restore")]));
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use item_1_1::{Color, DisplayProps, Job, RgbColor};
+use item_1_2::Shape;
+use item_2_1::formatting::{summarize, Area};
+
+const ORANGE: RgbColor = RgbColor(0xff, 0x88, 0x00);
+
+#[test]
+fn derived_debug_shows_every_field() {
+    let job = Job::new(3, "backup").with_priority(2);
+    assert_eq!(
+        format!("{job:?}"),
+        r#"Job { id: JobId(3), payload: "backup", priority: 2, submitted_at: 0 }"#
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            Shape::Triangle {
+                a: 3.0,
+                b: 4.0,
+                c: 5.0
+            }
+        ),
+        "Triangle { a: 3.0, b: 4.0, c: 5.0 }"
+    );
+}
+
+#[test]
+fn colors_debug_in_hex() {
+    assert_eq!(format!("{ORANGE:?}"), "RgbColor(#ff8800)");
+    assert_eq!(format!("{:?}", RgbColor(0, 0, 0)), "RgbColor(#000000)");
+    assert_eq!(
+        format!("{:?}", Color::Foreground(ORANGE)),
+        "Foreground(RgbColor(#ff8800))"
+    );
+}
+
+#[test]
+fn pretty_debug_nests_the_manual_impl_too() {
+    let props = DisplayProps {
+        x: 640,
+        y: 480,
+        color: Color::Foreground(ORANGE),
+    };
+    assert_eq!(
+        format!("{props:#?}"),
+        "\
+DisplayProps {
+    x: 640,
+    y: 480,
+    color: Foreground(
+        RgbColor(
+            #ff8800,
+        ),
+    ),
+}"
+    );
+    assert_eq!(
+        format!("{:#?}", DisplayProps::default()),
+        "DisplayProps {\n    x: 0,\n    y: 0,\n    color: Monochrome,\n}"
+    );
+}
+
+#[test]
+fn display_is_for_people() {
+    assert_eq!(ORANGE.to_string(), "#ff8800");
+    assert_eq!(Color::Monochrome.to_string(), "monochrome");
+    let props = DisplayProps {
+        x: 640,
+        y: 480,
+        color: Color::Foreground(ORANGE),
+    };
+    assert_eq!(props.to_string(), "(640, 480) in #ff8800");
+    assert_eq!(Job::new(4, "restore").to_string(), r#"job 4 ("restore")"#);
+    assert_eq!(
+        Job::new(3, "backup").with_priority(2).to_string(),
+        r#"job 3 ("backup") at priority 2"#
+    );
+    assert_eq!(Shape::Circle { radius: 2.0 }.to_string(), "circle r=2.0");
+}
+
+#[test]
+fn area_takes_width_and_precision() {
+    let circle = Shape::Circle { radius: 2.0 };
+    let rectangle = Shape::Rectangle {
+        width: 1.5,
+        height: 2.0,
+    };
+    assert_eq!(format!("{:>10.2}", Area(&circle)), "     12.57");
+    assert_eq!(format!("{:>10.2}", Area(&rectangle)), "      3.00");
+    assert_eq!(format!("{:<8.1}|", Area(&rectangle)), "3.0     |");
+    assert_eq!(format!("{:08.3}", Area(&circle)), "0012.566");
+    assert_eq!(format!("{}", Area(&rectangle)), "3");
+}
+
+#[test]
+fn summarize_lists_items_as_displayed() {
+    let jobs = [
+        Job::new(3, "backup").with_priority(2),
+        Job::new(4, "restore"),
+    ];
+    assert_eq!(
+        summarize(&jobs),
+        "1. job 3 (\"backup\") at priority 2\n2. job 4 (\"restore\")"
+    );
+    let shapes = [
+        Shape::Circle { radius: 1.0 },
+        Shape::Rectangle {
+            width: 2.0,
+            height: 3.0,
+        },
+    ];
+    assert_eq!(summarize(&shapes), "1. circle r=1.0\n2. rect 2.0x3.0");
+    assert_eq!(summarize::<RgbColor>(&[]), "nothing");
+}
@@ -0,0 +1,128 @@
+use item_1_1::RgbColor;
+use item_1_2::Shape;
+use item_2_1::{mix, scaled_area};
+
+const BLACK: RgbColor = RgbColor(0, 0, 0);
+const WHITE: RgbColor = RgbColor(255, 255, 255);
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9 * b.abs().max(1.0)
+}
+
+#[test]
+fn adding_colors_adds_components() {
+    assert_eq!(
+        RgbColor(10, 20, 30) + RgbColor(1, 2, 3),
+        RgbColor(11, 22, 33)
+    );
+    assert_eq!(BLACK + RgbColor(7, 8, 9), RgbColor(7, 8, 9));
+}
+
+#[test]
+fn adding_colors_saturates_at_the_top() {
+    assert_eq!(
+        RgbColor(250, 128, 0) + RgbColor(10, 128, 255),
+        RgbColor(255, 255, 255)
+    );
+    assert_eq!(WHITE + WHITE, WHITE);
+    assert_eq!(
+        RgbColor(200, 0, 0) + RgbColor(55, 0, 0),
+        RgbColor(255, 0, 0)
+    );
+}
+
+#[test]
+fn brightness_scales_and_clamps() {
+    assert_eq!(RgbColor(100, 50, 255) * 0.5, RgbColor(50, 25, 128));
+    assert_eq!(RgbColor(100, 50, 255) * 1.0, RgbColor(100, 50, 255));
+    assert_eq!(RgbColor(100, 200, 255) * 2.0, RgbColor(200, 255, 255));
+    assert_eq!(RgbColor(100, 200, 255) * -1.0, BLACK);
+    assert_eq!(WHITE * f64::NAN, BLACK);
+}
+
+#[test]
+fn zero_brightness_is_black() {
+    assert_eq!(WHITE * 0.0, BLACK);
+    assert_eq!(&RgbColor(1, 2, 3) * 0.0, BLACK);
+}
+
+#[test]
+fn reference_operators_give_the_same_answers() {
+    let colors = [RgbColor(200, 10, 3), RgbColor(100, 20, 4)];
+    let (a, b) = (&colors[0], &colors[1]);
+    assert_eq!(a + b, colors[0] + colors[1]);
+    assert_eq!(a * 0.25, colors[0] * 0.25);
+    let circle = Shape::Circle { radius: 1.5 };
+    assert_eq!(&circle * 2.0, circle.clone() * 2.0);
+}
+
+#[test]
+fn reference_operators_leave_the_collection_usable() {
+    // `mix` and `scaled_area` only get slices, so they can't move anything out of them. Without
+    // the impls on references, each would have to copy or clone every item to use the operator.
+    let colors = vec![RgbColor(10, 0, 0), RgbColor(0, 20, 0), RgbColor(240, 0, 30)];
+    assert_eq!(mix(&colors), RgbColor(250, 20, 30));
+    assert_eq!(mix(&colors[..1]), colors[0]);
+    assert_eq!(mix(&[]), BLACK);
+
+    let shapes = vec![
+        Shape::Rectangle {
+            width: 2.0,
+            height: 3.0,
+        },
+        Shape::Triangle {
+            a: 3.0,
+            b: 4.0,
+            c: 5.0,
+        },
+    ];
+    assert!(close(scaled_area(&shapes, 1.0), 12.0));
+    // `shapes` is still here, unscaled.
+    assert_eq!(shapes[0].area(), 6.0);
+}
+
+#[test]
+fn scaling_a_shape_by_k_multiplies_its_area_by_k_squared() {
+    let shapes = [
+        Shape::Circle { radius: 1.0 },
+        Shape::Rectangle {
+            width: 2.0,
+            height: 0.5,
+        },
+        Shape::Triangle {
+            a: 3.0,
+            b: 4.0,
+            c: 5.0,
+        },
+    ];
+    for shape in &shapes {
+        for k in [0.5, 2.0, 3.0, 10.0] {
+            let scaled = shape * k;
+            assert!(
+                close(scaled.area(), shape.area() * k * k),
+                "{shape:?} * {k}"
+            );
+            assert!(
+                close(scaled.perimeter(), shape.perimeter() * k),
+                "{shape:?} * {k}"
+            );
+        }
+    }
+    assert!(close(
+        scaled_area(&shapes, 2.0),
+        4.0 * scaled_area(&shapes, 1.0)
+    ));
+}
+
+#[test]
+fn multiplying_a_shape_matches_scale() {
+    let mut scaled = Shape::Circle { radius: 2.0 };
+    scaled.scale(1.5);
+    assert_eq!(Shape::Circle { radius: 2.0 } * 1.5, scaled);
+}
+
+#[test]
+#[should_panic(expected = "scale factor must be positive and finite")]
+fn a_shape_cant_be_scaled_to_nothing() {
+    let _ = Shape::Circle { radius: 1.0 } * 0.0;
+}
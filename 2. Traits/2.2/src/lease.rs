@@ -0,0 +1,87 @@
+// A `CpuPool` that hands out CPUs one holder at a time. Holding a CPU is holding a
+// `CpuLease`, and the CPU is free again as soon as the lease is dropped: at the end of its
+// scope, on an early `return` or `?`, or while a panic unwinds the stack. Nothing has to
+// remember to give it back.
+
+use item_1_1::CpuId;
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeaseError {
+    /// The CPU is already leased to someone else.
+    CpuBusy(CpuId),
+}
+
+impl fmt::Display for LeaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LeaseError::CpuBusy(cpu) => write!(f, "cpu {cpu} is already in use"),
+        }
+    }
+}
+
+impl std::error::Error for LeaseError {}
+
+#[derive(Debug, Default)]
+pub struct CpuPool {
+    // A `RefCell`, so that leases only need a shared reference to hand their CPU back, and
+    // several can be out at once.
+    busy: RefCell<BTreeSet<CpuId>>,
+}
+
+impl CpuPool {
+    pub fn new() -> Self {
+        CpuPool::default()
+    }
+
+    /// Mark `cpu` busy until the returned lease is dropped.
+    pub fn acquire(&self, cpu: CpuId) -> Result<CpuLease<'_>, LeaseError> {
+        if !self.busy.borrow_mut().insert(cpu) {
+            return Err(LeaseError::CpuBusy(cpu));
+        }
+        Ok(CpuLease { pool: self, cpu })
+    }
+
+    pub fn is_busy(&self, cpu: CpuId) -> bool {
+        self.busy.borrow().contains(&cpu)
+    }
+
+    /// The CPUs that are leased out, in order.
+    pub fn busy(&self) -> Vec<CpuId> {
+        self.busy.borrow().iter().copied().collect()
+    }
+}
+
+/// The right to use one CPU, which goes back to the [`CpuPool`] when this is dropped.
+#[derive(Debug)]
+#[must_use = "the CPU is released as soon as the lease is dropped"]
+pub struct CpuLease<'a> {
+    pool: &'a CpuPool,
+    cpu: CpuId,
+}
+
+impl CpuLease<'_> {
+    pub fn cpu(&self) -> CpuId {
+        self.cpu
+    }
+
+    /// Give the CPU back now, rather than at the end of the scope. This is just `drop`, under a
+    /// name that says what it's for.
+    pub fn release(self) {}
+
+    /// Keep the CPU busy for good, and return it without the lease. `Drop` is never
+    /// guaranteed to run: `std::mem::forget` is safe, and so is this.
+    pub fn leak(self) -> CpuId {
+        let cpu = self.cpu;
+        std::mem::forget(self);
+        cpu
+    }
+}
+
+impl Drop for CpuLease<'_> {
+    fn drop(&mut self) {
+        self.pool.busy.borrow_mut().remove(&self.cpu);
+    }
+}
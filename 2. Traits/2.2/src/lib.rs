@@ -0,0 +1,55 @@
+// Item 2.2: Implement the Drop trait for RAII patterns.
+//
+// Resource Acquisition Is Initialization: a value that owns a resource gives it back in its
+// `Drop` implementation, and the compiler inserts the call to `drop` wherever the value goes
+// out of scope, on every path out of it. Here the resource is a CPU, named by Item 1.1's
+// `CpuId`, from a pool that lends each one to a single holder at a time; see `lease`.
+
+pub mod lease;
+
+pub use lease::{CpuLease, CpuPool, LeaseError};
+
+use item_1_1::CpuId;
+
+pub const DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
+
+/// Count the words in `text`, holding `cpu` while doing so. Blank text returns early, and text
+/// with a NUL in it panics; the CPU is free again afterwards however this finishes.
+pub fn word_count_on(pool: &CpuPool, cpu: CpuId, text: &str) -> Result<usize, LeaseError> {
+    // Named `_lease` so that it lives to the end of the function. `let _ = ...` would drop it,
+    // and release the CPU, straight away.
+    let _lease = pool.acquire(cpu)?;
+    if text.trim().is_empty() {
+        return Ok(0);
+    }
+    assert!(!text.contains('\0'), "can't count the words in binary data");
+    Ok(text.split_whitespace().count())
+}
+
+pub fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let pool = CpuPool::new();
+
+    {
+        let lease = pool.acquire(CpuId(0))?;
+        println!("holding cpu {}; busy: {:?}", lease.cpu(), pool.busy());
+        // A second holder is turned away while the first has it.
+        if let Err(e) = pool.acquire(CpuId(0)) {
+            println!("{e}");
+        }
+    } // `lease` is dropped here, which frees cpu 0.
+    println!("after the scope; busy: {:?}", pool.busy());
+
+    let lease = pool.acquire(CpuId(1))?;
+    lease.release();
+    println!("after release; busy: {:?}", pool.busy());
+
+    // Both the early return and the normal one give the CPU back.
+    println!("{} words", word_count_on(&pool, CpuId(2), "a b c")?);
+    println!("{} words", word_count_on(&pool, CpuId(2), "   ")?);
+    println!("after the worker; busy: {:?}", pool.busy());
+
+    // A leaked lease never gives its CPU back.
+    let cpu = pool.acquire(CpuId(3))?.leak();
+    println!("leaked cpu {cpu}; busy: {:?}", pool.busy());
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use item_1_1::CpuId;
+use item_2_2::{word_count_on, CpuPool, LeaseError};
+use std::panic::{self, AssertUnwindSafe};
+
+#[test]
+fn dropping_the_lease_frees_the_cpu() {
+    let pool = CpuPool::new();
+    {
+        let lease = pool.acquire(CpuId(0)).unwrap();
+        assert_eq!(lease.cpu(), CpuId(0));
+        assert!(pool.is_busy(CpuId(0)));
+        assert!(!pool.is_busy(CpuId(1)));
+    }
+    assert!(!pool.is_busy(CpuId(0)));
+    assert!(pool.busy().is_empty());
+}
+
+#[test]
+fn release_frees_the_cpu_early() {
+    let pool = CpuPool::new();
+    let first = pool.acquire(CpuId(0)).unwrap();
+    let second = pool.acquire(CpuId(1)).unwrap();
+    assert_eq!(pool.busy(), [CpuId(0), CpuId(1)]);
+    first.release();
+    assert_eq!(pool.busy(), [CpuId(1)]);
+    drop(second);
+    assert!(pool.busy().is_empty());
+}
+
+#[test]
+fn a_busy_cpu_cant_be_acquired_again() {
+    let pool = CpuPool::new();
+    let lease = pool.acquire(CpuId(5)).unwrap();
+    let err = pool.acquire(CpuId(5)).unwrap_err();
+    assert_eq!(err, LeaseError::CpuBusy(CpuId(5)));
+    assert_eq!(err.to_string(), "cpu 5 is already in use");
+    // The failed attempt didn't free the CPU on its way out.
+    assert!(pool.is_busy(CpuId(5)));
+    drop(lease);
+    assert!(pool.acquire(CpuId(5)).is_ok());
+}
+
+#[test]
+fn a_leaked_lease_keeps_the_cpu_busy() {
+    let pool = CpuPool::new();
+    let cpu = pool.acquire(CpuId(2)).unwrap().leak();
+    assert_eq!(cpu, CpuId(2));
+    assert!(pool.is_busy(CpuId(2)));
+    assert!(pool.acquire(CpuId(2)).is_err());
+}
+
+#[test]
+fn early_return_frees_the_cpu() {
+    let pool = CpuPool::new();
+    assert_eq!(word_count_on(&pool, CpuId(0), " \n"), Ok(0));
+    assert!(!pool.is_busy(CpuId(0)));
+    assert_eq!(word_count_on(&pool, CpuId(0), "one two three"), Ok(3));
+    assert!(!pool.is_busy(CpuId(0)));
+}
+
+#[test]
+fn error_from_acquire_leaves_the_holder_alone() {
+    let pool = CpuPool::new();
+    let lease = pool.acquire(CpuId(0)).unwrap();
+    assert_eq!(
+        word_count_on(&pool, CpuId(0), "words"),
+        Err(LeaseError::CpuBusy(CpuId(0)))
+    );
+    assert!(pool.is_busy(CpuId(0)));
+    drop(lease);
+}
+
+#[test]
+fn panicking_frees_the_cpu() {
+    let pool = CpuPool::new();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        word_count_on(&pool, CpuId(1), "binary\0data")
+    }));
+    assert!(result.is_err());
+    assert!(!pool.is_busy(CpuId(1)));
+
+    // The same for a lease held directly.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let _lease = pool.acquire(CpuId(1)).unwrap();
+        assert!(pool.is_busy(CpuId(1)));
+        panic!("worker failed");
+    }));
+    assert!(result.is_err());
+    assert!(pool.busy().is_empty());
+}
@@ -0,0 +1,120 @@
+//! The same sum of areas three ways, to compare how each finds the code to run.
+//!
+//! - `total_area_enum` takes Item 1.2's `Shape`, and `Shape::area` matches on the variant: one
+//!   function, with a branch per kind of shape, which the compiler can see all of.
+//! - `total_area_dyn` takes boxed trait objects. Each call goes through a vtable, which rules
+//!   out inlining, and each shape is a separate heap allocation, reached through a pointer.
+//! - `total_area_generic` is compiled afresh for each `T`, so the call is direct and can be
+//!   inlined, but every element of the slice has to be the same type.
+//!
+//! The layouts differ as much as the calls. A `Shape` is as big as its largest variant, plus
+//! a tag, and sits inline in the slice. A `Box<dyn Area>` is two pointers, one to the shape and
+//! one to its vtable, and the shape itself is only as big as it needs to be, but somewhere
+//! else. transforms-bench's `total_area` criterion bench times the first two on ten million
+//! shapes.
+//!
+//! `Area` for `Shape` forwards to the `match`, and `Area` for `Box<T>` forwards to the boxed
+//! value, so the generic version also runs on either of the other two's slices.
+
+use item_1_2::Shape;
+use std::f64::consts::PI;
+
+pub trait Area {
+    fn area(&self) -> f64;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub width: f64,
+    pub height: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle {
+    pub radius: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+// The same arithmetic as `Shape::area`, in the same order, so the results are identical to the
+// last bit rather than merely close.
+
+impl Area for Rect {
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+}
+
+impl Area for Circle {
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+}
+
+impl Area for Triangle {
+    fn area(&self) -> f64 {
+        let Triangle { a, b, c } = self;
+        let s = (a + b + c) / 2.0;
+        (s * (s - a) * (s - b) * (s - c)).sqrt()
+    }
+}
+
+impl Area for Shape {
+    fn area(&self) -> f64 {
+        Shape::area(self)
+    }
+}
+
+impl<T: Area + ?Sized> Area for Box<T> {
+    fn area(&self) -> f64 {
+        (**self).area()
+    }
+}
+
+/// `shape` as a trait object, holding the struct for its variant.
+pub fn boxed(shape: &Shape) -> Box<dyn Area> {
+    match *shape {
+        Shape::Rectangle { width, height } => Box::new(Rect { width, height }),
+        Shape::Circle { radius } => Box::new(Circle { radius }),
+        Shape::Triangle { a, b, c } => Box::new(Triangle { a, b, c }),
+    }
+}
+
+pub fn total_area_enum(shapes: &[Shape]) -> f64 {
+    shapes.iter().map(Shape::area).sum()
+}
+
+pub fn total_area_dyn(shapes: &[Box<dyn Area>]) -> f64 {
+    shapes.iter().map(|shape| shape.area()).sum()
+}
+
+pub fn total_area_generic<T: Area>(shapes: &[T]) -> f64 {
+    shapes.iter().map(T::area).sum()
+}
+
+/// `n` shapes, taking turns at being a rectangle, a circle and a triangle, with sizes that
+/// vary so that no two neighbours are the same. The same `n` always gives the same shapes.
+pub fn mixed_shapes(n: usize) -> Vec<Shape> {
+    (0..n)
+        .map(|i| {
+            let size = 1.0 + (i % 97) as f64 / 8.0;
+            match i % 3 {
+                0 => Shape::Rectangle {
+                    width: size,
+                    height: 2.0,
+                },
+                1 => Shape::Circle { radius: size },
+                _ => Shape::Triangle {
+                    a: size,
+                    b: size,
+                    c: size,
+                },
+            }
+        })
+        .collect()
+}
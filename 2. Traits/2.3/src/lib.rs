@@ -0,0 +1,64 @@
+// Item 2.3: Understand the trade-offs between generics and trait objects.
+//
+// A generic function is compiled again for each type it is used with, which makes each call
+// direct and lets it be inlined, but each call still deals in one type only. A trait object
+// defers the choice to run time, so one `Vec` can hold different types, at the cost of a
+// vtable lookup per call, and only for traits that are object safe. Item 1.2's `Sort` and
+// `IntoIterator` together aren't, as `object_safety` shows, along with the ways around it.
+//
+// There is a third option when the set of types is closed: an enum, with a `match` in each
+// method. `dispatch` compares it with the other two.
+
+pub mod dispatch;
+pub mod object_safety;
+
+pub use object_safety::{
+    dump_sorted_boxed, write_sorted_boxed, write_sorted_wrapped, Boxed, DynSortIntoIter,
+    DynSortIter,
+};
+
+use dispatch::{boxed, mixed_shapes, total_area_dyn, total_area_enum, total_area_generic};
+use item_1_2::{Shape, SortedList};
+use std::mem::size_of;
+
+pub const DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
+
+pub fn run() -> Result<(), Box<dyn std::error::Error>> {
+    // Generic: one copy of `dump_sorted` for `Vec<i32>`, and another for `SortedList<&str>`.
+    item_1_2::dump_sorted(vec![3, 1, 2]);
+    item_1_2::dump_sorted(["pear", "fig"].into_iter().collect::<SortedList<_>>());
+
+    // Trait objects: one `Vec`, holding both kinds of collection.
+    let fruit: SortedList<&str> = ["pear", "apple", "fig"].into_iter().collect();
+    dump_sorted_boxed(vec![Box::new(vec![30, 10, 20]), Box::new(fruit)]);
+
+    // The wrapper takes anything `dump_sorted` would, with no `DynSortIter` impl needed.
+    let collections: Vec<Box<dyn DynSortIntoIter>> = vec![
+        Box::new(Boxed(vec!['c', 'a', 'b'])),
+        Box::new(Boxed(
+            ["kiwi", "date"].into_iter().collect::<SortedList<_>>(),
+        )),
+    ];
+    write_sorted_wrapped(collections, &mut std::io::stdout())?;
+
+    // Three ways to add up areas, all with the same answer.
+    let shapes = mixed_shapes(9);
+    let boxes: Vec<_> = shapes.iter().map(boxed).collect();
+    println!("enum:    {}", total_area_enum(&shapes));
+    println!("dyn:     {}", total_area_dyn(&boxes));
+    println!("generic: {}", total_area_generic(&shapes));
+    // An enum is as big as its biggest variant and its tag; a box is two pointers, and the
+    // struct it points to is allocated on its own.
+    println!("size_of::<Shape>() = {}", size_of::<Shape>());
+    println!(
+        "size_of::<Box<dyn Area>>() = {}",
+        size_of::<Box<dyn dispatch::Area>>()
+    );
+    println!("size_of::<Rect>() = {}", size_of::<dispatch::Rect>());
+    println!("size_of::<Circle>() = {}", size_of::<dispatch::Circle>());
+    println!(
+        "size_of::<Triangle>() = {}",
+        size_of::<dispatch::Triangle>()
+    );
+    Ok(())
+}
@@ -0,0 +1,120 @@
+//! Why Item 1.2's `dump_sorted` can't take a `Box<dyn Sort + IntoIterator>`, kept as
+//! `compile_fail` doctests with the error each attempt fails with, and two ways around it.
+//!
+//! A trait object can only name one trait, apart from auto traits such as `Send`:
+//!
+//! ```compile_fail,E0225
+//! use item_1_2::Sort;
+//!
+//! fn dump_sorted(collection: Box<dyn Sort + IntoIterator>) {}
+//! ```
+//!
+//! A trait with both as supertraits gets past that, but `IntoIterator` has associated types, and
+//! a trait object has to say what they are, since different implementors pick different ones:
+//!
+//! ```compile_fail,E0191
+//! use item_1_2::Sort;
+//!
+//! trait SortIntoIter: Sort + IntoIterator {}
+//!
+//! fn dump_sorted(collection: Box<dyn SortIntoIter>) {}
+//! ```
+//!
+//! Naming them ties the box to collections with exactly that iterator, which rules out mixing
+//! kinds of collection, the reason to want a trait object in the first place. And even then,
+//! `into_iter` takes `self` by value, and a `dyn` value has no size known at compile time, so
+//! it can't be moved out of its box to be called:
+//!
+//! ```compile_fail,E0161
+//! use item_1_2::Sort;
+//!
+//! trait SortIntoIter: Sort + IntoIterator {}
+//!
+//! fn dump_sorted(mut collection: Box<dyn SortIntoIter<Item = i32, IntoIter = std::vec::IntoIter<i32>>>) {
+//!     collection.sort();
+//!     for item in *collection {
+//!         println!("{item:?}");
+//!     }
+//! }
+//! ```
+//!
+//! Both workarounds below use traits whose methods only take `&self`, `&mut self` or
+//! `self: Box<Self>`, and hide the item type behind `dyn Debug`:
+//!
+//! - [`DynSortIter`], Item 1.2's `SortAndIter`, borrows the items, and is implemented for each
+//!   collection by hand;
+//! - [`Boxed`] wraps any `T: Sort + IntoIterator`, the same bounds as `dump_sorted`, and gets
+//!   [`DynSortIntoIter`] from one blanket impl, handing the items over when it is consumed.
+
+use item_1_2::Sort;
+use std::fmt::Debug;
+use std::io::{self, Write};
+
+// A SUPERTRAIT THAT BORROWS
+
+/// A collection that can be sorted, and whose items can be borrowed for printing, through a
+/// trait object. This is Item 1.2's `SortAndIter`, under the name used here.
+pub use item_1_2::SortAndIter as DynSortIter;
+
+pub fn dump_sorted_boxed(items: Vec<Box<dyn DynSortIter>>) {
+    write_sorted_boxed(items, &mut io::stdout()).expect("failed writing to stdout");
+}
+
+/// `dump_sorted_boxed`, but to any writer rather than stdout. Each collection is sorted and
+/// written in turn, by Item 1.2's `write_sorted_dyn`.
+pub fn write_sorted_boxed(items: Vec<Box<dyn DynSortIter>>, out: &mut dyn Write) -> io::Result<()> {
+    for mut collection in items {
+        item_1_2::write_sorted_dyn(collection.as_mut(), out)?;
+    }
+    Ok(())
+}
+
+// A GENERIC WRAPPER
+
+/// A collection that can be sorted, and then consumed for its items, through a trait object.
+/// `self: Box<Self>` is allowed where `self` isn't: a box is always the same size.
+pub trait DynSortIntoIter {
+    fn sort(&mut self);
+    fn into_dyn_iter(self: Box<Self>) -> Box<dyn Iterator<Item = Box<dyn Debug>>>;
+}
+
+/// Wraps anything `dump_sorted` would accept, so that it can be boxed as a
+/// `dyn DynSortIntoIter` without an impl of its own.
+///
+/// The blanket impl is on the wrapper rather than on every `T` so that it claims no types it
+/// wasn't asked to: other crates can still implement `DynSortIntoIter` for their own.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Boxed<T>(pub T);
+
+impl<T> DynSortIntoIter for Boxed<T>
+where
+    T: Sort + IntoIterator,
+    T::Item: Debug + 'static,
+    T::IntoIter: 'static,
+{
+    fn sort(&mut self) {
+        self.0.sort();
+    }
+
+    fn into_dyn_iter(self: Box<Self>) -> Box<dyn Iterator<Item = Box<dyn Debug>>> {
+        Box::new(
+            self.0
+                .into_iter()
+                .map(|item| Box::new(item) as Box<dyn Debug>),
+        )
+    }
+}
+
+/// `write_sorted_boxed` for wrapped collections, which are used up on the way.
+pub fn write_sorted_wrapped(
+    items: Vec<Box<dyn DynSortIntoIter>>,
+    out: &mut dyn Write,
+) -> io::Result<()> {
+    for mut collection in items {
+        collection.sort();
+        for item in collection.into_dyn_iter() {
+            writeln!(out, "{item:?}")?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use item_1_2::Shape;
+use item_2_3::dispatch::{
+    boxed, mixed_shapes, total_area_dyn, total_area_enum, total_area_generic, Area, Circle, Rect,
+    Triangle,
+};
+use std::mem::size_of;
+
+#[test]
+fn every_version_gives_the_same_total() {
+    let shapes = mixed_shapes(10_000);
+    let boxes: Vec<Box<dyn Area>> = shapes.iter().map(boxed).collect();
+    let total = total_area_enum(&shapes);
+    // Exactly equal: the same arithmetic, added up in the same order.
+    assert_eq!(total_area_dyn(&boxes).to_bits(), total.to_bits());
+    assert_eq!(total_area_generic(&shapes).to_bits(), total.to_bits());
+    assert_eq!(total_area_generic(&boxes).to_bits(), total.to_bits());
+    assert!(total > 0.0);
+}
+
+#[test]
+fn generic_over_one_concrete_type_agrees() {
+    let circles: Vec<Circle> = (1..=100)
+        .map(|r| Circle {
+            radius: f64::from(r) / 4.0,
+        })
+        .collect();
+    let shapes: Vec<Shape> = circles
+        .iter()
+        .map(|c| Shape::Circle { radius: c.radius })
+        .collect();
+    assert_eq!(
+        total_area_generic(&circles).to_bits(),
+        total_area_enum(&shapes).to_bits()
+    );
+}
+
+#[test]
+fn each_struct_matches_its_variant() {
+    for shape in mixed_shapes(300) {
+        assert_eq!(
+            boxed(&shape).area().to_bits(),
+            shape.area().to_bits(),
+            "{shape}"
+        );
+    }
+    assert_eq!(
+        Rect {
+            width: 2.0,
+            height: 3.0
+        }
+        .area(),
+        6.0
+    );
+    assert_eq!(
+        Triangle {
+            a: 3.0,
+            b: 4.0,
+            c: 5.0
+        }
+        .area(),
+        6.0
+    );
+}
+
+#[test]
+fn mixed_shapes_takes_turns_and_repeats() {
+    let shapes = mixed_shapes(6);
+    assert!(matches!(shapes[0], Shape::Rectangle { .. }));
+    assert!(matches!(shapes[1], Shape::Circle { .. }));
+    assert!(matches!(shapes[2], Shape::Triangle { .. }));
+    assert!(matches!(shapes[3], Shape::Rectangle { .. }));
+    assert_eq!(mixed_shapes(6), shapes);
+    assert!(mixed_shapes(0).is_empty());
+}
+
+#[test]
+fn layouts() {
+    // A tag, plus room for a triangle's three sides.
+    assert_eq!(size_of::<Shape>(), 32);
+    // A pointer to the data and a pointer to the vtable.
+    assert_eq!(size_of::<Box<dyn Area>>(), 2 * size_of::<usize>());
+    assert_eq!(size_of::<Rect>(), 16);
+    assert_eq!(size_of::<Circle>(), 8);
+    assert_eq!(size_of::<Triangle>(), 24);
+}
@@ -0,0 +1,66 @@
+use item_1_2::SortedList;
+use item_2_3::{write_sorted_boxed, write_sorted_wrapped, Boxed, DynSortIntoIter, DynSortIter};
+
+fn boxed_output(items: Vec<Box<dyn DynSortIter>>) -> String {
+    let mut out = Vec::new();
+    write_sorted_boxed(items, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+fn wrapped_output(items: Vec<Box<dyn DynSortIntoIter>>) -> String {
+    let mut out = Vec::new();
+    write_sorted_wrapped(items, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+fn fruit() -> SortedList<&'static str> {
+    ["pear", "apple", "fig"].into_iter().collect()
+}
+
+#[test]
+fn boxed_collections_are_each_sorted_in_turn() {
+    let output = boxed_output(vec![
+        Box::new(vec![3, -1, 2]),
+        Box::new(fruit()),
+        Box::new(vec![2.5f64.to_string(), "10".to_owned()]),
+    ]);
+    assert_eq!(
+        output,
+        "-1\n2\n3\n\"apple\"\n\"fig\"\n\"pear\"\n\"10\"\n\"2.5\"\n"
+    );
+}
+
+#[test]
+fn boxed_matches_the_generic_version() {
+    let mut generic = Vec::new();
+    item_1_2::write_sorted(vec![5, 4, 6], &mut generic).unwrap();
+    item_1_2::write_sorted(fruit(), &mut generic).unwrap();
+    let dynamic = boxed_output(vec![Box::new(vec![5, 4, 6]), Box::new(fruit())]);
+    assert_eq!(dynamic, String::from_utf8(generic).unwrap());
+}
+
+#[test]
+fn wrapped_collections_are_each_sorted_in_turn() {
+    let output = wrapped_output(vec![
+        Box::new(Boxed(fruit())),
+        Box::new(Boxed(vec![3u8, 1, 2])),
+    ]);
+    assert_eq!(output, "\"apple\"\n\"fig\"\n\"pear\"\n1\n2\n3\n");
+}
+
+#[test]
+fn wrapped_and_boxed_agree() {
+    let boxed = boxed_output(vec![Box::new(vec![9, 7, 8]), Box::new(fruit())]);
+    let wrapped = wrapped_output(vec![
+        Box::new(Boxed(vec![9, 7, 8])),
+        Box::new(Boxed(fruit())),
+    ]);
+    assert_eq!(boxed, wrapped);
+}
+
+#[test]
+fn no_collections_no_output() {
+    assert_eq!(boxed_output(Vec::new()), "");
+    assert_eq!(wrapped_output(Vec::new()), "");
+    assert_eq!(boxed_output(vec![Box::new(Vec::<i32>::new())]), "");
+}
@@ -0,0 +1,51 @@
+// Item 3.1: Understand lifetimes.
+//
+// A reference's lifetime is the stretch of code it is valid for, and the borrow checker makes
+// sure it never outlives what it refers to. Most of the time the lifetimes are elided, and the
+// compiler fills them in; they only need writing out when a type holds a reference, or a
+// function's result could borrow from more than one of its inputs.
+//
+// `passwd_ref` parses a password file into entries that borrow their fields from the file's
+// text, which saves copying them, at the cost of a lifetime parameter on the entry type.
+
+pub mod passwd_ref;
+
+pub use passwd_ref::{entries, parse_line_ref, PasswdEntryRef};
+
+use item_1_3::PasswdEntry;
+
+pub const DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
+
+pub fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let content = String::from(
+        "\
+root:x:0:0:root:/root:/bin/sh
+
+alice:x:1000:1000:Alice:/home/alice:/bin/zsh
+bob:x:oops:1000:Bob:/home/bob:/bin/sh
+",
+    );
+    for entry in entries(&content) {
+        match entry {
+            Ok(entry) => println!(
+                "{} has uid {} and shell {}",
+                entry.name, entry.uid.0, entry.shell
+            ),
+            Err(e) => println!("skipping a malformed line: {e}"),
+        }
+    }
+
+    // The fields are slices of `content` itself, not copies of it.
+    let alice = entries(&content).nth(1).expect("alice is there")?;
+    let offset = alice.home.as_ptr() as usize - content.as_ptr() as usize;
+    println!(
+        "alice's home is {:?}, at byte {offset} of the file",
+        alice.home
+    );
+
+    // An owned entry doesn't need `content` to stay around; `alice` would.
+    let owned: PasswdEntry = alice.into();
+    drop(content);
+    println!("owned copy: {}", owned.to_line());
+    Ok(())
+}
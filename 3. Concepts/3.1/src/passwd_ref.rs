@@ -0,0 +1,112 @@
+//! Item 1.3's password file parser, without the copying. `parse_line` copies every field into a
+//! `String` of its own; `parse_line_ref` returns a `PasswdEntryRef<'a>` whose fields are
+//! slices of the line it was given, so a whole file can be scanned, with `entries`, without
+//! allocating anything unless a line is malformed.
+//!
+//! An entry borrows from the line, so the lifetime `'a` ties the two together: the entry can't
+//! be used after the string it points into is gone. Returning one parsed from a local string is
+//! the classic mistake, and doesn't compile:
+//!
+//! ```compile_fail,E0515
+//! use item_3_1::passwd_ref::{parse_line_ref, PasswdEntryRef};
+//!
+//! fn root() -> PasswdEntryRef<'static> {
+//!     let line = String::from("root:x:0:0:root:/root:/bin/sh");
+//!     parse_line_ref(&line).unwrap()
+//! }
+//! ```
+//!
+//! The string is dropped at the end of the function, and the entry would point into freed
+//! memory. Either the caller supplies the string, or the entry is converted into an owned
+//! `PasswdEntry` before the string goes:
+//!
+//! ```
+//! use item_1_3::PasswdEntry;
+//! use item_3_1::passwd_ref::parse_line_ref;
+//!
+//! fn root() -> PasswdEntry {
+//!     let line = String::from("root:x:0:0:root:/root:/bin/sh");
+//!     parse_line_ref(&line).unwrap().into()
+//! }
+//!
+//! assert_eq!(root().home, "/root");
+//! ```
+
+use item_1_3::{ParseError, PasswdEntry, UserId};
+
+/// A `PasswdEntry` that borrows its text fields from the line it was parsed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PasswdEntryRef<'a> {
+    pub name: &'a str,
+    pub password: &'a str,
+    pub uid: UserId,
+    pub gid: u32,
+    pub gecos: &'a str,
+    pub home: &'a str,
+    pub shell: &'a str,
+}
+
+impl From<PasswdEntryRef<'_>> for PasswdEntry {
+    fn from(entry: PasswdEntryRef<'_>) -> Self {
+        PasswdEntry {
+            name: entry.name.to_owned(),
+            password: entry.password.to_owned(),
+            uid: entry.uid,
+            gid: entry.gid,
+            gecos: entry.gecos.to_owned(),
+            home: entry.home.to_owned(),
+            shell: entry.shell.to_owned(),
+        }
+    }
+}
+
+/// `item_1_3::passwd::parse_line`, borrowing rather than copying. It accepts and rejects the
+/// same lines, with the same errors.
+///
+/// The elided lifetime in the return type is the one on `line`: with a single reference
+/// among the parameters, that is the only place the result can borrow from.
+pub fn parse_line_ref(line: &str) -> Result<PasswdEntryRef<'_>, ParseError> {
+    // Counting into an array rather than collecting into a `Vec`, which would allocate.
+    let mut fields = [""; 7];
+    let mut count = 0;
+    for field in line.split(':') {
+        if let Some(slot) = fields.get_mut(count) {
+            *slot = field;
+        }
+        count += 1;
+    }
+    if count != fields.len() {
+        return Err(ParseError::FieldCount(count));
+    }
+    let [name, password, uid, gid, gecos, home, shell] = fields;
+    if name.is_empty() {
+        return Err(ParseError::EmptyName);
+    }
+    let id = |field, value: &str| {
+        value.parse().map_err(|_| ParseError::InvalidId {
+            field,
+            value: value.to_owned(),
+        })
+    };
+    Ok(PasswdEntryRef {
+        name,
+        password,
+        uid: UserId(id("uid", uid)?),
+        gid: id("gid", gid)?,
+        gecos,
+        home,
+        shell,
+    })
+}
+
+/// Every entry in `content`, a whole password file, in order. Blank lines are skipped, as
+/// `find_user` skips them; a malformed line is an `Err` in its place, and the lines after it
+/// are still parsed.
+///
+/// The `+ '_` says that the iterator, as well as the entries it yields, borrows `content`.
+pub fn entries(content: &str) -> impl Iterator<Item = Result<PasswdEntryRef<'_>, ParseError>> + '_ {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(parse_line_ref)
+}
@@ -0,0 +1,34 @@
+// Allocation counts for scanning a password file with borrowed entries. The counting allocator
+// is installed in this test binary only.
+
+use item_3_1::{entries, parse_line_ref};
+use test_support::alloc::{assert_allocations, CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const FIXTURE: &str = "\
+root:x:0:0:root:/root:/bin/bash
+daemon:x:1:1:daemon:/usr/sbin:/usr/sbin/nologin
+
+alice:x:1000:1000:Alice Liddell,,,:/home/alice:/bin/zsh
+";
+
+#[test]
+fn scanning_a_file_does_not_allocate() {
+    assert_allocations("parse_line_ref", 0, || {
+        parse_line_ref("root:x:0:0:root:/root:/bin/bash")
+    });
+    assert_allocations("entries", 0, || {
+        entries(FIXTURE).filter(|entry| entry.is_ok()).count()
+    });
+    // Too many or too few fields are counted, not collected.
+    assert_allocations("a line with too many fields", 0, || {
+        parse_line_ref("a:b:c:d:e:f:g:h")
+    });
+}
+
+#[test]
+fn only_a_bad_id_allocates_to_report_it() {
+    assert_allocations("a bad uid", 1, || parse_line_ref("alice:x:oops:1000:::"));
+}
@@ -0,0 +1,104 @@
+use item_1_3::passwd::parse_line;
+use item_1_3::{ParseError, PasswdEntry, UserId};
+use item_3_1::{entries, parse_line_ref, PasswdEntryRef};
+
+const FIXTURE: &str = "\
+root:x:0:0:root:/root:/bin/bash
+daemon:x:1:1:daemon:/usr/sbin:/usr/sbin/nologin
+
+alice:x:1000:1000:Alice Liddell,,,:/home/alice:/bin/zsh
+nobody::65534:65534:::
+";
+
+// Lines of every kind `parse_line` knows about, good and bad.
+const LINES: &[&str] = &[
+    "alice:x:1000:100:Alice Liddell,,,:/home/alice:/bin/zsh",
+    "nobody::65534:65534:::",
+    "",
+    "alice:x:1000:100",
+    "a:b:c:d:e:f:g:h",
+    "::::::::::",
+    ":x:1:1:::",
+    "alice:x:-1:100:::",
+    "alice:x:1000:gid:::",
+    "alice:x:1000:4294967296:::",
+];
+
+#[test]
+fn parses_a_line_without_copying() {
+    let line = "alice:x:1000:100:Alice Liddell,,,:/home/alice:/bin/zsh";
+    let entry = parse_line_ref(line).unwrap();
+    assert_eq!(
+        entry,
+        PasswdEntryRef {
+            name: "alice",
+            password: "x",
+            uid: UserId(1000),
+            gid: 100,
+            gecos: "Alice Liddell,,,",
+            home: "/home/alice",
+            shell: "/bin/zsh",
+        }
+    );
+    // Slices of the line itself.
+    assert!(std::ptr::eq(entry.name.as_ptr(), line.as_ptr()));
+    assert!(std::ptr::eq(
+        entry.shell.as_ptr(),
+        line[line.len() - 8..].as_ptr()
+    ));
+}
+
+#[test]
+fn agrees_with_the_owned_parser() {
+    for line in LINES {
+        let borrowed = parse_line_ref(line).map(PasswdEntry::from);
+        assert_eq!(borrowed, parse_line(line), "{line:?}");
+    }
+}
+
+#[test]
+fn reports_the_same_errors() {
+    assert_eq!(parse_line_ref("alice:x"), Err(ParseError::FieldCount(2)));
+    assert_eq!(
+        parse_line_ref("a:b:c:d:e:f:g:h"),
+        Err(ParseError::FieldCount(8))
+    );
+    assert_eq!(parse_line_ref(":x:1:1:::"), Err(ParseError::EmptyName));
+    assert_eq!(
+        parse_line_ref("alice:x:1000:gid:::"),
+        Err(ParseError::InvalidId {
+            field: "gid",
+            value: "gid".to_owned()
+        })
+    );
+}
+
+#[test]
+fn entries_yields_every_non_blank_line_in_order() {
+    let lines: Vec<&str> = FIXTURE.lines().filter(|line| !line.is_empty()).collect();
+    let parsed: Vec<_> = entries(FIXTURE).collect::<Result<_, _>>().unwrap();
+    assert_eq!(parsed.len(), lines.len());
+    for (entry, line) in parsed.iter().zip(&lines) {
+        assert_eq!(PasswdEntry::from(*entry), parse_line(line).unwrap());
+    }
+    let names: Vec<_> = parsed.iter().map(|entry| entry.name).collect();
+    assert_eq!(names, ["root", "daemon", "alice", "nobody"]);
+}
+
+#[test]
+fn entries_keeps_going_past_a_malformed_line() {
+    let content = "root:x:0:0:root:/root:/bin/sh\n  \nbroken\nalice:x:1000:1000:::\n";
+    let results: Vec<_> = entries(content).collect();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().map(|entry| entry.name), Ok("root"));
+    assert_eq!(results[1], Err(ParseError::FieldCount(1)));
+    assert_eq!(results[2].as_ref().map(|entry| entry.name), Ok("alice"));
+}
+
+#[test]
+fn the_owned_conversion_copies_every_field() {
+    for line in FIXTURE.lines().filter(|line| !line.is_empty()) {
+        let owned = PasswdEntry::from(parse_line_ref(line).unwrap());
+        assert_eq!(owned.to_line(), line);
+    }
+}
@@ -0,0 +1,34 @@
+// Item 3.4: Be wary of shared-state parallelism.
+//
+// Rust rules out data races at compile time: data can only be shared between threads if it is
+// `Sync`, and only moved to another thread if it is `Send`. What it can't rule out is a
+// design that holds a lock for too long, or takes two locks in different orders. `simulation`
+// keeps the shared state to one scheduler behind one `Mutex`, held only for as long as it
+// takes to change it.
+
+pub mod simulation;
+
+pub use simulation::{run_simulation, Completion, SimulationReport};
+
+use item_1_1::Job;
+
+pub const DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
+
+pub fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let jobs = (1..=8)
+        .map(|n| Job::new(n, format!("job number {n}")))
+        .collect();
+    let report = run_simulation(jobs, 3);
+    for completion in &report.completions {
+        println!(
+            "job {} finished on cpu {} with {}",
+            completion.job.0, completion.cpu, completion.output
+        );
+    }
+    // Which CPU got which job changes from run to run; the total doesn't.
+    for (cpu, count) in &report.per_cpu {
+        println!("cpu {cpu} finished {count}");
+    }
+    println!("{} jobs in all", report.completions.len());
+    Ok(())
+}
@@ -0,0 +1,138 @@
+//! Worker threads sharing one Item 1.1 scheduler. Each worker owns a CPU, and in a loop takes
+//! the lock, claims a pending job, lets go of the lock while it does the work, then takes it
+//! again to mark the job complete. Completions go back to the caller over a channel, so the
+//! report is built by one thread only.
+//!
+//! The scheduler is behind an `Arc<Mutex<_>>`: the `Arc` so that every thread can own a
+//! handle to it, the `Mutex` so that only one at a time can change it. Neither is optional.
+//! Moving a value into another thread needs it to be `Send`, and `Job` is, because a `String`
+//! is. A job whose payload is in an `Rc` isn't, since two threads could update its reference
+//! count at once, and the compiler won't allow it to be spawned:
+//!
+//! ```compile_fail,E0277
+//! use item_1_1::JobId;
+//! use std::rc::Rc;
+//!
+//! struct Job {
+//!     id: JobId,
+//!     payload: Rc<String>,
+//! }
+//!
+//! let job = Job { id: JobId(1), payload: Rc::new("build".to_owned()) };
+//! std::thread::spawn(move || println!("job {} is {}", job.id.0, job.payload));
+//! ```
+//!
+//! With an `Arc`, whose count is updated atomically, the same thing compiles:
+//!
+//! ```
+//! use item_1_1::JobId;
+//! use std::sync::Arc;
+//!
+//! struct Job {
+//!     id: JobId,
+//!     payload: Arc<String>,
+//! }
+//!
+//! let job = Job { id: JobId(1), payload: Arc::new("build".to_owned()) };
+//! std::thread::spawn(move || println!("job {} is {}", job.id.0, job.payload))
+//!     .join()
+//!     .unwrap();
+//! ```
+
+use item_1_1::{CpuId, Job, JobId, SchedulerState};
+use std::collections::BTreeMap;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+
+/// One finished job.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Completion {
+    pub job: JobId,
+    pub cpu: CpuId,
+    /// What the work came to; see [`work`].
+    pub output: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SimulationReport {
+    /// In the order the jobs finished.
+    pub completions: Vec<Completion>,
+    /// How many jobs each CPU finished. A CPU whose worker never got a job isn't listed.
+    pub per_cpu: BTreeMap<CpuId, usize>,
+}
+
+/// The work a job stands for, which is to add up the bytes of its payload.
+pub fn work(job: &Job) -> u64 {
+    job.payload.bytes().map(u64::from).sum()
+}
+
+/// Run every job in `jobs` on `workers` threads, one CPU each, and report what finished where.
+///
+/// Panics if `workers` is zero, or if two jobs have the same id.
+pub fn run_simulation(jobs: Vec<Job>, workers: usize) -> SimulationReport {
+    assert!(workers > 0, "a simulation needs at least one worker");
+    let mut scheduler = SchedulerState::new();
+    for job in jobs {
+        scheduler
+            .submit(job)
+            .unwrap_or_else(|e| panic!("can't simulate: {e}"));
+    }
+    let scheduler = Arc::new(Mutex::new(scheduler));
+
+    let (done, completions) = mpsc::channel();
+    let handles: Vec<_> = (0..workers)
+        .map(|n| {
+            let cpu = CpuId(n.try_into().expect("too many workers"));
+            let scheduler = Arc::clone(&scheduler);
+            let done = done.clone();
+            thread::spawn(move || worker(&scheduler, cpu, &done))
+        })
+        .collect();
+    // Without this, the channel would stay open, and the loop below never finish, however many
+    // workers had stopped.
+    drop(done);
+
+    let mut report = SimulationReport::default();
+    for completion in completions {
+        *report.per_cpu.entry(completion.cpu).or_default() += 1;
+        report.completions.push(completion);
+    }
+    for handle in handles {
+        handle.join().expect("a worker panicked");
+    }
+    report
+}
+
+fn worker(scheduler: &Mutex<SchedulerState>, cpu: CpuId, done: &mpsc::Sender<Completion>) {
+    loop {
+        // The guard is dropped at the end of this block, so nothing waits on the lock while
+        // the work is being done.
+        let job = {
+            let mut scheduler = scheduler.lock().expect("scheduler lock poisoned");
+            let Some(id) = scheduler.pending().map(|job| job.id).min() else {
+                return;
+            };
+            scheduler.assign(id, cpu).expect("the job was pending");
+            scheduler
+                .running_on(cpu)
+                .last()
+                .cloned()
+                .expect("just assigned")
+        };
+        let output = work(&job);
+        let job = scheduler
+            .lock()
+            .expect("scheduler lock poisoned")
+            .complete(cpu, job.id)
+            .expect("the job was running here");
+        let completion = Completion {
+            job: job.id,
+            cpu,
+            output,
+        };
+        if done.send(completion).is_err() {
+            // Nobody is listening any more.
+            return;
+        }
+    }
+}
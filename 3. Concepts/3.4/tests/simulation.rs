@@ -0,0 +1,72 @@
+use item_1_1::{Job, JobId};
+use item_3_4::run_simulation;
+use item_3_4::simulation::work;
+use std::collections::BTreeSet;
+
+fn jobs(count: u32) -> Vec<Job> {
+    (1..=count)
+        .map(|n| Job::new(n, "x".repeat(n as usize)))
+        .collect()
+}
+
+#[test]
+fn every_job_completes_exactly_once_whatever_the_worker_count() {
+    for workers in [1, 2, 3, 8, 50] {
+        let report = run_simulation(jobs(40), workers);
+        assert_eq!(report.completions.len(), 40, "{workers} workers");
+        let ids: BTreeSet<JobId> = report.completions.iter().map(|c| c.job).collect();
+        assert_eq!(ids, (1..=40).map(JobId).collect(), "{workers} workers");
+    }
+}
+
+#[test]
+fn per_cpu_counts_add_up_to_the_total() {
+    for workers in [1, 4, 16] {
+        let report = run_simulation(jobs(25), workers);
+        assert_eq!(report.per_cpu.values().sum::<usize>(), 25);
+        assert!(report.per_cpu.len() <= workers);
+        for (cpu, count) in &report.per_cpu {
+            assert!((cpu.0 as usize) < workers);
+            let on_cpu = report.completions.iter().filter(|c| c.cpu == *cpu).count();
+            assert_eq!(on_cpu, *count);
+        }
+    }
+}
+
+#[test]
+fn one_worker_does_everything_on_cpu_0_in_id_order() {
+    let report = run_simulation(jobs(5), 1);
+    let order: Vec<u32> = report.completions.iter().map(|c| c.job.0).collect();
+    assert_eq!(order, [1, 2, 3, 4, 5]);
+    assert_eq!(report.per_cpu.len(), 1);
+    assert_eq!(report.per_cpu.values().next(), Some(&5));
+}
+
+#[test]
+fn outputs_are_the_work_done() {
+    let report = run_simulation(jobs(10), 3);
+    for completion in &report.completions {
+        let job = Job::new(completion.job.0, "x".repeat(completion.job.0 as usize));
+        assert_eq!(completion.output, work(&job));
+    }
+    assert_eq!(work(&Job::new(1, "ab")), 97 + 98);
+}
+
+#[test]
+fn no_jobs_is_an_empty_report() {
+    let report = run_simulation(Vec::new(), 4);
+    assert!(report.completions.is_empty());
+    assert!(report.per_cpu.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "at least one worker")]
+fn no_workers_panics() {
+    run_simulation(jobs(1), 0);
+}
+
+#[test]
+#[should_panic(expected = "job 2 has already been submitted")]
+fn duplicate_ids_panic() {
+    run_simulation(vec![Job::new(2, "a"), Job::new(2, "b")], 2);
+}
@@ -0,0 +1,130 @@
+//! `code_enum!`, for C-like enums whose variants stand for numeric codes, each with a phrase
+//! that describes it: HTTP status codes, SMTP reply codes and the like.
+//!
+//! Written by hand, every such enum needs the same four things: a `#[repr(u16)]` with a
+//! discriminant per variant, a list of every variant, a `match` from variant to phrase, and a
+//! checked `TryFrom<u16>` with an error type for numbers that aren't a code. Each of those
+//! repeats the variants, and nothing but care keeps the repetitions in step. The macro takes
+//! each variant, its code and its phrase once, and writes all four:
+//!
+//! ```
+//! item_5_2::code_enum! {
+//!     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+//!     pub enum Signal {
+//!         Hangup = 1 => "Hangup",
+//!         Interrupt = 2 => "Interrupt",
+//!         Kill = 9 => "Killed",
+//!     }
+//!
+//!     pub struct UnknownSignal => "unknown signal";
+//! }
+//!
+//! assert_eq!(Signal::Kill.code(), 9);
+//! assert_eq!(Signal::Kill.to_string(), "Killed");
+//! assert_eq!(Signal::try_from(2), Ok(Signal::Interrupt));
+//! assert_eq!(Signal::try_from(3), Err(UnknownSignal(3)));
+//! assert_eq!(UnknownSignal(3).to_string(), "unknown signal 3");
+//! assert_eq!(Signal::ALL, [Signal::Hangup, Signal::Interrupt, Signal::Kill]);
+//! ```
+//!
+//! The enum gets:
+//!
+//! - `ALL`, an array of every variant in the order they were declared;
+//! - `code(&self) -> u16` and `reason(&self) -> &'static str`;
+//! - `Display`, which writes the reason;
+//! - `TryFrom<u16>`, failing with the error struct, which is declared as a `pub u16` tuple
+//!   struct with `Display` (the message, then the number) and `Error`.
+//!
+//! Attributes and doc comments on the enum, its variants and the error struct are passed
+//! through. The macro derives nothing itself, but `TryFrom`'s error needs `Debug`, and the
+//! error struct always derives `Debug, Clone, Copy, PartialEq, Eq`. The enum doesn't have to
+//! be `Copy`: `code` and `reason` only borrow it, and `code` is a `match` like `reason`
+//! rather than an `as` cast, which would need a copy to cast from.
+//!
+//! Two variants with the same code are a compile error, as they would be in the hand-written
+//! enum, since both the discriminants and the `match` patterns would clash:
+//!
+//! ```compile_fail,E0081
+//! item_5_2::code_enum! {
+//!     #[derive(Debug)]
+//!     pub enum Twice {
+//!         First = 1 => "first",
+//!         Again = 1 => "again",
+//!     }
+//!
+//!     pub struct UnknownTwice => "unknown";
+//! }
+//! ```
+
+/// Declare a `#[repr(u16)]` enum of codes with phrases, and the error for an unknown code;
+/// see the [module documentation](crate::code_enum) for what is generated.
+#[macro_export]
+macro_rules! code_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $code:literal => $reason:literal
+            ),+ $(,)?
+        }
+
+        $(#[$error_meta:meta])*
+        $error_vis:vis struct $error:ident => $message:literal;
+    ) => {
+        $(#[$meta])*
+        #[repr(u16)]
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant = $code,
+            )+
+        }
+
+        impl $name {
+            /// Every variant, in the order they were declared.
+            pub const ALL: [$name; [$(stringify!($variant)),+].len()] = [$($name::$variant),+];
+
+            pub fn code(&self) -> u16 {
+                match self {
+                    $($name::$variant => $code,)+
+                }
+            }
+
+            pub fn reason(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $reason,)+
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.reason())
+            }
+        }
+
+        $(#[$error_meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        $error_vis struct $error(pub u16);
+
+        impl ::std::fmt::Display for $error {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "{} {}", $message, self.0)
+            }
+        }
+
+        impl ::std::error::Error for $error {}
+
+        impl ::std::convert::TryFrom<u16> for $name {
+            type Error = $error;
+
+            fn try_from(code: u16) -> ::std::result::Result<Self, Self::Error> {
+                match code {
+                    $($code => Ok($name::$variant),)+
+                    _ => Err($error(code)),
+                }
+            }
+        }
+    };
+}
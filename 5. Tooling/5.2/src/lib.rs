@@ -0,0 +1,30 @@
+// Item 5.2: Use macros judiciously.
+//
+// A declarative macro (`macro_rules!`) earns its keep where the same code would otherwise be
+// written out again and again, and a function or a generic can't do it because what repeats is
+// a declaration: an enum, its impls, a list of its variants. `code_enum!` is one of those; see
+// the `code_enum` module. Item 1.1's `HttpResultCode` and the `smtp` module's `SmtpReplyCode`
+// are both declared with it.
+//
+// The price is paid by the reader: the code that runs isn't the code on the page, and the
+// macro's own input syntax is one more thing to learn. So the macro should do one thing, look
+// like the Rust it expands to, and document what it generates.
+
+pub mod code_enum;
+pub mod smtp;
+
+pub use smtp::{SmtpReplyCode, UnknownReplyCode};
+
+pub const DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
+
+pub fn run() -> Result<(), Box<dyn std::error::Error>> {
+    for code in SmtpReplyCode::ALL {
+        println!("{} {code}", code.code());
+    }
+    let reply = SmtpReplyCode::try_from(450)?;
+    println!("450 is transient: {}", reply.is_transient());
+    if let Err(e) = SmtpReplyCode::try_from(299) {
+        println!("{e}");
+    }
+    Ok(())
+}
@@ -0,0 +1,33 @@
+// SMTP reply codes from RFC 5321, the second user of `code_enum!` after Item 1.1's
+// `HttpResultCode`. The first digit says how things went, as with HTTP: 2 done, 3 carry on,
+// 4 failed for now, 5 failed for good.
+
+crate::code_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum SmtpReplyCode {
+        ServiceReady = 220 => "Service ready",
+        Closing = 221 => "Service closing transmission channel",
+        Completed = 250 => "Requested mail action okay, completed",
+        StartMailInput = 354 => "Start mail input",
+        ServiceNotAvailable = 421 => "Service not available",
+        MailboxBusy = 450 => "Requested mail action not taken: mailbox unavailable",
+        SyntaxError = 500 => "Syntax error, command unrecognized",
+        BadSequence = 503 => "Bad sequence of commands",
+        MailboxUnavailable = 550 => "Requested action not taken: mailbox unavailable",
+    }
+
+    /// A number that isn't one of the reply codes `SmtpReplyCode` covers.
+    pub struct UnknownReplyCode => "unknown SMTP reply code";
+}
+
+impl SmtpReplyCode {
+    /// 4xx: worth trying again later.
+    pub fn is_transient(self) -> bool {
+        (400..500).contains(&self.code())
+    }
+
+    /// 5xx: don't send the same thing again.
+    pub fn is_permanent(self) -> bool {
+        (500..600).contains(&self.code())
+    }
+}
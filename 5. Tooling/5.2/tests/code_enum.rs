@@ -0,0 +1,110 @@
+use item_5_2::{SmtpReplyCode, UnknownReplyCode};
+
+#[test]
+fn every_variant_round_trips_through_its_code() {
+    for variant in SmtpReplyCode::ALL {
+        assert_eq!(SmtpReplyCode::try_from(variant.code()), Ok(variant));
+        assert_eq!(variant.code(), variant as u16);
+        assert_eq!(variant.to_string(), variant.reason());
+    }
+}
+
+#[test]
+fn all_is_in_declared_order() {
+    assert_eq!(
+        SmtpReplyCode::ALL,
+        [
+            SmtpReplyCode::ServiceReady,
+            SmtpReplyCode::Closing,
+            SmtpReplyCode::Completed,
+            SmtpReplyCode::StartMailInput,
+            SmtpReplyCode::ServiceNotAvailable,
+            SmtpReplyCode::MailboxBusy,
+            SmtpReplyCode::SyntaxError,
+            SmtpReplyCode::BadSequence,
+            SmtpReplyCode::MailboxUnavailable,
+        ]
+    );
+    let codes: Vec<u16> = SmtpReplyCode::ALL.iter().map(|c| c.code()).collect();
+    assert_eq!(codes, [220, 221, 250, 354, 421, 450, 500, 503, 550]);
+}
+
+#[test]
+fn unknown_codes_are_errors() {
+    for code in [0, 200, 251, 299, 422, 551, u16::MAX] {
+        assert_eq!(SmtpReplyCode::try_from(code), Err(UnknownReplyCode(code)));
+    }
+    assert_eq!(
+        UnknownReplyCode(299).to_string(),
+        "unknown SMTP reply code 299"
+    );
+    let error: Box<dyn std::error::Error> = Box::new(UnknownReplyCode(1));
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn display_is_the_reason() {
+    assert_eq!(
+        SmtpReplyCode::Completed.to_string(),
+        "Requested mail action okay, completed"
+    );
+    assert_eq!(
+        SmtpReplyCode::StartMailInput.to_string(),
+        "Start mail input"
+    );
+}
+
+#[test]
+fn categories() {
+    assert!(SmtpReplyCode::MailboxBusy.is_transient());
+    assert!(!SmtpReplyCode::MailboxBusy.is_permanent());
+    assert!(SmtpReplyCode::MailboxUnavailable.is_permanent());
+    assert!(!SmtpReplyCode::Completed.is_transient() && !SmtpReplyCode::Completed.is_permanent());
+}
+
+// A second enum declared here, outside the crate, to check the macro only needs what it names
+// with full paths.
+mod outside {
+    item_5_2::code_enum! {
+        /// Docs and attributes are passed through.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub(crate) enum Tiny {
+            /// The only one with a doc comment.
+            One = 1 => "one",
+            Two = 2 => "two",
+        }
+
+        pub(crate) struct UnknownTiny => "not tiny:";
+    }
+}
+
+#[test]
+fn declared_in_another_crate() {
+    use outside::{Tiny, UnknownTiny};
+    assert_eq!(Tiny::ALL, [Tiny::One, Tiny::Two]);
+    assert_eq!(Tiny::try_from(2), Ok(Tiny::Two));
+    assert_eq!(Tiny::try_from(3), Err(UnknownTiny(3)));
+    assert_eq!(UnknownTiny(3).to_string(), "not tiny: 3");
+    assert_eq!(Tiny::One.to_string(), "one");
+}
+
+// Nothing but `Debug`, which `TryFrom` needs.
+item_5_2::code_enum! {
+    #[derive(Debug)]
+    enum Bare {
+        Low = 10 => "low",
+        High = 20 => "high",
+    }
+
+    struct UnknownBare => "unknown";
+}
+
+#[test]
+fn the_enum_need_not_be_copy() {
+    let high = Bare::High;
+    assert_eq!(high.code(), 20);
+    assert_eq!(high.reason(), "high");
+    assert_eq!(high.to_string(), "high");
+    assert!(matches!(Bare::try_from(10), Ok(Bare::Low)));
+    assert_eq!(Bare::ALL.map(|b| b.code()), [10, 20]);
+}
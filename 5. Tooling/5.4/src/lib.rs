@@ -0,0 +1,29 @@
+// Item 5.4: Write more than unit tests.
+//
+// A unit test checks the examples its author thought of. A property test states something that
+// should be true of every input, such as "dividing then multiplying gets back where it
+// started", and checks it against hundreds of generated ones, including the awkward ones
+// nobody would have written down. The properties for Item 1.2's `div` and `Shape` and Item
+// 1.3's password file parser are in this crate's `tests/properties.rs`.
+//
+// Most of the work is in the generators: a property about triangles needs triangles, not three
+// random lengths. The `strategies` module has them, for any test to reuse.
+//
+// When a property fails, proptest shrinks the input it failed on to as small a one as still
+// fails, and reports that. `shrinking` has a property that is false on purpose, to show what
+// that looks like.
+
+pub mod shrinking;
+pub mod strategies;
+
+pub const DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
+
+pub fn run() -> Result<(), Box<dyn std::error::Error>> {
+    println!("claim: every shape's area is less than its perimeter");
+    let counterexample =
+        shrinking::area_below_perimeter().ok_or("the claim held for every shape tried")?;
+    println!("first counterexample: {}", counterexample.found);
+    println!("shrunk to:            {}", counterexample.shrunk);
+    println!("because {}", counterexample.reason);
+    Ok(())
+}
@@ -0,0 +1,70 @@
+//! A property that doesn't hold, checked with a fixed seed so that it fails the same way every
+//! time, to show what proptest reports.
+//!
+//! The property is that a shape's area is less than its perimeter, which is true of small
+//! shapes and false of big ones. The first shape proptest finds that breaks it is usually
+//! much bigger than it needs to be, so proptest shrinks it: it keeps trying simpler or smaller
+//! versions for as long as they still fail, and reports the last one. Here that turns a
+//! triangle into a rectangle, which comes first in `strategies::shape`, with one side pulled
+//! down to a few units. Shrinking doesn't promise the smallest input that fails, only one that
+//! no single simplification makes pass.
+
+use crate::strategies;
+use item_1_2::shapes::Shape;
+use proptest::test_runner::{Config, RngAlgorithm, TestCaseError, TestError, TestRng, TestRunner};
+use std::cell::RefCell;
+
+/// The seed used by `area_below_perimeter`, so that its counterexample is always the same.
+pub const SEED: [u8; 32] = *b"effective rust: more than units!";
+
+/// Where a property failed: the first input found to break it, and what that shrank to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Counterexample {
+    pub found: Shape,
+    pub shrunk: Shape,
+    /// Why the shrunk input fails.
+    pub reason: String,
+}
+
+/// The false claim, as a property.
+pub fn area_is_below_perimeter(shape: &Shape) -> Result<(), TestCaseError> {
+    let (area, perimeter) = (shape.area(), shape.perimeter());
+    if area < perimeter {
+        Ok(())
+    } else {
+        Err(TestCaseError::fail(format!(
+            "area {area} is not below perimeter {perimeter}"
+        )))
+    }
+}
+
+/// Check `area_is_below_perimeter` against generated shapes, from `SEED`. Returns `None` only
+/// if every shape passes, which it won't.
+pub fn area_below_perimeter() -> Option<Counterexample> {
+    let config = Config {
+        // Nothing to save the failure for: it isn't a test, and is meant to fail.
+        failure_persistence: None,
+        ..Config::default()
+    };
+    let rng = TestRng::from_seed(RngAlgorithm::ChaCha, &SEED);
+    let mut runner = TestRunner::new_with_rng(config, rng);
+
+    // The runner only reports the shrunk input, so note the first failure on the way.
+    let found = RefCell::new(None);
+    let result = runner.run(&strategies::shape(), |shape| {
+        let outcome = area_is_below_perimeter(&shape);
+        if outcome.is_err() {
+            found.borrow_mut().get_or_insert(shape);
+        }
+        outcome
+    });
+    match result {
+        Ok(()) => None,
+        Err(TestError::Fail(reason, shrunk)) => Some(Counterexample {
+            found: found.into_inner().expect("a shape failed"),
+            shrunk,
+            reason: reason.to_string(),
+        }),
+        Err(TestError::Abort(reason)) => panic!("proptest gave up: {reason}"),
+    }
+}
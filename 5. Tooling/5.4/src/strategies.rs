@@ -0,0 +1,74 @@
+//! Generators for the types from other items, for property tests to draw their inputs from.
+//!
+//! Each one only produces values the type's own constructors accept, so a property can assume
+//! it has a valid shape or entry rather than filtering out the ones it can't use.
+
+use item_1_2::shapes::Shape;
+use item_1_3::passwd::{PasswdEntry, UserId};
+use proptest::prelude::*;
+
+/// Lengths from a hundredth to a hundred. Wide enough to mix tiny shapes with large ones, and
+/// narrow enough that areas never get near overflowing or underflowing.
+pub fn length() -> impl Strategy<Value = f64> {
+    0.01..100.0
+}
+
+pub fn rectangle() -> impl Strategy<Value = Shape> {
+    (length(), length()).prop_map(|(width, height)| Shape::Rectangle { width, height })
+}
+
+pub fn circle() -> impl Strategy<Value = Shape> {
+    length().prop_map(|radius| Shape::Circle { radius })
+}
+
+/// Triangles that satisfy the triangle inequality. Given sides `a` and `b`, the third must be
+/// longer than their difference and shorter than their sum, so `c` is picked from that range,
+/// away from either end: a triangle that is nearly a line has almost no area, and Heron's
+/// formula loses most of its precision computing it.
+pub fn triangle() -> impl Strategy<Value = Shape> {
+    (length(), length(), 0.01..0.99).prop_filter_map(
+        "sides that don't make a triangle",
+        |(a, b, t): (f64, f64, f64)| {
+            let (shortest, longest) = ((a - b).abs(), a + b);
+            let c = shortest + t * (longest - shortest);
+            // Rounding could still, in principle, put `c` on the wrong side of a limit.
+            Shape::try_new_triangle(a, b, c).ok()
+        },
+    )
+}
+
+/// Any of the three kinds of shape. When a test fails, proptest shrinks toward the first
+/// alternative, so a counterexample is a rectangle if a rectangle will do.
+pub fn shape() -> impl Strategy<Value = Shape> {
+    prop_oneof![rectangle(), circle(), triangle()]
+}
+
+/// Text for a field of a password file line: anything but a `:`, which would split it in two,
+/// or a line break, which would end the line.
+pub fn field() -> impl Strategy<Value = String> {
+    "[^:\r\n]{0,12}"
+}
+
+/// Entries that `to_line` writes as a line `parse_line` reads back.
+pub fn passwd_entry() -> impl Strategy<Value = PasswdEntry> {
+    (
+        "[^:\r\n]{1,12}",
+        field(),
+        any::<u32>(),
+        any::<u32>(),
+        field(),
+        field(),
+        field(),
+    )
+        .prop_map(
+            |(name, password, uid, gid, gecos, home, shell)| PasswdEntry {
+                name,
+                password,
+                uid: UserId(uid),
+                gid,
+                gecos,
+                home,
+                shell,
+            },
+        )
+}
@@ -0,0 +1,55 @@
+use item_1_2::div;
+use item_1_2::shapes::Shape;
+use item_1_3::passwd::parse_line;
+use item_5_4::strategies::{passwd_entry, shape};
+use proptest::prelude::*;
+
+// Two roundings, one for each operation, each off by at most half a unit in the last place.
+fn close(actual: f64, expected: f64) -> bool {
+    (actual - expected).abs() <= 4.0 * f64::EPSILON * expected.abs()
+}
+
+fn divisor() -> impl Strategy<Value = f64> {
+    prop_oneof![-1e6..-1e-6, 1e-6..1e6]
+}
+
+proptest! {
+    #[test]
+    fn division_undoes_multiplication(x in -1e6..1e6, y in divisor()) {
+        let quotient = div(x, y).unwrap();
+        prop_assert!(close(quotient * y, x), "{x} / {y} = {quotient}");
+    }
+
+    #[test]
+    fn area_is_never_negative(shape in shape()) {
+        prop_assert!(shape.area() >= 0.0, "{shape} has area {}", shape.area());
+    }
+
+    #[test]
+    fn area_scales_with_the_square(shape in shape(), factor in 0.1..10.0) {
+        let scaled: Shape = &shape * factor;
+        let expected = shape.area() * factor * factor;
+        prop_assert!(
+            (scaled.area() - expected).abs() <= 1e-9 * expected,
+            "{shape} scaled by {factor} has area {}, not {expected}",
+            scaled.area()
+        );
+    }
+
+    #[test]
+    fn parse_line_never_panics(bytes in proptest::collection::vec(any::<u8>(), 0..64)) {
+        let _ = parse_line(&String::from_utf8_lossy(&bytes));
+    }
+
+    // Mostly colons and digits, which random bytes rarely are, so that more lines get past the
+    // field count and reach the ids.
+    #[test]
+    fn parse_line_never_panics_on_nearly_valid_lines(line in "[a-z0-9:-]{0,40}") {
+        let _ = parse_line(&line);
+    }
+
+    #[test]
+    fn entries_round_trip_through_a_line(entry in passwd_entry()) {
+        prop_assert_eq!(parse_line(&entry.to_line()), Ok(entry));
+    }
+}
@@ -0,0 +1,16 @@
+use item_5_4::shrinking::{area_below_perimeter, area_is_below_perimeter};
+
+#[test]
+fn the_false_claim_fails_the_same_way_every_time() {
+    let first = area_below_perimeter().expect("big shapes break the claim");
+    assert_eq!(area_below_perimeter(), Some(first));
+}
+
+#[test]
+fn the_shrunk_counterexample_still_fails_and_is_no_bigger() {
+    let counterexample = area_below_perimeter().unwrap();
+    assert!(area_is_below_perimeter(&counterexample.found).is_err());
+    assert!(area_is_below_perimeter(&counterexample.shrunk).is_err());
+    assert!(counterexample.shrunk.area() <= counterexample.found.area());
+    assert!(counterexample.reason.starts_with("area "));
+}
@@ -0,0 +1,33 @@
+/* The C side of item-6-2, for linking against the shared library it builds
+ * (libitem_6_2.so, libitem_6_2.dylib or item_6_2.dll, under target/). Keep in step with
+ * src/c_shape.rs and src/exports.rs. */
+
+#ifndef ITEM_6_2_SHAPES_H
+#define ITEM_6_2_SHAPES_H
+
+#include <stdint.h>
+
+#define SHAPE_RECTANGLE 0
+#define SHAPE_CIRCLE 1
+#define SHAPE_TRIANGLE 2
+
+/* Rectangle: a = width, b = height. Circle: a = radius. Triangle: a, b, c = sides. */
+typedef struct {
+    uint8_t kind;
+    double a;
+    double b;
+    double c;
+} CShape;
+
+/* NaN if shape is NULL or invalid. */
+double shape_area(const CShape *shape);
+
+#define DIV_OK 0
+#define DIV_BY_ZERO 1
+#define DIV_NON_FINITE 2
+#define DIV_BAD_POINTER (-1)
+
+/* On DIV_OK, *out holds x / y; otherwise it is left alone. */
+int32_t checked_div(double x, double y, double *out);
+
+#endif
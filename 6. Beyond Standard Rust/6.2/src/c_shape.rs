@@ -0,0 +1,97 @@
+// Item 1.2's `Shape` in a form C can declare: a `#[repr(C)]` struct, laid out field by field
+// as a C compiler would lay out the same struct, with a tag saying which shape it is. A Rust
+// enum with fields has no layout C can rely on, so it doesn't cross the boundary itself.
+//
+// Going from `CShape` to `Shape` is checked, since anything could be in the struct: a tag
+// that isn't one of ours, or lengths that no `Shape` can have. Going back always works.
+
+use item_1_2::{Shape, ShapeError};
+use std::fmt;
+
+pub const SHAPE_RECTANGLE: u8 = 0;
+pub const SHAPE_CIRCLE: u8 = 1;
+pub const SHAPE_TRIANGLE: u8 = 2;
+
+/// A shape as C sees it. `kind` is one of the `SHAPE_` constants, and `a`, `b` and `c` are:
+///
+/// - for a rectangle, the width and height, with `c` unused;
+/// - for a circle, the radius, with `b` and `c` unused;
+/// - for a triangle, the lengths of the three sides.
+///
+/// Unused fields are zero in a `CShape` made from a `Shape`, and ignored when converting back.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[repr(C)]
+pub struct CShape {
+    pub kind: u8,
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CShapeError {
+    UnknownKind(u8),
+    Invalid(ShapeError),
+}
+
+impl fmt::Display for CShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CShapeError::UnknownKind(kind) => write!(f, "unknown shape kind {kind}"),
+            CShapeError::Invalid(e) => write!(f, "invalid shape: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for CShapeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CShapeError::UnknownKind(_) => None,
+            CShapeError::Invalid(e) => Some(e),
+        }
+    }
+}
+
+impl From<ShapeError> for CShapeError {
+    fn from(e: ShapeError) -> Self {
+        CShapeError::Invalid(e)
+    }
+}
+
+impl TryFrom<&CShape> for Shape {
+    type Error = CShapeError;
+
+    fn try_from(shape: &CShape) -> Result<Self, Self::Error> {
+        let CShape { kind, a, b, c } = *shape;
+        Ok(match kind {
+            SHAPE_RECTANGLE => Shape::try_new_rectangle(a, b)?,
+            SHAPE_CIRCLE => Shape::try_new_circle(a)?,
+            SHAPE_TRIANGLE => Shape::try_new_triangle(a, b, c)?,
+            _ => return Err(CShapeError::UnknownKind(kind)),
+        })
+    }
+}
+
+impl From<&Shape> for CShape {
+    fn from(shape: &Shape) -> Self {
+        match *shape {
+            Shape::Rectangle { width, height } => CShape {
+                kind: SHAPE_RECTANGLE,
+                a: width,
+                b: height,
+                c: 0.0,
+            },
+            Shape::Circle { radius } => CShape {
+                kind: SHAPE_CIRCLE,
+                a: radius,
+                ..CShape::default()
+            },
+            Shape::Triangle { a, b, c } => CShape {
+                kind: SHAPE_TRIANGLE,
+                a,
+                b,
+                c,
+            },
+        }
+    }
+}
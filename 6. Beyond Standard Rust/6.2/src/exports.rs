@@ -0,0 +1,63 @@
+// The functions a C program calls, declared in `include/shapes.h`. Each is `extern "C"`, so it
+// uses the C calling convention, and `#[no_mangle]`, so the linker finds it under its own
+// name.
+//
+// Nothing a C caller passes can be trusted to be what the Rust types say, so each function
+// checks what it can (null and misaligned pointers, tags, lengths) and reports failure in a
+// way C can see: a NaN, or a status code. A panic must not unwind out into C either; none of
+// the code here can panic, and if it ever did, `extern "C"` aborts rather than unwind.
+
+use crate::c_shape::CShape;
+use item_1_2::{div, DivError, Shape};
+
+/// `checked_div` succeeded, and the quotient was written to `out`.
+pub const DIV_OK: i32 = 0;
+/// See `DivError::DivisionByZero`.
+pub const DIV_BY_ZERO: i32 = 1;
+/// See `DivError::NonFinite`.
+pub const DIV_NON_FINITE: i32 = 2;
+/// `out` was null or misaligned, so there was nowhere to put the quotient.
+pub const DIV_BAD_POINTER: i32 = -1;
+
+/// The area of `*shape`, or NaN if `shape` is null or misaligned, or doesn't describe a valid
+/// shape.
+///
+/// # Safety
+///
+/// `shape` must be null, or point to a `CShape` that can be read for the duration of the call.
+#[no_mangle]
+pub unsafe extern "C" fn shape_area(shape: *const CShape) -> f64 {
+    if !shape.is_aligned() {
+        return f64::NAN;
+    }
+    // SAFETY: aligned, and the caller promises that a non-null pointer can be read.
+    let Some(shape) = (unsafe { shape.as_ref() }) else {
+        return f64::NAN;
+    };
+    Shape::try_from(shape).map_or(f64::NAN, |shape| shape.area())
+}
+
+/// Divide `x` by `y`, writing the quotient to `*out` and returning `DIV_OK`, or returning one
+/// of the other `DIV_` codes and leaving `*out` alone.
+///
+/// # Safety
+///
+/// `out` must be null, or point to an `f64` that can be written for the duration of the call.
+#[no_mangle]
+pub unsafe extern "C" fn checked_div(x: f64, y: f64, out: *mut f64) -> i32 {
+    if !out.is_aligned() {
+        return DIV_BAD_POINTER;
+    }
+    // SAFETY: aligned, and the caller promises that a non-null pointer can be written.
+    let Some(out) = (unsafe { out.as_mut() }) else {
+        return DIV_BAD_POINTER;
+    };
+    match div(x, y) {
+        Ok(quotient) => {
+            *out = quotient;
+            DIV_OK
+        }
+        Err(DivError::DivisionByZero) => DIV_BY_ZERO,
+        Err(DivError::NonFinite) => DIV_NON_FINITE,
+    }
+}
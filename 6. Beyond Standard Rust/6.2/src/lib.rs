@@ -0,0 +1,47 @@
+// Item 6.2: Control what crosses FFI boundaries.
+//
+// Item 1.2's `Shape::area` and `div`, made callable from C. Only what C understands crosses:
+// `#[repr(C)]` structs, numbers and pointers, never an enum with fields, a `Result` or a
+// reference. `c_shape` translates between `Shape` and its C form, and `exports` holds the
+// `extern "C"` functions themselves, which `include/shapes.h` declares for C.
+//
+// On the Rust side, the boundary is where safety stops being checked by the compiler: a raw
+// pointer from C may be null, dangling or misaligned, and the functions that take them are
+// `unsafe` to call, with the contract spelled out.
+
+pub mod c_shape;
+pub mod exports;
+
+pub use c_shape::{CShape, CShapeError};
+
+use item_1_2::Shape;
+
+pub const DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
+
+pub fn run() -> Result<(), Box<dyn std::error::Error>> {
+    // What a C caller would do, with the pointers made on this side.
+    let circle = CShape::from(&Shape::try_new_circle(1.0)?);
+    // SAFETY: a pointer to a live local.
+    let area = unsafe { exports::shape_area(&circle) };
+    println!("{circle:?} has area {area}");
+    let unknown = CShape { kind: 7, ..circle };
+    // SAFETY: as above, and null is allowed.
+    let (bad, null) = unsafe {
+        (
+            exports::shape_area(&unknown),
+            exports::shape_area(std::ptr::null()),
+        )
+    };
+    println!("an unknown kind gives {bad}, and a null pointer {null}");
+    if let Err(e) = Shape::try_from(&unknown) {
+        println!("{e}");
+    }
+
+    let mut quotient = 0.0;
+    for (x, y) in [(1.0, 4.0), (1.0, 0.0)] {
+        // SAFETY: a pointer to a live local.
+        let status = unsafe { exports::checked_div(x, y, &mut quotient) };
+        println!("checked_div({x}, {y}) = {status}, quotient {quotient}");
+    }
+    Ok(())
+}
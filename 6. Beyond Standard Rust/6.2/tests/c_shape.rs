@@ -0,0 +1,98 @@
+use item_1_2::{Shape, ShapeError};
+use item_6_2::c_shape::{SHAPE_CIRCLE, SHAPE_RECTANGLE, SHAPE_TRIANGLE};
+use item_6_2::{CShape, CShapeError};
+
+fn shapes() -> Vec<Shape> {
+    vec![
+        Shape::try_new_rectangle(3.0, 4.0).unwrap(),
+        Shape::try_new_circle(0.5).unwrap(),
+        Shape::try_new_triangle(3.0, 4.0, 5.0).unwrap(),
+    ]
+}
+
+#[test]
+fn shapes_round_trip() {
+    for shape in shapes() {
+        let c_shape = CShape::from(&shape);
+        assert_eq!(Shape::try_from(&c_shape), Ok(shape));
+    }
+}
+
+#[test]
+fn unused_fields_are_zero() {
+    let circle = CShape::from(&Shape::try_new_circle(2.0).unwrap());
+    assert_eq!(
+        circle,
+        CShape {
+            kind: SHAPE_CIRCLE,
+            a: 2.0,
+            b: 0.0,
+            c: 0.0
+        }
+    );
+    let rectangle = CShape::from(&Shape::try_new_rectangle(1.0, 2.0).unwrap());
+    assert_eq!((rectangle.kind, rectangle.c), (SHAPE_RECTANGLE, 0.0));
+    // And ignored on the way back.
+    let noisy = CShape {
+        b: 99.0,
+        c: f64::NAN,
+        ..circle
+    };
+    assert_eq!(
+        Shape::try_from(&noisy),
+        Ok(Shape::try_new_circle(2.0).unwrap())
+    );
+}
+
+#[test]
+fn unknown_kinds_are_rejected() {
+    for kind in [3, 0x7f, u8::MAX] {
+        let shape = CShape {
+            kind,
+            a: 1.0,
+            b: 1.0,
+            c: 1.0,
+        };
+        assert_eq!(Shape::try_from(&shape), Err(CShapeError::UnknownKind(kind)));
+    }
+    assert_eq!(
+        CShapeError::UnknownKind(9).to_string(),
+        "unknown shape kind 9"
+    );
+}
+
+#[test]
+fn invalid_lengths_are_rejected() {
+    let negative = CShape {
+        kind: SHAPE_RECTANGLE,
+        a: -1.0,
+        b: 1.0,
+        c: 0.0,
+    };
+    assert_eq!(
+        Shape::try_from(&negative),
+        Err(CShapeError::Invalid(ShapeError::InvalidLength(-1.0)))
+    );
+    let flat = CShape {
+        kind: SHAPE_TRIANGLE,
+        a: 1.0,
+        b: 2.0,
+        c: 3.0,
+    };
+    assert!(matches!(
+        Shape::try_from(&flat),
+        Err(CShapeError::Invalid(ShapeError::TriangleInequality { .. }))
+    ));
+    // A zeroed struct is a rectangle with no width.
+    assert!(Shape::try_from(&CShape::default()).is_err());
+}
+
+#[test]
+fn layout_is_what_c_expects() {
+    // A `u8`, padded to the alignment of the `f64`s that follow it.
+    assert_eq!(std::mem::size_of::<CShape>(), 32);
+    assert_eq!(std::mem::align_of::<CShape>(), std::mem::align_of::<f64>());
+    assert_eq!(std::mem::offset_of!(CShape, kind), 0);
+    assert_eq!(std::mem::offset_of!(CShape, a), 8);
+    assert_eq!(std::mem::offset_of!(CShape, c), 24);
+}
@@ -0,0 +1,108 @@
+// The exported functions, called the way C would call them: through raw pointers, which may be
+// null or point at nonsense.
+
+use item_1_2::Shape;
+use item_6_2::c_shape::{SHAPE_CIRCLE, SHAPE_TRIANGLE};
+use item_6_2::exports::{
+    checked_div, shape_area, DIV_BAD_POINTER, DIV_BY_ZERO, DIV_NON_FINITE, DIV_OK,
+};
+use item_6_2::CShape;
+use std::f64::consts::PI;
+
+fn area(shape: &CShape) -> f64 {
+    // SAFETY: a pointer made from a reference.
+    unsafe { shape_area(shape) }
+}
+
+#[test]
+fn area_of_valid_shapes() {
+    let circle = CShape {
+        kind: SHAPE_CIRCLE,
+        a: 2.0,
+        ..CShape::default()
+    };
+    assert_eq!(area(&circle), 4.0 * PI);
+    let triangle = CShape {
+        kind: SHAPE_TRIANGLE,
+        a: 3.0,
+        b: 4.0,
+        c: 5.0,
+    };
+    assert_eq!(area(&triangle), 6.0);
+    let rectangle = Shape::try_new_rectangle(2.0, 3.5).unwrap();
+    assert_eq!(area(&CShape::from(&rectangle)), rectangle.area());
+}
+
+#[test]
+fn area_of_invalid_input_is_nan() {
+    // SAFETY: null is allowed.
+    assert!(unsafe { shape_area(std::ptr::null()) }.is_nan());
+    let unknown = CShape {
+        kind: 42,
+        a: 1.0,
+        b: 1.0,
+        c: 1.0,
+    };
+    assert!(area(&unknown).is_nan());
+    let negative = CShape {
+        kind: SHAPE_CIRCLE,
+        a: -1.0,
+        ..CShape::default()
+    };
+    assert!(area(&negative).is_nan());
+}
+
+#[test]
+fn area_through_a_misaligned_pointer_is_nan() {
+    let buffer = [0u8; 2 * std::mem::size_of::<CShape>()];
+    let base = buffer.as_ptr();
+    let offset = if base.align_offset(8) == 0 { 1 } else { 0 };
+    let misaligned = base.wrapping_add(offset).cast::<CShape>();
+    assert!(!misaligned.is_aligned());
+    // SAFETY: `shape_area` checks alignment before reading, and the bytes are all there anyway.
+    assert!(unsafe { shape_area(misaligned) }.is_nan());
+}
+
+#[test]
+fn checked_div_writes_the_quotient() {
+    let mut out = f64::NAN;
+    // SAFETY: a pointer made from a mutable reference.
+    assert_eq!(unsafe { checked_div(7.0, 2.0, &mut out) }, DIV_OK);
+    assert_eq!(out, 3.5);
+}
+
+#[test]
+fn checked_div_errors_leave_out_alone() {
+    let cases = [
+        (1.0, 0.0, DIV_BY_ZERO),
+        (1.0, -0.0, DIV_BY_ZERO),
+        (f64::NAN, 1.0, DIV_NON_FINITE),
+        (1.0, f64::INFINITY, DIV_NON_FINITE),
+        (f64::MAX, 0.5, DIV_NON_FINITE),
+    ];
+    for (x, y, status) in cases {
+        let mut out = 123.0;
+        // SAFETY: as above.
+        assert_eq!(unsafe { checked_div(x, y, &mut out) }, status, "{x} / {y}");
+        assert_eq!(out, 123.0);
+    }
+}
+
+#[test]
+fn checked_div_rejects_bad_out_pointers() {
+    // SAFETY: null is allowed.
+    assert_eq!(
+        unsafe { checked_div(1.0, 2.0, std::ptr::null_mut()) },
+        DIV_BAD_POINTER
+    );
+    let mut buffer = [0u8; 16];
+    let base = buffer.as_mut_ptr();
+    let offset = if base.align_offset(8) == 0 { 1 } else { 0 };
+    let misaligned = base.wrapping_add(offset).cast::<f64>();
+    // SAFETY: checked for alignment before anything is written.
+    assert_eq!(
+        unsafe { checked_div(1.0, 2.0, misaligned) },
+        DIV_BAD_POINTER
+    );
+    assert_eq!(buffer, [0; 16]);
+}
@@ -0,0 +1,187 @@
+// A small micro-benchmark harness, for comparing a handful of functions without pulling in a
+// benchmarking framework.
+//
+// Each benchmark is first run a number of times untimed, to warm up caches and branch
+// predictors, and then timed in `samples` batches of `iterations` calls. The result is the
+// median time per call across the batches, with the median absolute deviation (MAD) as its
+// spread. Unlike the mean and standard deviation, both shrug off the odd batch that was
+// interrupted by the OS.
+//
+// Timing goes through `Clock`, so the statistics can be tested with made-up timings.
+
+pub mod report;
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// A source of timestamps, measured from some fixed point in the past.
+pub trait Clock {
+    fn now(&mut self) -> Duration;
+}
+
+/// The real clock.
+#[derive(Debug, Clone, Copy)]
+pub struct InstantClock {
+    start: Instant,
+}
+
+impl InstantClock {
+    pub fn new() -> Self {
+        InstantClock {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Default for InstantClock {
+    fn default() -> Self {
+        InstantClock::new()
+    }
+}
+
+impl Clock for InstantClock {
+    fn now(&mut self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+impl<F: FnMut() -> Duration> Clock for F {
+    fn now(&mut self) -> Duration {
+        self()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// Untimed calls before measuring starts.
+    pub warmup: usize,
+    /// How many timed batches to take. Must be at least 1.
+    pub samples: usize,
+    /// Calls per batch. Must be at least 1; more calls make very fast functions measurable.
+    pub iterations: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            warmup: 100,
+            samples: 21,
+            iterations: 100,
+        }
+    }
+}
+
+/// Time per call, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub median_ns: f64,
+    pub mad_ns: f64,
+}
+
+impl Stats {
+    /// Summarise per-call timings. `samples` must not be empty.
+    pub fn from_samples(samples: &[f64]) -> Stats {
+        Stats {
+            median_ns: median(samples),
+            mad_ns: mad(samples),
+        }
+    }
+}
+
+/// The middle value, or the mean of the two middle values for an even count.
+///
+/// Panics if `values` is empty.
+pub fn median(values: &[f64]) -> f64 {
+    assert!(!values.is_empty(), "median of no values");
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+/// Median absolute deviation: the median distance of the values from their median.
+///
+/// Panics if `values` is empty.
+pub fn mad(values: &[f64]) -> f64 {
+    let center = median(values);
+    let deviations: Vec<f64> = values.iter().map(|v| (v - center).abs()).collect();
+    median(&deviations)
+}
+
+/// Run `f` as described by `config` and summarise how long each call took.
+pub fn measure(f: &mut dyn FnMut(), config: &Config, clock: &mut impl Clock) -> Stats {
+    assert!(
+        config.samples > 0 && config.iterations > 0,
+        "samples and iterations must be at least 1"
+    );
+    for _ in 0..config.warmup {
+        f();
+    }
+    let samples: Vec<f64> = (0..config.samples)
+        .map(|_| {
+            let start = clock.now();
+            for _ in 0..config.iterations {
+                f();
+            }
+            let elapsed = clock.now().saturating_sub(start);
+            elapsed.as_nanos() as f64 / config.iterations as f64
+        })
+        .collect();
+    Stats::from_samples(&samples)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub name: String,
+    pub stats: Stats,
+}
+
+/// A list of named benchmarks, run in the order they were registered.
+///
+/// Names are conventionally `group/variant`; the report compares each variant with the first
+/// one in its group.
+#[derive(Default)]
+pub struct Bench<'a> {
+    benches: Vec<(String, Box<dyn FnMut() + 'a>)>,
+}
+
+impl<'a> Bench<'a> {
+    pub fn new() -> Self {
+        Bench::default()
+    }
+
+    /// Register `f` under `name`. Its return value is passed through [`black_box`] so the
+    /// compiler can't optimise the work away.
+    pub fn bench<R>(&mut self, name: &str, mut f: impl FnMut() -> R + 'a) -> &mut Self {
+        self.benches.push((
+            name.to_owned(),
+            Box::new(move || {
+                black_box(f());
+            }),
+        ));
+        self
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.benches.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Keep only the benchmarks whose name satisfies `keep`.
+    pub fn retain(&mut self, mut keep: impl FnMut(&str) -> bool) {
+        self.benches.retain(|(name, _)| keep(name));
+    }
+
+    pub fn run(&mut self, config: &Config, clock: &mut impl Clock) -> Vec<Measurement> {
+        self.benches
+            .iter_mut()
+            .map(|(name, f)| Measurement {
+                name: name.clone(),
+                stats: measure(f, config, clock),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,195 @@
+use bench_lite::report::{from_json, regressions, table, to_json};
+use bench_lite::{Bench, Config, InstantClock};
+use codec::{decode_all, encode_all, Encoding, Shape};
+use glob::{glob_match, Glob};
+use hexdump::{hex_dump, hex_dump_to, HexDumpOpts};
+use item_1_3::hot_loops::{
+    pipeline_combinator, pipeline_inline_never, pipeline_match, sum_some_combinator,
+    sum_some_inline_never, sum_some_match,
+};
+use std::cell::OnceCell;
+use std::process::ExitCode;
+use std::rc::Rc;
+use transforms_bench::{option_values, pipeline_numbers};
+
+const USAGE: &str = "usage: bench-lite [--json] [--baseline FILE] [--threshold PERCENT]
+                  [--warmup N] [--samples N] [--iterations N] [FILTER]";
+
+struct Args {
+    json: bool,
+    baseline: Option<String>,
+    // How much slower than the baseline a benchmark may get before the run fails.
+    threshold_percent: f64,
+    config: Config,
+    filter: Option<String>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args {
+        json: false,
+        baseline: None,
+        threshold_percent: 10.0,
+        config: Config::default(),
+        filter: None,
+    };
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("{name} needs a value"));
+        let count = |name: &str, value: String| {
+            value
+                .parse()
+                .ok()
+                .filter(|&n| n > 0 || name == "--warmup")
+                .ok_or(format!("{name} needs a positive number, not {value:?}"))
+        };
+        match arg.as_str() {
+            "--json" => parsed.json = true,
+            "--baseline" => parsed.baseline = Some(value("--baseline")?),
+            "--threshold" => {
+                let v = value("--threshold")?;
+                parsed.threshold_percent = v
+                    .parse()
+                    .map_err(|_| format!("--threshold needs a number, not {v:?}"))?;
+            }
+            "--warmup" => parsed.config.warmup = count("--warmup", value("--warmup")?)?,
+            "--samples" => parsed.config.samples = count("--samples", value("--samples")?)?,
+            "--iterations" => {
+                parsed.config.iterations = count("--iterations", value("--iterations")?)?
+            }
+            "-h" | "--help" => return Err(USAGE.to_owned()),
+            flag if flag.starts_with('-') => return Err(format!("unknown flag {flag}\n{USAGE}")),
+            _ if parsed.filter.is_none() => parsed.filter = Some(arg),
+            _ => return Err(format!("only one filter may be given\n{USAGE}")),
+        }
+    }
+    Ok(parsed)
+}
+
+// Everything worth timing, in the pairs that are worth comparing.
+fn register(bench: &mut Bench<'_>) {
+    let shapes: Vec<Shape> = (0..100)
+        .map(|i| match i % 2 {
+            0 => Shape::Circle { radius: i as f64 },
+            _ => Shape::Rectangle {
+                width: i as f64,
+                height: 2.0,
+            },
+        })
+        .collect();
+    let encoded = encode_all(&shapes);
+    bench.bench("encode/encode_all", move || encode_all(&shapes));
+    let shapes = decode_all(&encoded).unwrap().0;
+    bench.bench("encode/to_bytes_each", move || {
+        shapes.iter().flat_map(Shape::to_bytes).collect::<Vec<u8>>()
+    });
+    bench.bench("decode/decode_all", move || decode_all(&encoded));
+
+    let files = [
+        "src/main.rs",
+        "Cargo.toml",
+        "README.md",
+        "src/report.rs",
+        "target",
+    ];
+    bench.bench("glob/glob_match", move || {
+        files.iter().filter(|f| glob_match("src/*.rs", f)).count()
+    });
+    let pattern = Glob::new("src/*.rs").unwrap();
+    bench.bench("glob/compiled", move || {
+        files.iter().filter(|f| pattern.matches(f)).count()
+    });
+
+    let bytes: Vec<u8> = (0..=255).collect();
+    let opts = HexDumpOpts::default();
+    bench.bench("hexdump/in_memory", {
+        let (bytes, opts) = (bytes.clone(), opts.clone());
+        move || hex_dump(&bytes, &opts)
+    });
+    bench.bench("hexdump/streaming", move || {
+        let mut out = Vec::new();
+        hex_dump_to(&bytes[..], &mut out, &opts).unwrap();
+        out
+    });
+
+    // Item 1.3's claim that combinators cost no more than `match`, on ten million values, with
+    // a third version of each loop that can't inline its per-element call. About one value in
+    // seven is missing, and about one number in eight fails the pipeline. Each set is built on
+    // the first call that needs it, so that runs of the other groups don't pay for it.
+    let values: Rc<OnceCell<Vec<Option<u64>>>> = Rc::default();
+    bench.bench("option_sum/match", {
+        let values = Rc::clone(&values);
+        move || sum_some_match(values.get_or_init(|| option_values(10_000_000)))
+    });
+    bench.bench("option_sum/combinator", {
+        let values = Rc::clone(&values);
+        move || sum_some_combinator(values.get_or_init(|| option_values(10_000_000)))
+    });
+    bench.bench("option_sum/inline_never", move || {
+        sum_some_inline_never(values.get_or_init(|| option_values(10_000_000)))
+    });
+    let numbers: Rc<OnceCell<Vec<u64>>> = Rc::default();
+    bench.bench("result_pipeline/match", {
+        let numbers = Rc::clone(&numbers);
+        move || pipeline_match(numbers.get_or_init(|| pipeline_numbers(10_000_000)))
+    });
+    bench.bench("result_pipeline/combinator", {
+        let numbers = Rc::clone(&numbers);
+        move || pipeline_combinator(numbers.get_or_init(|| pipeline_numbers(10_000_000)))
+    });
+    bench.bench("result_pipeline/inline_never", move || {
+        pipeline_inline_never(numbers.get_or_init(|| pipeline_numbers(10_000_000)))
+    });
+}
+
+fn main() -> Result<ExitCode, Box<dyn std::error::Error>> {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{message}");
+            return Ok(ExitCode::from(2));
+        }
+    };
+
+    // Read the baseline first, so a bad file doesn't waste a whole run.
+    let baseline = match &args.baseline {
+        Some(path) => {
+            let text = std::fs::read_to_string(path)
+                .map_err(|e| format!("can't read baseline {path}: {e}"))?;
+            match from_json(&text) {
+                Ok(baseline) => Some(baseline),
+                Err(e) => {
+                    eprint!("{path}: {}", diagnostic::render(&text, &e));
+                    return Ok(ExitCode::from(2));
+                }
+            }
+        }
+        None => None,
+    };
+
+    let mut bench = Bench::new();
+    register(&mut bench);
+    if let Some(filter) = &args.filter {
+        bench.retain(|name| name.contains(filter.as_str()));
+    }
+    let results = bench.run(&args.config, &mut InstantClock::new());
+
+    if args.json {
+        print!("{}", to_json(&results));
+    } else {
+        print!("{}", table(&results, baseline.as_deref()));
+    }
+
+    let Some(baseline) = baseline else {
+        return Ok(ExitCode::SUCCESS);
+    };
+    let slower = regressions(&results, &baseline, args.threshold_percent);
+    if slower.is_empty() {
+        return Ok(ExitCode::SUCCESS);
+    }
+    for change in &slower {
+        eprintln!(
+            "regression: {} is {:.1}% slower than the baseline (threshold {}%)",
+            change.name, change.percent, args.threshold_percent
+        );
+    }
+    Ok(ExitCode::FAILURE)
+}
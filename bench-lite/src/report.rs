@@ -0,0 +1,434 @@
+// Presenting results: a plain-text table, and a JSON form that can be saved as a baseline and
+// read back to compare a later run against.
+//
+// The JSON is an array of `{"name": ..., "median_ns": ..., "mad_ns": ...}` objects. Reading it
+// back uses a small parser of its own rather than a JSON library; it accepts any valid JSON
+// and ignores fields it doesn't know, so the format can grow.
+
+use crate::{Measurement, Stats};
+use std::fmt::{self, Write as _};
+use std::ops::Range;
+
+/// Percent change from `reference` to `current`; positive means slower.
+pub fn change_percent(reference: f64, current: f64) -> f64 {
+    (current - reference) / reference * 100.0
+}
+
+/// Format a time in nanoseconds with a unit that keeps the number readable.
+pub fn format_ns(ns: f64) -> String {
+    if ns < 1e3 {
+        format!("{ns:.1} ns")
+    } else if ns < 1e6 {
+        format!("{:.2} us", ns / 1e3)
+    } else if ns < 1e9 {
+        format!("{:.2} ms", ns / 1e6)
+    } else {
+        format!("{:.2} s", ns / 1e9)
+    }
+}
+
+/// One row per measurement, with its change relative to `baseline` if given. Without a
+/// baseline, each benchmark is compared with the first one in its `group/`.
+pub fn table(results: &[Measurement], baseline: Option<&[Measurement]>) -> String {
+    let reference = |m: &Measurement| -> Option<f64> {
+        match baseline {
+            Some(baseline) => find(baseline, &m.name).map(|b| b.stats.median_ns),
+            None => {
+                let first = results.iter().find(|r| group(&r.name) == group(&m.name))?;
+                (first.name != m.name).then_some(first.stats.median_ns)
+            }
+        }
+    };
+    let vs = if baseline.is_some() {
+        "vs baseline"
+    } else {
+        "vs group"
+    };
+
+    let width = results
+        .iter()
+        .map(|m| m.name.len())
+        .chain(["benchmark".len()])
+        .max()
+        .unwrap_or(0);
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{:width$}  {:>10}  {:>10}  {vs:>11}",
+        "benchmark", "median", "mad"
+    );
+    for m in results {
+        let change = match reference(m) {
+            Some(reference) => format!("{:+.1}%", change_percent(reference, m.stats.median_ns)),
+            None if baseline.is_some() => "new".to_owned(),
+            None => "-".to_owned(),
+        };
+        let _ = writeln!(
+            out,
+            "{:width$}  {:>10}  {:>10}  {change:>11}",
+            m.name,
+            format_ns(m.stats.median_ns),
+            format_ns(m.stats.mad_ns),
+        );
+    }
+    out
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change<'a> {
+    pub name: &'a str,
+    pub percent: f64,
+}
+
+/// The benchmarks in `results` that got slower than `baseline` by more than `threshold_percent`.
+/// Benchmarks missing from the baseline are new, not regressions.
+pub fn regressions<'a>(
+    results: &'a [Measurement],
+    baseline: &[Measurement],
+    threshold_percent: f64,
+) -> Vec<Change<'a>> {
+    results
+        .iter()
+        .filter_map(|m| {
+            let reference = find(baseline, &m.name)?.stats.median_ns;
+            let percent = change_percent(reference, m.stats.median_ns);
+            (percent > threshold_percent).then_some(Change {
+                name: &m.name,
+                percent,
+            })
+        })
+        .collect()
+}
+
+fn find<'a>(results: &'a [Measurement], name: &str) -> Option<&'a Measurement> {
+    results.iter().find(|m| m.name == name)
+}
+
+fn group(name: &str) -> &str {
+    name.split_once('/').map_or(name, |(group, _)| group)
+}
+
+pub fn to_json(results: &[Measurement]) -> String {
+    let mut out = String::from("[\n");
+    for (i, m) in results.iter().enumerate() {
+        let comma = if i + 1 < results.len() { "," } else { "" };
+        let _ = writeln!(
+            out,
+            "  {{\"name\": {}, \"median_ns\": {}, \"mad_ns\": {}}}{comma}",
+            json_string(&m.name),
+            json_number(m.stats.median_ns),
+            json_number(m.stats.mad_ns),
+        );
+    }
+    out.push_str("]\n");
+    out
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json_number(n: f64) -> String {
+    // JSON has no NaN or infinity.
+    if n.is_finite() {
+        n.to_string()
+    } else {
+        "null".to_owned()
+    }
+}
+
+/// A baseline file that couldn't be read, and where in it the problem is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub span: Range<usize>,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl diagnostic::Spanned for ParseError {
+    fn span(&self) -> Option<Range<usize>> {
+        Some(self.span.clone())
+    }
+}
+
+/// Read back what [`to_json`] wrote.
+pub fn from_json(input: &str) -> Result<Vec<Measurement>, ParseError> {
+    let mut parser = Parser { input, pos: 0 };
+    let root = parser.value()?;
+    parser.skip_whitespace();
+    if parser.pos < input.len() {
+        return Err(parser.error("unexpected text after the results", parser.pos..input.len()));
+    }
+
+    let Value::Array(items) = root.value else {
+        return Err(parser.error("expected an array of results", root.span));
+    };
+    items
+        .into_iter()
+        .map(|item| {
+            let Value::Object(fields) = item.value else {
+                return Err(parser.error("expected a result object", item.span));
+            };
+            let field = |key: &str| {
+                fields
+                    .iter()
+                    .find(|(k, _)| k == key)
+                    .map(|(_, v)| v)
+                    .ok_or_else(|| {
+                        parser.error(&format!("missing field {key:?}"), item.span.clone())
+                    })
+            };
+            let number = |key: &str| match field(key)? {
+                Node {
+                    value: Value::Number(n),
+                    ..
+                } => Ok(*n),
+                other => {
+                    Err(parser.error(&format!("{key:?} must be a number"), other.span.clone()))
+                }
+            };
+            let name = match field("name")? {
+                Node {
+                    value: Value::String(s),
+                    ..
+                } => s.clone(),
+                other => return Err(parser.error("\"name\" must be a string", other.span.clone())),
+            };
+            Ok(Measurement {
+                name,
+                stats: Stats {
+                    median_ns: number("median_ns")?,
+                    mad_ns: number("mad_ns")?,
+                },
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+enum Value {
+    // `true`, `false` and `null`, which no field of ours uses.
+    Literal,
+    Number(f64),
+    String(String),
+    Array(Vec<Node>),
+    Object(Vec<(String, Node)>),
+}
+
+// A value and the bytes of the input it came from, for error messages.
+#[derive(Debug)]
+struct Node {
+    value: Value,
+    span: Range<usize>,
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str, span: Range<usize>) -> ParseError {
+        ParseError {
+            message: message.to_owned(),
+            span,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start_matches([' ', '\t', '\n', '\r']).len();
+    }
+
+    // Consume `c`, which must come next (after whitespace).
+    fn expect(&mut self, c: char) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.unexpected(&format!("expected `{c}`")))
+        }
+    }
+
+    fn unexpected(&self, message: &str) -> ParseError {
+        let len = self.peek().map_or(0, char::len_utf8);
+        self.error(message, self.pos..self.pos + len)
+    }
+
+    fn value(&mut self) -> Result<Node, ParseError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let value = match self.peek() {
+            Some('[') => self.array()?,
+            Some('{') => self.object()?,
+            Some('"') => Value::String(self.string()?),
+            Some('-' | '0'..='9') => self.number()?,
+            Some(_) => self.literal()?,
+            None => return Err(self.unexpected("unexpected end of input")),
+        };
+        Ok(Node {
+            value,
+            span: start..self.pos,
+        })
+    }
+
+    // A comma-separated list between `open` and `close`, each item parsed by `item`.
+    fn list<T>(
+        &mut self,
+        open: char,
+        close: char,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        self.expect(open)?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(close) {
+            self.pos += 1;
+            return Ok(items);
+        }
+        loop {
+            items.push(item(self)?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some(c) if c == close => {
+                    self.pos += 1;
+                    return Ok(items);
+                }
+                _ => return Err(self.unexpected(&format!("expected `,` or `{close}`"))),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, ParseError> {
+        self.list('[', ']', Self::value).map(Value::Array)
+    }
+
+    fn object(&mut self) -> Result<Value, ParseError> {
+        self.list('{', '}', |p| {
+            p.skip_whitespace();
+            if p.peek() != Some('"') {
+                return Err(p.unexpected("expected a field name"));
+            }
+            let key = p.string()?;
+            p.expect(':')?;
+            Ok((key, p.value()?))
+        })
+        .map(Value::Object)
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        let start = self.pos;
+        self.pos += 1; // the opening quote
+        let mut out = String::new();
+        loop {
+            let Some(c) = self.peek() else {
+                return Err(self.error("unterminated string", start..self.pos));
+            };
+            self.pos += c.len_utf8();
+            match c {
+                '"' => return Ok(out),
+                '\\' => out.push(self.escape()?),
+                c if c.is_control() => {
+                    return Err(self.error("control character in string", self.pos - 1..self.pos));
+                }
+                c => out.push(c),
+            }
+        }
+    }
+
+    // The rest of an escape sequence, after its `\`.
+    fn escape(&mut self) -> Result<char, ParseError> {
+        let start = self.pos - 1;
+        let c = self.peek();
+        self.pos += c.map_or(0, char::len_utf8);
+        Ok(match c {
+            Some('"') => '"',
+            Some('\\') => '\\',
+            Some('/') => '/',
+            Some('b') => '\u{8}',
+            Some('f') => '\u{c}',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('u') => {
+                let high = self.hex4(start)?;
+                let code = if (0xd800..0xdc00).contains(&high) {
+                    // A surrogate pair: the low half has to follow as another `\u` escape.
+                    if !self.input[self.pos..].starts_with("\\u") {
+                        return Err(self.error("unpaired surrogate", start..self.pos));
+                    }
+                    self.pos += 2;
+                    let low = self.hex4(start)?;
+                    if !(0xdc00..0xe000).contains(&low) {
+                        return Err(self.error("unpaired surrogate", start..self.pos));
+                    }
+                    0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+                } else {
+                    high
+                };
+                char::from_u32(code)
+                    .ok_or_else(|| self.error("invalid unicode escape", start..self.pos))?
+            }
+            _ => return Err(self.error("invalid escape", start..self.pos)),
+        })
+    }
+
+    fn hex4(&mut self, start: usize) -> Result<u32, ParseError> {
+        let digits = self.input.get(self.pos..self.pos + 4).unwrap_or("");
+        if digits.len() < 4 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(self.error("invalid unicode escape", start..self.pos));
+        }
+        self.pos += 4;
+        Ok(u32::from_str_radix(digits, 16).unwrap())
+    }
+
+    fn number(&mut self) -> Result<Value, ParseError> {
+        let start = self.pos;
+        let rest = &self.input[start..];
+        let len = rest
+            .find(|c: char| !matches!(c, '0'..='9' | '-' | '+' | '.' | 'e' | 'E'))
+            .unwrap_or(rest.len());
+        self.pos += len;
+        rest[..len]
+            .parse()
+            .map(Value::Number)
+            .map_err(|_| self.error("invalid number", start..self.pos))
+    }
+
+    fn literal(&mut self) -> Result<Value, ParseError> {
+        let rest = &self.input[self.pos..];
+        for word in ["true", "false", "null"] {
+            if rest.starts_with(word) {
+                self.pos += word.len();
+                return Ok(Value::Literal);
+            }
+        }
+        Err(self.unexpected("expected a value"))
+    }
+}
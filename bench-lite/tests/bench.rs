@@ -0,0 +1,94 @@
+use bench_lite::{mad, measure, median, Bench, Config, Stats};
+use std::cell::Cell;
+use std::time::Duration;
+
+// A clock that moves forward by the next of `steps` (in nanoseconds) each time it is read.
+fn scripted_clock(steps: &[u64]) -> impl FnMut() -> Duration + '_ {
+    let mut now = Duration::ZERO;
+    let mut steps = steps.iter();
+    move || {
+        now += Duration::from_nanos(*steps.next().expect("clock read too often"));
+        now
+    }
+}
+
+#[test]
+fn median_of_odd_and_even_counts() {
+    assert_eq!(median(&[3.0, 1.0, 2.0]), 2.0);
+    assert_eq!(median(&[4.0, 1.0, 3.0, 2.0]), 2.5);
+    assert_eq!(median(&[7.0]), 7.0);
+}
+
+#[test]
+fn median_ignores_outliers() {
+    assert_eq!(median(&[10.0, 11.0, 9.0, 10.0, 1_000_000.0]), 10.0);
+}
+
+#[test]
+#[should_panic(expected = "median of no values")]
+fn median_of_nothing_panics() {
+    median(&[]);
+}
+
+#[test]
+fn median_absolute_deviation() {
+    // Deviations from the median 2 are 1, 1, 0, 2, 7: their median is 1.
+    assert_eq!(mad(&[1.0, 3.0, 2.0, 4.0, 9.0]), 1.0);
+    assert_eq!(mad(&[5.0, 5.0, 5.0]), 0.0);
+}
+
+#[test]
+fn measure_times_each_batch() {
+    let calls = Cell::new(0);
+    let config = Config {
+        warmup: 3,
+        samples: 3,
+        iterations: 10,
+    };
+    // Each batch reads the clock at its start and end. Batches take 100, 300 and 200 ns,
+    // i.e. 10, 30 and 20 ns per call; the gaps between batches don't count.
+    let mut clock = scripted_clock(&[1_000, 100, 5_000, 300, 5_000, 200]);
+    let stats = measure(&mut || calls.set(calls.get() + 1), &config, &mut clock);
+    assert_eq!(
+        stats,
+        Stats {
+            median_ns: 20.0,
+            mad_ns: 10.0,
+        }
+    );
+    assert_eq!(calls.get(), 3 + 3 * 10);
+}
+
+#[test]
+fn bench_runs_in_registration_order() {
+    let order = Cell::new(Vec::new());
+    let push = |name| {
+        let mut v = order.take();
+        v.push(name);
+        order.set(v);
+    };
+    let mut bench = Bench::new();
+    bench
+        .bench("b/first", || push("first"))
+        .bench("a/second", || push("second"))
+        .bench("c/third", || push("third"));
+    assert_eq!(
+        bench.names().collect::<Vec<_>>(),
+        ["b/first", "a/second", "c/third"]
+    );
+
+    bench.retain(|name| name != "c/third");
+    let config = Config {
+        warmup: 0,
+        samples: 1,
+        iterations: 1,
+    };
+    let results = bench.run(&config, &mut scripted_clock(&[1, 4, 1, 6]));
+    drop(bench);
+    assert_eq!(order.take(), ["first", "second"]);
+    let medians: Vec<_> = results
+        .iter()
+        .map(|m| (m.name.as_str(), m.stats.median_ns))
+        .collect();
+    assert_eq!(medians, [("b/first", 4.0), ("a/second", 6.0)]);
+}
@@ -0,0 +1,152 @@
+use bench_lite::report::{change_percent, format_ns, from_json, regressions, table, to_json};
+use bench_lite::{Measurement, Stats};
+
+fn measurement(name: &str, median_ns: f64, mad_ns: f64) -> Measurement {
+    Measurement {
+        name: name.to_owned(),
+        stats: Stats { median_ns, mad_ns },
+    }
+}
+
+#[test]
+fn percent_change() {
+    assert_eq!(change_percent(100.0, 150.0), 50.0);
+    assert_eq!(change_percent(200.0, 100.0), -50.0);
+}
+
+#[test]
+fn units() {
+    assert_eq!(format_ns(12.25), "12.2 ns");
+    assert_eq!(format_ns(1_500.0), "1.50 us");
+    assert_eq!(format_ns(2_345_678.0), "2.35 ms");
+    assert_eq!(format_ns(3e9), "3.00 s");
+}
+
+#[test]
+fn table_compares_within_groups() {
+    let results = [
+        measurement("sort/std", 100.0, 2.0),
+        measurement("sort/bubble", 250.0, 10.0),
+        measurement("parse", 1_000.0, 0.0),
+    ];
+    assert_eq!(
+        table(&results, None),
+        "\
+benchmark        median         mad     vs group
+sort/std       100.0 ns      2.0 ns            -
+sort/bubble    250.0 ns     10.0 ns      +150.0%
+parse           1.00 us      0.0 ns            -
+"
+    );
+}
+
+#[test]
+fn table_compares_with_baseline() {
+    let baseline = [measurement("a", 100.0, 1.0), measurement("b", 100.0, 1.0)];
+    let results = [measurement("a", 90.0, 1.0), measurement("c", 5.0, 1.0)];
+    assert_eq!(
+        table(&results, Some(&baseline)),
+        "\
+benchmark      median         mad  vs baseline
+a             90.0 ns      1.0 ns       -10.0%
+c              5.0 ns      1.0 ns          new
+"
+    );
+}
+
+#[test]
+fn regressions_beyond_threshold() {
+    let baseline = [
+        measurement("same", 100.0, 1.0),
+        measurement("slower", 100.0, 1.0),
+        measurement("a bit slower", 100.0, 1.0),
+        measurement("faster", 100.0, 1.0),
+    ];
+    let results = [
+        measurement("same", 100.0, 1.0),
+        measurement("slower", 125.0, 1.0),
+        measurement("a bit slower", 105.0, 1.0),
+        measurement("faster", 50.0, 1.0),
+        measurement("new", 1e9, 1.0),
+    ];
+    let slower = regressions(&results, &baseline, 10.0);
+    assert_eq!(slower.len(), 1);
+    assert_eq!(slower[0].name, "slower");
+    assert_eq!(slower[0].percent, 25.0);
+}
+
+#[test]
+fn json_round_trip() {
+    let results = [
+        measurement("encode/all", 1234.5, 6.25),
+        measurement("quote\" and \\ and \u{1}", 1.0, 0.0),
+        measurement("ünïcode", 1e-3, 1e12),
+    ];
+    let json = to_json(&results);
+    assert_eq!(from_json(&json).unwrap(), results);
+    assert!(json.starts_with(
+        "[\n  {\"name\": \"encode/all\", \"median_ns\": 1234.5, \"mad_ns\": 6.25},\n"
+    ));
+    assert_eq!(to_json(&[]), "[\n]\n");
+    assert_eq!(from_json("[\n]\n").unwrap(), []);
+}
+
+#[test]
+fn json_from_elsewhere() {
+    // Other field orders, unknown fields, escapes and exponents are all fine.
+    let json = r#" [ { "mad_ns": 2e1, "extra": [true, null, {"x": false}],
+        "name": "café 😀 \n", "median_ns": -0.5E+2 } ] "#;
+    assert_eq!(
+        from_json(json).unwrap(),
+        [measurement("café 😀 \n", -50.0, 20.0)]
+    );
+}
+
+#[test]
+fn json_errors_point_at_the_problem() {
+    let cases = [
+        ("", "unexpected end of input", 0..0),
+        ("{}", "expected an array of results", 0..2),
+        ("[1]", "expected a result object", 1..2),
+        (
+            r#"[{"name": "x", "mad_ns": 1}]"#,
+            "missing field \"median_ns\"",
+            1..27,
+        ),
+        (
+            r#"[{"name": "x", "median_ns": "1", "mad_ns": 1}]"#,
+            "\"median_ns\" must be a number",
+            28..31,
+        ),
+        (r#"[{"name": "x""#, "expected `,` or `}`", 13..13),
+        (r#"["abc"#, "unterminated string", 1..5),
+        (r#"["\q"]"#, "invalid escape", 2..4),
+        (r#"["\ud800"]"#, "unpaired surrogate", 2..8),
+        ("[1.2.3]", "invalid number", 1..6),
+        ("[nope]", "expected a value", 1..2),
+        ("[] []", "unexpected text after the results", 3..5),
+    ];
+    for (input, message, span) in cases {
+        let err = from_json(input).unwrap_err();
+        assert_eq!(
+            (err.message.as_str(), err.span),
+            (message, span),
+            "{input:?}"
+        );
+    }
+}
+
+#[test]
+fn json_errors_render() {
+    let input = "[\n  {\"name\": 3}\n]";
+    let err = from_json(input).unwrap_err();
+    assert_eq!(
+        diagnostic::render(input, &err),
+        "error: \"name\" must be a string
+ --> 2:12
+  |
+2 |   {\"name\": 3}
+  |            ^
+"
+    );
+}
@@ -0,0 +1,239 @@
+// CSV import and export for batches of jobs.
+//
+// This implements the parts of RFC 4180 that matter for round-tripping job names: fields
+// containing commas, quotes or line breaks are wrapped in double quotes, with embedded quotes
+// doubled (`he said "hi"` becomes `"he said ""hi"""`), and records end in CRLF. On import,
+// either CRLF or a bare LF is accepted, columns are matched by their header name so their
+// order doesn't matter, and columns other than `id` and `name` are skipped with a warning.
+
+use crate::Job;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+#[derive(Debug)]
+pub enum CsvErrorKind {
+    Io(io::Error),
+    /// The header row doesn't have a column with this name.
+    MissingColumn(&'static str),
+    /// A record has a different number of fields from the header.
+    FieldCount {
+        expected: usize,
+        found: usize,
+    },
+    /// A quoted field is still open at the end of the input.
+    UnterminatedQuote,
+    /// A `"` inside an unquoted field, or text after a closing quote.
+    StrayQuote,
+    InvalidId(std::num::ParseIntError),
+}
+
+#[derive(Debug)]
+pub struct CsvError {
+    /// 1-based record number, counting the header as row 1.
+    pub row: usize,
+    /// 1-based field number, or 0 when the error isn't about a particular field.
+    pub column: usize,
+    pub kind: CsvErrorKind,
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "row {}", self.row)?;
+        if self.column > 0 {
+            write!(f, ", column {}", self.column)?;
+        }
+        match &self.kind {
+            CsvErrorKind::Io(e) => write!(f, ": I/O error: {e}"),
+            CsvErrorKind::MissingColumn(name) => write!(f, ": missing column {name:?}"),
+            CsvErrorKind::FieldCount { expected, found } => {
+                write!(f, ": expected {expected} fields, found {found}")
+            }
+            CsvErrorKind::UnterminatedQuote => write!(f, ": unterminated quoted field"),
+            CsvErrorKind::StrayQuote => write!(f, ": unexpected quote"),
+            CsvErrorKind::InvalidId(e) => write!(f, ": invalid job id: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            CsvErrorKind::Io(e) => Some(e),
+            CsvErrorKind::InvalidId(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// The result of an import, including anything that was skipped along the way.
+#[derive(Debug, Default)]
+pub struct CsvImport {
+    pub jobs: Vec<Job>,
+    /// One message per ignored header column.
+    pub warnings: Vec<String>,
+}
+
+pub fn export_jobs_csv(jobs: &[Job], out: &mut impl Write) -> Result<(), CsvError> {
+    let io_error = |row| {
+        move |e| CsvError {
+            row,
+            column: 0,
+            kind: CsvErrorKind::Io(e),
+        }
+    };
+    out.write_all(b"id,name\r\n").map_err(io_error(1))?;
+    for (i, job) in jobs.iter().enumerate() {
+        write!(out, "{},{}\r\n", job.id, quote(&job.name)).map_err(io_error(i + 2))?;
+    }
+    Ok(())
+}
+
+pub fn import_jobs_csv(input: impl BufRead) -> Result<Vec<Job>, CsvError> {
+    import_jobs_csv_with_warnings(input).map(|import| import.jobs)
+}
+
+/// Like [`import_jobs_csv`], but also reports the columns it ignored.
+pub fn import_jobs_csv_with_warnings(input: impl BufRead) -> Result<CsvImport, CsvError> {
+    let mut records = Records { input, row: 0 };
+    let Some(header) = records.next_record()? else {
+        // An empty file holds no jobs.
+        return Ok(CsvImport::default());
+    };
+    let position = |name| {
+        header.iter().position(|h| h == name).ok_or(CsvError {
+            row: 1,
+            column: 0,
+            kind: CsvErrorKind::MissingColumn(name),
+        })
+    };
+    let (id_col, name_col) = (position("id")?, position("name")?);
+    let warnings = header
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| i != id_col && i != name_col)
+        .map(|(i, h)| format!("ignoring unknown column {h:?} (column {})", i + 1))
+        .collect();
+
+    let mut jobs = Vec::new();
+    while let Some(mut fields) = records.next_record()? {
+        let row = records.row;
+        if fields.len() != header.len() {
+            return Err(CsvError {
+                row,
+                column: 0,
+                kind: CsvErrorKind::FieldCount {
+                    expected: header.len(),
+                    found: fields.len(),
+                },
+            });
+        }
+        let id = fields[id_col].parse().map_err(|e| CsvError {
+            row,
+            column: id_col + 1,
+            kind: CsvErrorKind::InvalidId(e),
+        })?;
+        let name = std::mem::take(&mut fields[name_col]);
+        jobs.push(Job { id, name });
+    }
+    Ok(CsvImport { jobs, warnings })
+}
+
+// Quote a field if it needs it.
+fn quote(field: &str) -> std::borrow::Cow<'_, str> {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\"")).into()
+    } else {
+        field.into()
+    }
+}
+
+// Splits the input into records of unquoted fields, pulling in more lines when a quoted field
+// spans a line break.
+struct Records<R> {
+    input: R,
+    row: usize,
+}
+
+impl<R: BufRead> Records<R> {
+    fn read_line(&mut self, buf: &mut String) -> Result<usize, CsvError> {
+        self.input.read_line(buf).map_err(|e| CsvError {
+            row: self.row,
+            column: 0,
+            kind: CsvErrorKind::Io(e),
+        })
+    }
+
+    fn next_record(&mut self) -> Result<Option<Vec<String>>, CsvError> {
+        let mut line = String::new();
+        // Skip blank lines between records.
+        loop {
+            line.clear();
+            if self.read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+            if !line.trim_end_matches(['\r', '\n']).is_empty() {
+                break;
+            }
+        }
+        self.row += 1;
+
+        let error = |row, fields: &Vec<String>, kind| CsvError {
+            row,
+            column: fields.len() + 1,
+            kind,
+        };
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut in_quotes = false;
+        // Set after a closing quote, when only a comma or the end of the record may follow.
+        let mut after_quotes = false;
+        let mut chars: Vec<char> = line.chars().collect();
+        let mut i = 0;
+        loop {
+            let Some(&c) = chars.get(i) else {
+                if in_quotes {
+                    // The line break belongs to the field; carry on into the next line.
+                    line.clear();
+                    if self.read_line(&mut line)? == 0 {
+                        return Err(error(self.row, &fields, CsvErrorKind::UnterminatedQuote));
+                    }
+                    chars = line.chars().collect();
+                    i = 0;
+                    continue;
+                }
+                break;
+            };
+            i += 1;
+            if in_quotes {
+                match (c, chars.get(i)) {
+                    ('"', Some('"')) => {
+                        field.push('"');
+                        i += 1;
+                    }
+                    ('"', _) => {
+                        in_quotes = false;
+                        after_quotes = true;
+                    }
+                    _ => field.push(c),
+                }
+                continue;
+            }
+            match c {
+                ',' => {
+                    fields.push(std::mem::take(&mut field));
+                    after_quotes = false;
+                }
+                '\n' => break,
+                '\r' if chars.get(i) == Some(&'\n') => break,
+                '"' if field.is_empty() && !after_quotes => in_quotes = true,
+                _ if after_quotes => {
+                    return Err(error(self.row, &fields, CsvErrorKind::StrayQuote));
+                }
+                '"' => return Err(error(self.row, &fields, CsvErrorKind::StrayQuote)),
+                _ => field.push(c),
+            }
+        }
+        fields.push(field);
+        Ok(Some(fields))
+    }
+}
@@ -0,0 +1,236 @@
+// A hand-rolled binary encoding for core-types' `Shape`, which Items 1.1 and 1.2 re-export, and
+// for a `Job` record of this crate's own: an id and a name, the fields that its CSV form carries
+// too. It isn't Item 1.1's scheduler `Job`.
+//
+// The format is deliberately tiny so that it can be read off a hex dump by eye:
+//
+// Shape:  tag (1 byte) followed by the fields of the variant as little-endian f64 values
+//         0x01 Rectangle  width: f64, height: f64
+//         0x02 Circle     radius: f64
+//         0x03 Triangle   a: f64, b: f64, c: f64
+// Job:    tag 0x10, id: u64, name: length-prefixed UTF-8 string
+// String: length as little-endian u64, followed by that many bytes of UTF-8
+// List:   count as little-endian u64, followed by that many encoded values
+//
+// Decoding never trusts a length or count read from the input: everything is checked against
+// the number of bytes that are actually available before anything is allocated, so that
+// arbitrary input produces a `DecodeError` rather than a panic or a huge allocation. Lengths
+// are not checked the way `Shape::try_new_*` checks them, so that any shape, NaN sides and all,
+// comes back exactly as it went in.
+
+use std::fmt;
+
+pub mod csv;
+
+pub use core_types::Shape;
+
+pub const RECTANGLE_TAG: u8 = 0x01;
+pub const CIRCLE_TAG: u8 = 0x02;
+pub const TRIANGLE_TAG: u8 = 0x03;
+pub const JOB_TAG: u8 = 0x10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Job {
+    pub id: u64,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input ended before a complete value could be read.
+    Truncated { needed: usize, available: usize },
+    /// The leading tag byte doesn't belong to any known variant.
+    BadTag(u8),
+    /// A length-prefixed string wasn't valid UTF-8.
+    InvalidUtf8(std::str::Utf8Error),
+    /// A complete value was decoded but bytes were left over.
+    TrailingBytes(usize),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Truncated { needed, available } => {
+                write!(
+                    f,
+                    "input truncated: needed {needed} bytes, {available} available"
+                )
+            }
+            DecodeError::BadTag(tag) => write!(f, "unknown tag byte 0x{tag:02x}"),
+            DecodeError::InvalidUtf8(e) => write!(f, "string is not valid UTF-8: {e}"),
+            DecodeError::TrailingBytes(n) => write!(f, "{n} trailing bytes after value"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DecodeError::InvalidUtf8(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// A type that can be written to and read back from the byte format described above.
+pub trait Encoding: Sized {
+    fn to_bytes(&self) -> Vec<u8>;
+
+    /// Decode a value from the front of `bytes`, returning it along with the number of bytes
+    /// consumed. Any bytes after the value are left alone.
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), DecodeError>;
+}
+
+impl Encoding for Shape {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        match self {
+            Shape::Rectangle { width, height } => {
+                out.push(RECTANGLE_TAG);
+                out.extend_from_slice(&width.to_le_bytes());
+                out.extend_from_slice(&height.to_le_bytes());
+            }
+            Shape::Circle { radius } => {
+                out.push(CIRCLE_TAG);
+                out.extend_from_slice(&radius.to_le_bytes());
+            }
+            Shape::Triangle { a, b, c } => {
+                out.push(TRIANGLE_TAG);
+                for side in [a, b, c] {
+                    out.extend_from_slice(&side.to_le_bytes());
+                }
+            }
+        }
+        out
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let mut reader = Reader::new(bytes);
+        let shape = match reader.u8()? {
+            RECTANGLE_TAG => Shape::Rectangle {
+                width: reader.f64()?,
+                height: reader.f64()?,
+            },
+            CIRCLE_TAG => Shape::Circle {
+                radius: reader.f64()?,
+            },
+            TRIANGLE_TAG => Shape::Triangle {
+                a: reader.f64()?,
+                b: reader.f64()?,
+                c: reader.f64()?,
+            },
+            tag => return Err(DecodeError::BadTag(tag)),
+        };
+        Ok((shape, reader.pos))
+    }
+}
+
+impl Encoding for Job {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut out = vec![JOB_TAG];
+        out.extend_from_slice(&self.id.to_le_bytes());
+        out.extend_from_slice(&(self.name.len() as u64).to_le_bytes());
+        out.extend_from_slice(self.name.as_bytes());
+        out
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let mut reader = Reader::new(bytes);
+        match reader.u8()? {
+            JOB_TAG => {}
+            tag => return Err(DecodeError::BadTag(tag)),
+        }
+        let id = reader.u64()?;
+        let name = reader.string()?;
+        Ok((Job { id, name }, reader.pos))
+    }
+}
+
+/// Decode a single value that must occupy the whole of `bytes`.
+pub fn decode_exact<T: Encoding>(bytes: &[u8]) -> Result<T, DecodeError> {
+    let (value, used) = T::from_bytes(bytes)?;
+    match bytes.len() - used {
+        0 => Ok(value),
+        extra => Err(DecodeError::TrailingBytes(extra)),
+    }
+}
+
+/// Encode a list of shapes, prefixed with their count.
+pub fn encode_all(shapes: &[Shape]) -> Vec<u8> {
+    let mut out = (shapes.len() as u64).to_le_bytes().to_vec();
+    for shape in shapes {
+        out.extend(shape.to_bytes());
+    }
+    out
+}
+
+/// Decode a count-prefixed list of shapes, returning them along with the number of bytes
+/// consumed.
+pub fn decode_all(bytes: &[u8]) -> Result<(Vec<Shape>, usize), DecodeError> {
+    let mut reader = Reader::new(bytes);
+    let count = reader.u64()?;
+    // Don't trust `count` for pre-allocation: a hostile prefix could claim billions of shapes.
+    let mut shapes = Vec::new();
+    for _ in 0..count {
+        let (shape, used) = Shape::from_bytes(reader.rest())?;
+        reader.pos += used;
+        shapes.push(shape);
+    }
+    Ok((shapes, reader.pos))
+}
+
+// Cursor over the input that turns every short read into `DecodeError::Truncated`.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Reader { bytes, pos: 0 }
+    }
+
+    fn rest(&self) -> &'a [u8] {
+        &self.bytes[self.pos..]
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8], DecodeError> {
+        let available = self.bytes.len() - self.pos;
+        if n > available {
+            return Err(DecodeError::Truncated {
+                needed: n,
+                available,
+            });
+        }
+        let chunk = &self.bytes[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(chunk)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        let mut out = [0; N];
+        out.copy_from_slice(self.take(N)?);
+        Ok(out)
+    }
+
+    fn u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    fn u64(&mut self) -> Result<u64, DecodeError> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+
+    fn f64(&mut self) -> Result<f64, DecodeError> {
+        Ok(f64::from_le_bytes(self.array()?))
+    }
+
+    fn string(&mut self) -> Result<String, DecodeError> {
+        let len = self.u64()?;
+        // A length that doesn't even fit in `usize` certainly isn't available.
+        let len = usize::try_from(len).unwrap_or(usize::MAX);
+        let raw = self.take(len)?;
+        let s = std::str::from_utf8(raw).map_err(DecodeError::InvalidUtf8)?;
+        Ok(s.to_owned())
+    }
+}
@@ -0,0 +1,66 @@
+use codec::csv::{export_jobs_csv, import_jobs_csv};
+use codec::{decode_all, decode_exact, encode_all, DecodeError, Encoding, Job, Shape};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // ENCODING
+    // Every value starts with a one-byte tag saying which variant follows, so a decoder
+    // can tell a circle from a rectangle before it reads any fields.
+    let circle = Shape::Circle { radius: 1.5 };
+    let bytes = circle.to_bytes();
+    println!("{circle:?} => {bytes:02x?}");
+
+    let job = Job {
+        id: 7,
+        name: "backup".to_owned(),
+    };
+    let bytes = job.to_bytes();
+    println!("{job:?} => {bytes:02x?}");
+
+    // DECODING
+    // `from_bytes` reports how much of the input it used, which is what lets values be
+    // concatenated...
+    let shapes = vec![
+        circle,
+        Shape::Rectangle {
+            width: 3.0,
+            height: 4.0,
+        },
+        Shape::Triangle {
+            a: 3.0,
+            b: 4.0,
+            c: 5.0,
+        },
+    ];
+    let bytes = encode_all(&shapes);
+    let (decoded, used) = decode_all(&bytes)?;
+    assert_eq!(decoded, shapes);
+    assert_eq!(used, bytes.len());
+
+    // ...while `decode_exact` insists that nothing is left over.
+    let mut bytes = job.to_bytes();
+    bytes.push(0xff);
+    match decode_exact::<Job>(&bytes) {
+        Err(DecodeError::TrailingBytes(n)) => println!("rejected {n} trailing byte(s)"),
+        other => println!("unexpected: {other:?}"),
+    }
+
+    // Bad input is an error, never a panic.
+    let err = decode_exact::<Shape>(&[0x02, 0x00]).unwrap_err();
+    println!("decoding a short circle: {err}");
+
+    // CSV
+    // Job batches can also be exchanged as CSV, with awkward names quoted as RFC 4180 asks.
+    let jobs = vec![
+        job,
+        Job {
+            id: 8,
+            name: "say \"hi\", then leave".to_owned(),
+        },
+    ];
+    let mut csv = Vec::new();
+    export_jobs_csv(&jobs, &mut csv)?;
+    print!("{}", String::from_utf8(csv.clone())?.replace("\r\n", "\n"));
+    assert_eq!(import_jobs_csv(csv.as_slice())?, jobs);
+
+    Ok(())
+}
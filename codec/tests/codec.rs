@@ -0,0 +1,374 @@
+use codec::csv::{export_jobs_csv, import_jobs_csv, CsvError};
+use codec::{
+    decode_all, decode_exact, encode_all, DecodeError, Encoding, Job, Shape, CIRCLE_TAG, JOB_TAG,
+    RECTANGLE_TAG, TRIANGLE_TAG,
+};
+use test_support::roundtrip::{check_roundtrip, Codec};
+use test_support::{seeded, Rng};
+
+fn f64(rng: &mut impl Rng) -> f64 {
+    // Arbitrary bit patterns, so NaNs, infinities and subnormals all show up.
+    f64::from_bits(rng.next_u64())
+}
+
+fn shape(rng: &mut impl Rng) -> Shape {
+    match rng.gen_range(0..3) {
+        0 => Shape::Rectangle {
+            width: f64(rng),
+            height: f64(rng),
+        },
+        1 => Shape::Circle { radius: f64(rng) },
+        _ => Shape::Triangle {
+            a: f64(rng),
+            b: f64(rng),
+            c: f64(rng),
+        },
+    }
+}
+
+// Values that compare equal to themselves, unlike NaN, for checks that use `PartialEq`.
+fn comparable_shape(rng: &mut impl Rng) -> Shape {
+    match shape(rng) {
+        Shape::Rectangle { width, height } => Shape::Rectangle {
+            width: if width.is_nan() { 0.0 } else { width },
+            height: if height.is_nan() {
+                f64::INFINITY
+            } else {
+                height
+            },
+        },
+        Shape::Circle { radius } if radius.is_nan() => Shape::Circle { radius: -0.0 },
+        Shape::Triangle { a, b, c } => {
+            let [a, b, c] = [a, b, c].map(|side| if side.is_nan() { 1.0 } else { side });
+            Shape::Triangle { a, b, c }
+        }
+        shape => shape,
+    }
+}
+
+fn job(rng: &mut impl Rng) -> Job {
+    let len = rng.gen_range(0..20);
+    let name = (0..len)
+        .map(|_| char::from_u32(rng.gen_range(0..0x1_0000) as u32).unwrap_or('\u{fffd}'))
+        .collect();
+    Job {
+        id: rng.next_u64(),
+        name,
+    }
+}
+
+fn bytes(rng: &mut impl Rng) -> Vec<u8> {
+    let len = rng.gen_range(0..64);
+    (0..len).map(|_| rng.next_u64() as u8).collect()
+}
+
+const ITERATIONS: usize = 1_000;
+
+#[test]
+fn shape_round_trip() {
+    seeded("shape_round_trip", |rng| {
+        for _ in 0..ITERATIONS {
+            let shape = shape(rng);
+            let bytes = shape.to_bytes();
+            let decoded: Shape = decode_exact(&bytes).unwrap();
+            // Compare re-encoded bytes rather than values so the check is bit-exact even for NaN.
+            assert_eq!(decoded.to_bytes(), bytes, "{shape:?}");
+        }
+    });
+}
+
+#[test]
+fn job_round_trip() {
+    seeded("job_round_trip", |rng| {
+        for _ in 0..ITERATIONS {
+            let job = job(rng);
+            let decoded: Job = decode_exact(&job.to_bytes()).unwrap();
+            assert_eq!(decoded, job);
+        }
+    });
+}
+
+#[test]
+fn encode_all_round_trip() {
+    seeded("encode_all_round_trip", |rng| {
+        for _ in 0..ITERATIONS / 10 {
+            let shapes: Vec<Shape> = (0..rng.gen_range(0..10)).map(|_| shape(rng)).collect();
+            let bytes = encode_all(&shapes);
+            let (decoded, used) = decode_all(&bytes).unwrap();
+            assert_eq!(used, bytes.len());
+            assert_eq!(encode_all(&decoded), bytes);
+        }
+    });
+}
+
+#[test]
+fn circle_fixture() {
+    let bytes = [0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf8, 0x3f];
+    assert_eq!(
+        decode_exact::<Shape>(&bytes),
+        Ok(Shape::Circle { radius: 1.5 })
+    );
+    assert_eq!(Shape::Circle { radius: 1.5 }.to_bytes(), bytes);
+}
+
+#[test]
+fn rectangle_fixture() {
+    let bytes = [
+        0x01, // Rectangle
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x40, // 3.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x40, // 4.0
+    ];
+    let shape = Shape::Rectangle {
+        width: 3.0,
+        height: 4.0,
+    };
+    assert_eq!(decode_exact::<Shape>(&bytes), Ok(shape.clone()));
+    assert_eq!(shape.to_bytes(), bytes);
+}
+
+#[test]
+fn triangle_fixture() {
+    let bytes = [
+        0x03, // Triangle
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x40, // 3.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x40, // 4.0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x40, // 5.0
+    ];
+    let shape = Shape::Triangle {
+        a: 3.0,
+        b: 4.0,
+        c: 5.0,
+    };
+    assert_eq!(decode_exact::<Shape>(&bytes), Ok(shape.clone()));
+    assert_eq!(shape.to_bytes(), bytes);
+}
+
+#[test]
+fn job_fixture() {
+    let bytes = [
+        0x10, // Job
+        0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // id 7
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // name length 2
+        b'o', b'k',
+    ];
+    let job = Job {
+        id: 7,
+        name: "ok".to_owned(),
+    };
+    assert_eq!(decode_exact::<Job>(&bytes), Ok(job.clone()));
+    assert_eq!(job.to_bytes(), bytes);
+}
+
+#[test]
+fn encode_all_fixture() {
+    let bytes = [
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // count 1
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x3f, // Circle radius 1.0
+    ];
+    assert_eq!(encode_all(&[Shape::Circle { radius: 1.0 }]), bytes);
+    assert_eq!(
+        decode_all(&bytes),
+        Ok((vec![Shape::Circle { radius: 1.0 }], bytes.len()))
+    );
+    assert_eq!(encode_all(&[]), [0; 8]);
+}
+
+#[test]
+fn truncated_input() {
+    assert_eq!(
+        decode_exact::<Shape>(&[]),
+        Err(DecodeError::Truncated {
+            needed: 1,
+            available: 0
+        })
+    );
+    assert_eq!(
+        decode_exact::<Shape>(&[0x01, 0x00, 0x00]),
+        Err(DecodeError::Truncated {
+            needed: 8,
+            available: 2
+        })
+    );
+    // A string length that claims more bytes than are present.
+    let mut bytes = Job {
+        id: 1,
+        name: "abc".to_owned(),
+    }
+    .to_bytes();
+    bytes.pop();
+    assert_eq!(
+        decode_exact::<Job>(&bytes),
+        Err(DecodeError::Truncated {
+            needed: 3,
+            available: 2
+        })
+    );
+}
+
+#[test]
+fn every_prefix_is_truncated() {
+    let bytes = Job {
+        id: 99,
+        name: "prefix".to_owned(),
+    }
+    .to_bytes();
+    for len in 0..bytes.len() {
+        assert!(
+            matches!(
+                Job::from_bytes(&bytes[..len]),
+                Err(DecodeError::Truncated { .. })
+            ),
+            "prefix of length {len}"
+        );
+    }
+}
+
+#[test]
+fn bad_tag() {
+    assert_eq!(
+        decode_exact::<Shape>(&[0x7f]),
+        Err(DecodeError::BadTag(0x7f))
+    );
+    // A shape tag is not a job tag.
+    assert_eq!(
+        Job::from_bytes(&Shape::Circle { radius: 1.0 }.to_bytes()),
+        Err(DecodeError::BadTag(0x02))
+    );
+}
+
+#[test]
+fn invalid_utf8() {
+    let bytes = [
+        0x10, 0, 0, 0, 0, 0, 0, 0, 0, // Job id 0
+        0x01, 0, 0, 0, 0, 0, 0, 0, // name length 1
+        0xff,
+    ];
+    assert!(matches!(
+        decode_exact::<Job>(&bytes),
+        Err(DecodeError::InvalidUtf8(_))
+    ));
+}
+
+#[test]
+fn trailing_bytes() {
+    let mut bytes = Shape::Circle { radius: 2.0 }.to_bytes();
+    bytes.extend([0xaa, 0xbb]);
+    assert_eq!(
+        decode_exact::<Shape>(&bytes),
+        Err(DecodeError::TrailingBytes(2))
+    );
+    // `from_bytes` itself is happy to stop early.
+    assert_eq!(
+        Shape::from_bytes(&bytes),
+        Ok((Shape::Circle { radius: 2.0 }, 9))
+    );
+}
+
+#[test]
+fn huge_lengths_do_not_allocate() {
+    let mut bytes = vec![0x10];
+    bytes.extend(0u64.to_le_bytes());
+    bytes.extend(u64::MAX.to_le_bytes());
+    assert!(matches!(
+        decode_exact::<Job>(&bytes),
+        Err(DecodeError::Truncated { .. })
+    ));
+
+    let count = u64::MAX.to_le_bytes();
+    assert!(matches!(
+        decode_all(&count),
+        Err(DecodeError::Truncated { .. })
+    ));
+}
+
+#[test]
+fn random_bytes_never_panic() {
+    seeded("random_bytes_never_panic", |rng| {
+        for _ in 0..ITERATIONS * 10 {
+            let mut bytes = bytes(rng);
+            // Bias towards valid tags so decoding gets past the first byte reasonably often.
+            if let Some(first) = bytes.first_mut() {
+                let tags = [RECTANGLE_TAG, CIRCLE_TAG, TRIANGLE_TAG, JOB_TAG, *first];
+                *first = tags[rng.gen_range(0..5) as usize];
+            }
+            let _ = Shape::from_bytes(&bytes);
+            let _ = Job::from_bytes(&bytes);
+            let _ = decode_exact::<Shape>(&bytes);
+            let _ = decode_exact::<Job>(&bytes);
+            let _ = decode_all(&bytes);
+        }
+    });
+}
+
+// The byte format, for anything that implements `Encoding`.
+struct Binary;
+
+impl<T: Encoding> Codec<T> for Binary {
+    type Error = DecodeError;
+
+    fn encode(&self, value: &T) -> Vec<u8> {
+        value.to_bytes()
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<T, DecodeError> {
+        decode_exact(bytes)
+    }
+}
+
+// `encode_all`, with its count prefix.
+struct ShapeList;
+
+impl Codec<Vec<Shape>> for ShapeList {
+    type Error = DecodeError;
+
+    fn encode(&self, shapes: &Vec<Shape>) -> Vec<u8> {
+        encode_all(shapes)
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<Vec<Shape>, DecodeError> {
+        match decode_all(bytes)? {
+            (shapes, used) if used == bytes.len() => Ok(shapes),
+            (_, used) => Err(DecodeError::TrailingBytes(bytes.len() - used)),
+        }
+    }
+}
+
+struct JobsCsv;
+
+impl Codec<Vec<Job>> for JobsCsv {
+    type Error = CsvError;
+
+    fn encode(&self, jobs: &Vec<Job>) -> Vec<u8> {
+        let mut out = Vec::new();
+        export_jobs_csv(jobs, &mut out).unwrap();
+        out
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<Vec<Job>, CsvError> {
+        import_jobs_csv(bytes)
+    }
+
+    // Cutting a document after any record leaves a valid, shorter one.
+    fn prefixes_are_errors(&self) -> bool {
+        false
+    }
+
+    // Quoting is optional and bare LF line endings are accepted.
+    fn canonical(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn codecs_round_trip() {
+    check_roundtrip("codecs_round_trip/shape", &Binary, comparable_shape);
+    check_roundtrip("codecs_round_trip/job", &Binary, job);
+    check_roundtrip("codecs_round_trip/shape_list", &ShapeList, |rng| {
+        (0..rng.gen_range(0..8))
+            .map(|_| comparable_shape(rng))
+            .collect()
+    });
+    check_roundtrip("codecs_round_trip/jobs_csv", &JobsCsv, |rng| {
+        (0..rng.gen_range(0..5)).map(|_| job(rng)).collect()
+    });
+}
@@ -0,0 +1,117 @@
+use codec::csv::{
+    export_jobs_csv, import_jobs_csv, import_jobs_csv_with_warnings, CsvError, CsvErrorKind,
+};
+use codec::Job;
+
+fn job(id: u64, name: &str) -> Job {
+    Job {
+        id,
+        name: name.to_owned(),
+    }
+}
+
+fn export(jobs: &[Job]) -> String {
+    let mut out = Vec::new();
+    export_jobs_csv(jobs, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+fn import(csv: &str) -> Result<Vec<Job>, CsvError> {
+    import_jobs_csv(csv.as_bytes())
+}
+
+#[test]
+fn export_format() {
+    let csv = export(&[job(1, "plain"), job(2, "a,b"), job(3, r#"he said "hi""#)]);
+    assert_eq!(
+        csv,
+        "id,name\r\n1,plain\r\n2,\"a,b\"\r\n3,\"he said \"\"hi\"\"\"\r\n"
+    );
+    assert_eq!(export(&[]), "id,name\r\n");
+}
+
+#[test]
+fn round_trip_adversarial_names() {
+    let jobs = vec![
+        job(1, "a,b"),
+        job(2, r#"he said ""hi"""#),
+        job(3, "\"leading quote"),
+        job(4, "line\r\nbreak"),
+        job(5, "lone\nnewline"),
+        job(6, ""),
+        job(7, ",,,"),
+        job(u64::MAX, "naïve 🦀"),
+    ];
+    assert_eq!(import(&export(&jobs)).unwrap(), jobs);
+}
+
+#[test]
+fn column_order_and_line_endings() {
+    let jobs = import("name,id\nfirst,1\n\"se,cond\",2\n").unwrap();
+    assert_eq!(jobs, [job(1, "first"), job(2, "se,cond")]);
+    let jobs = import("id,name\r\n1,first\r\n\r\n2,second").unwrap();
+    assert_eq!(jobs, [job(1, "first"), job(2, "second")]);
+}
+
+#[test]
+fn unknown_columns_are_ignored_with_warnings() {
+    let import = import_jobs_csv_with_warnings(
+        "owner,id,name,cpu\nalice,1,backup,3\nbob,2,\"re,index\",4\n".as_bytes(),
+    )
+    .unwrap();
+    assert_eq!(import.jobs, [job(1, "backup"), job(2, "re,index")]);
+    assert_eq!(
+        import.warnings,
+        [
+            "ignoring unknown column \"owner\" (column 1)",
+            "ignoring unknown column \"cpu\" (column 4)"
+        ]
+    );
+}
+
+#[test]
+fn empty_file() {
+    assert_eq!(import("").unwrap(), []);
+    assert_eq!(import("id,name\r\n").unwrap(), []);
+}
+
+#[test]
+fn missing_columns() {
+    let err = import("id,title\n1,x\n").unwrap_err();
+    assert!(matches!(err.kind, CsvErrorKind::MissingColumn("name")));
+    assert_eq!((err.row, err.column), (1, 0));
+    assert_eq!(err.to_string(), "row 1: missing column \"name\"");
+
+    let err = import("id,name\n1,a\n2\n").unwrap_err();
+    assert!(matches!(
+        err.kind,
+        CsvErrorKind::FieldCount {
+            expected: 2,
+            found: 1
+        }
+    ));
+    assert_eq!(err.row, 3);
+}
+
+#[test]
+fn errors_carry_row_and_column() {
+    let err = import("name,id\nok,1\nbad,x1\n").unwrap_err();
+    assert!(matches!(err.kind, CsvErrorKind::InvalidId(_)));
+    assert_eq!((err.row, err.column), (3, 2));
+    assert_eq!(
+        err.to_string(),
+        "row 3, column 2: invalid job id: invalid digit found in string"
+    );
+    assert!(std::error::Error::source(&err).is_some());
+
+    let err = import("id,name\n1,a\"b\n").unwrap_err();
+    assert!(matches!(err.kind, CsvErrorKind::StrayQuote));
+    assert_eq!((err.row, err.column), (2, 2));
+
+    let err = import("id,name\n1,\"ab\"c\n").unwrap_err();
+    assert!(matches!(err.kind, CsvErrorKind::StrayQuote));
+
+    let err = import("id,name\n1,ok\n2,\"never\nclosed\n").unwrap_err();
+    assert!(matches!(err.kind, CsvErrorKind::UnterminatedQuote));
+    assert_eq!((err.row, err.column), (3, 2));
+}
@@ -0,0 +1,99 @@
+// A color as red, green and blue components, each from 0 to 255.
+
+use crate::float::round;
+use core::fmt;
+use core::ops::{Add, Mul};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RgbColor(pub u8, pub u8, pub u8);
+
+// FORMATTING (see Item 2.1)
+// `Debug` for a color is written out so that it shows in hex, as `RgbColor(#ff8800)`, which is
+// how colors are usually read, rather than as three decimal numbers. It goes through
+// `debug_tuple`, as the derived version would, so `{:#?}` still lays it out over several lines
+// inside a struct. `Display` is just the hex, as a user would write it.
+
+impl fmt::Debug for RgbColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RgbColor")
+            .field(&format_args!("{self}"))
+            .finish()
+    }
+}
+
+impl fmt::Display for RgbColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let RgbColor(r, g, b) = self;
+        write!(f, "#{r:02x}{g:02x}{b:02x}")
+    }
+}
+
+/// A color component outside `0..=255`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorRangeError(pub i32);
+
+impl fmt::Display for ColorRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "color component {} is not between 0 and 255", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ColorRangeError {}
+
+// The components used to be `i32`s, which could hold colors that don't exist. This keeps code
+// written against that working, with a check.
+impl TryFrom<(i32, i32, i32)> for RgbColor {
+    type Error = ColorRangeError;
+
+    fn try_from((r, g, b): (i32, i32, i32)) -> Result<Self, Self::Error> {
+        let component = |c: i32| u8::try_from(c).map_err(|_| ColorRangeError(c));
+        Ok(RgbColor(component(r)?, component(g)?, component(b)?))
+    }
+}
+
+// ARITHMETIC (see Item 2.1)
+// Adding two colors mixes their light, component by component, and a component that would go
+// past 255 stays at 255. Multiplying by a number scales the brightness, with the result rounded
+// and held within `0..=255`; a NaN factor gives black.
+
+impl Add for RgbColor {
+    type Output = RgbColor;
+
+    fn add(self, other: RgbColor) -> RgbColor {
+        RgbColor(
+            self.0.saturating_add(other.0),
+            self.1.saturating_add(other.1),
+            self.2.saturating_add(other.2),
+        )
+    }
+}
+
+// `RgbColor` is `Copy`, so this just forwards; it is there so that code holding references,
+// such as a fold over `colors.iter()`, can add them as they are.
+impl Add<&RgbColor> for &RgbColor {
+    type Output = RgbColor;
+
+    fn add(self, other: &RgbColor) -> RgbColor {
+        *self + *other
+    }
+}
+
+impl Mul<f64> for RgbColor {
+    type Output = RgbColor;
+
+    fn mul(self, factor: f64) -> RgbColor {
+        // `as` saturates float to integer conversions, and turns NaN into 0.
+        let scale = |c: u8| round(f64::from(c) * factor) as u8;
+        RgbColor(scale(self.0), scale(self.1), scale(self.2))
+    }
+}
+
+impl Mul<f64> for &RgbColor {
+    type Output = RgbColor;
+
+    fn mul(self, factor: f64) -> RgbColor {
+        *self * factor
+    }
+}
@@ -0,0 +1,24 @@
+// The `f64` methods that `core` doesn't have. With `std` they are the usual ones; without, they
+// come from `libm`, a port of musl's maths library to Rust. IEEE 754 requires `sqrt` to be
+// correctly rounded, and rounding to a whole number is exact, so both give the same answers.
+
+#[cfg(feature = "std")]
+pub(crate) fn sqrt(x: f64) -> f64 {
+    x.sqrt()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn sqrt(x: f64) -> f64 {
+    libm::sqrt(x)
+}
+
+/// Halfway cases round away from zero.
+#[cfg(feature = "std")]
+pub(crate) fn round(x: f64) -> f64 {
+    x.round()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn round(x: f64) -> f64 {
+    libm::round(x)
+}
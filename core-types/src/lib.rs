@@ -0,0 +1,28 @@
+// The parts of Items 1.1 and 1.2 that are pure computation, with nothing that needs an
+// operating system: `Shape`, `RgbColor` and the `Meters`, `Feet` and `Millimeters` lengths.
+// Those items re-export them from where they used to be, and turn on the `std` feature, so
+// nothing changes for their users.
+//
+// Without `std` the crate only has `core`, which is enough for almost all of it: `Display`,
+// `FromStr` and the operator traits are all defined there. What isn't:
+//
+// - `std::error::Error`, for the error types. (`core::error::Error` is the same trait, but only
+//   since Rust 1.81, which an embedded toolchain may not have.)
+// - `String` and `Vec`, which the text form of a shape needs for its errors and for
+//   `parse_shapes`. They could come from `alloc`, but that needs an allocator, and a target
+//   with no heap has none.
+// - `f64::sqrt` and `f64::round`, which `std` gets from the platform's maths library. Here
+//   they come from `float`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod color;
+mod float;
+pub mod shapes;
+pub mod units;
+
+pub use color::{ColorRangeError, RgbColor};
+#[cfg(feature = "std")]
+pub use shapes::{parse_shapes, ParseShapeError};
+pub use shapes::{Shape, ShapeError};
+pub use units::{ConversionError, Feet, Meters, Millimeters};
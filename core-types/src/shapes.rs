@@ -0,0 +1,315 @@
+// Shapes as an enum with fields rather than a class hierarchy: each variant carries exactly the
+// data it needs, and every method is a single `match` that the compiler checks covers all of
+// them. Adding a variant makes every such `match` fail to compile until it handles the newcomer.
+//
+// Shapes also have a one-line text form, written by `Display` and read back by `FromStr`:
+//
+//     rect 3.0x4.5
+//     circle r=2.0
+//     triangle 3.0,4.0,5.0
+//
+// Keywords and the `x` and `r` are read in any case, with spaces allowed around each part, and
+// `r=` may be left out. Lengths are checked as `try_new_*` checks them. Reading needs the `std`
+// feature, for the `String`s in `ParseShapeError`; writing doesn't.
+
+use crate::float::sqrt;
+use core::f64::consts::PI;
+use core::fmt;
+use core::ops::Mul;
+#[cfg(feature = "std")]
+use std::{num::ParseFloatError, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "kind")
+)]
+pub enum Shape {
+    Rectangle {
+        width: f64,
+        height: f64,
+    },
+    Circle {
+        radius: f64,
+    },
+    /// A triangle given by the lengths of its sides.
+    Triangle {
+        a: f64,
+        b: f64,
+        c: f64,
+    },
+}
+
+/// Why a shape couldn't be built.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShapeError {
+    /// A length that is zero, negative, NaN or infinite.
+    InvalidLength(f64),
+    /// One side of a triangle is at least as long as the other two together.
+    TriangleInequality { a: f64, b: f64, c: f64 },
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShapeError::InvalidLength(len) => {
+                write!(f, "length {len} is not a positive, finite number")
+            }
+            ShapeError::TriangleInequality { a, b, c } => {
+                write!(f, "sides {a}, {b} and {c} don't make a triangle")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ShapeError {}
+
+fn check_length(len: f64) -> Result<f64, ShapeError> {
+    if len > 0.0 && len.is_finite() {
+        Ok(len)
+    } else {
+        Err(ShapeError::InvalidLength(len))
+    }
+}
+
+impl Shape {
+    // The variants can be built directly too; these constructors are for lengths that come
+    // from somewhere untrusted, and reject shapes the methods below would give nonsense for.
+
+    pub fn try_new_rectangle(width: f64, height: f64) -> Result<Shape, ShapeError> {
+        Ok(Shape::Rectangle {
+            width: check_length(width)?,
+            height: check_length(height)?,
+        })
+    }
+
+    pub fn try_new_circle(radius: f64) -> Result<Shape, ShapeError> {
+        Ok(Shape::Circle {
+            radius: check_length(radius)?,
+        })
+    }
+
+    /// A triangle with sides `a`, `b` and `c`, each of which must be shorter than the other
+    /// two together; a triangle that has collapsed into a line is rejected.
+    pub fn try_new_triangle(a: f64, b: f64, c: f64) -> Result<Shape, ShapeError> {
+        let (a, b, c) = (check_length(a)?, check_length(b)?, check_length(c)?);
+        if a >= b + c || b >= a + c || c >= a + b {
+            return Err(ShapeError::TriangleInequality { a, b, c });
+        }
+        Ok(Shape::Triangle { a, b, c })
+    }
+
+    // Method
+    pub fn area(&self) -> f64 {
+        match self {
+            Shape::Rectangle { width, height } => width * height,
+            Shape::Circle { radius } => PI * radius * radius,
+            Shape::Triangle { a, b, c } => {
+                // Heron's formula.
+                let s = (a + b + c) / 2.0;
+                sqrt(s * (s - a) * (s - b) * (s - c))
+            }
+        }
+    }
+
+    pub fn perimeter(&self) -> f64 {
+        match self {
+            Shape::Rectangle { width, height } => 2.0 * (width + height),
+            Shape::Circle { radius } => 2.0 * PI * radius,
+            Shape::Triangle { a, b, c } => a + b + c,
+        }
+    }
+
+    /// Multiply every length by `factor`, which multiplies the area by `factor²`.
+    ///
+    /// Panics if `factor` isn't positive and finite.
+    pub fn scale(&mut self, factor: f64) {
+        assert!(
+            factor > 0.0 && factor.is_finite(),
+            "scale factor must be positive and finite, not {factor}"
+        );
+        match self {
+            Shape::Rectangle { width, height } => {
+                *width *= factor;
+                *height *= factor;
+            }
+            Shape::Circle { radius } => *radius *= factor,
+            Shape::Triangle { a, b, c } => {
+                *a *= factor;
+                *b *= factor;
+                *c *= factor;
+            }
+        }
+    }
+
+    /// The smallest axis-aligned rectangle that contains the shape, as a `Shape::Rectangle`.
+    ///
+    /// A triangle only has an orientation once it is placed somewhere; it is taken to lie with
+    /// side `c` along the x axis.
+    pub fn bounding_box(&self) -> Shape {
+        let (width, height) = match *self {
+            Shape::Rectangle { width, height } => (width, height),
+            Shape::Circle { radius } => (2.0 * radius, 2.0 * radius),
+            Shape::Triangle { a, b, c } => {
+                // With corners at (0, 0) and (c, 0), the third corner is `b` from the first
+                // and `a` from the second, at (x, 2 * area / c).
+                let x = (b * b + c * c - a * a) / (2.0 * c);
+                (c.max(x) - x.min(0.0), 2.0 * self.area() / c)
+            }
+        };
+        Shape::Rectangle { width, height }
+    }
+}
+
+// TEXT FORM
+
+// `{:?}` writes a float with enough digits to read back the same number, and always with a
+// decimal point: `3.0` rather than `3`.
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Shape::Rectangle { width, height } => write!(f, "rect {width:?}x{height:?}"),
+            Shape::Circle { radius } => write!(f, "circle r={radius:?}"),
+            Shape::Triangle { a, b, c } => write!(f, "triangle {a:?},{b:?},{c:?}"),
+        }
+    }
+}
+
+/// Why a line of text isn't a shape.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseShapeError {
+    /// There was nothing but whitespace.
+    Empty,
+    /// The first word isn't `rect`, `circle` or `triangle`.
+    UnknownShape(String),
+    /// The named length wasn't given.
+    MissingDimension(&'static str),
+    /// The named length isn't a number.
+    NotANumber {
+        dimension: &'static str,
+        text: String,
+        source: ParseFloatError,
+    },
+    /// The lengths are numbers, but don't make a shape.
+    Invalid(ShapeError),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for ParseShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseShapeError::Empty => write!(f, "no shape given"),
+            ParseShapeError::UnknownShape(name) => write!(f, "unknown shape {name:?}"),
+            ParseShapeError::MissingDimension(dimension) => write!(f, "missing {dimension}"),
+            ParseShapeError::NotANumber {
+                dimension, text, ..
+            } => write!(f, "{dimension} {text:?} is not a number"),
+            ParseShapeError::Invalid(_) => write!(f, "not a valid shape"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseShapeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseShapeError::NotANumber { source, .. } => Some(source),
+            ParseShapeError::Invalid(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<ShapeError> for ParseShapeError {
+    fn from(e: ShapeError) -> Self {
+        ParseShapeError::Invalid(e)
+    }
+}
+
+// One length out of `part`, which is missing if it's blank.
+#[cfg(feature = "std")]
+fn dimension(part: Option<&str>, dimension: &'static str) -> Result<f64, ParseShapeError> {
+    let text = part
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+        .ok_or(ParseShapeError::MissingDimension(dimension))?;
+    text.parse().map_err(|source| ParseShapeError::NotANumber {
+        dimension,
+        text: text.to_owned(),
+        source,
+    })
+}
+
+#[cfg(feature = "std")]
+impl FromStr for Shape {
+    type Err = ParseShapeError;
+
+    fn from_str(s: &str) -> Result<Shape, ParseShapeError> {
+        let s = s.trim();
+        let (keyword, rest) = s.split_once(char::is_whitespace).unwrap_or((s, ""));
+        match keyword.to_ascii_lowercase().as_str() {
+            "" => Err(ParseShapeError::Empty),
+            "rect" => {
+                let mut parts = rest.splitn(2, ['x', 'X']);
+                let width = dimension(parts.next(), "width")?;
+                let height = dimension(parts.next(), "height")?;
+                Ok(Shape::try_new_rectangle(width, height)?)
+            }
+            "circle" => {
+                let rest = rest.trim_start();
+                let radius = match rest.strip_prefix(['r', 'R']) {
+                    Some(after) if after.trim_start().starts_with('=') => {
+                        after.trim_start()[1..].to_owned()
+                    }
+                    _ => rest.to_owned(),
+                };
+                Ok(Shape::try_new_circle(dimension(Some(&radius), "radius")?)?)
+            }
+            "triangle" => {
+                let mut parts = rest.splitn(3, ',');
+                let a = dimension(parts.next(), "side a")?;
+                let b = dimension(parts.next(), "side b")?;
+                let c = dimension(parts.next(), "side c")?;
+                Ok(Shape::try_new_triangle(a, b, c)?)
+            }
+            _ => Err(ParseShapeError::UnknownShape(keyword.to_owned())),
+        }
+    }
+}
+
+/// One shape per line of `input`, skipping blank lines. The error comes with the number of
+/// the line it is on, counting from 1.
+#[cfg(feature = "std")]
+pub fn parse_shapes(input: &str) -> Result<Vec<Shape>, (usize, ParseShapeError)> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| line.parse().map_err(|e| (i + 1, e)))
+        .collect()
+}
+
+// `shape * k` is a scaled copy of `shape`, as if by `scale`, and panics on the same factors.
+// `Shape` isn't `Copy`, so without `&shape * k` a loop over `&shapes` would have to clone each
+// shape itself just to multiply it, or else consume the whole collection.
+
+impl Mul<f64> for Shape {
+    type Output = Shape;
+
+    fn mul(mut self, factor: f64) -> Shape {
+        self.scale(factor);
+        self
+    }
+}
+
+impl Mul<f64> for &Shape {
+    type Output = Shape;
+
+    fn mul(self, factor: f64) -> Shape {
+        self.clone() * factor
+    }
+}
@@ -0,0 +1,156 @@
+//! Lengths in three units, with conversions between them written as `From` where every value
+//! has an answer and `TryFrom` where some don't.
+//!
+//! Only feet to meters always works: it shrinks the number, so it can't overflow. Meters to
+//! feet grows it, and the largest `f64`s have no answer in feet. `Millimeters` count whole
+//! millimeters in a `u64`, which leaves out negative lengths, NaN and infinity, and lengths
+//! too long to count. Going the other way, an `f64` stops being able to tell consecutive
+//! integers apart above 2<sup>53</sup>, which is also why the standard library has no
+//! `From<u64> for f64`; here, those counts are refused rather than rounded.
+
+use crate::float::round;
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Meters(pub f64);
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Feet(pub f64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Millimeters(pub u64);
+
+/// How many meters make a foot, exactly, by definition.
+pub const METERS_PER_FOOT: f64 = 0.3048;
+
+/// The largest count of millimeters that can become meters or feet. Above it, an `f64` can't
+/// hold every integer, so two different counts could come out as the same length.
+pub const MAX_EXACT_MILLIMETERS: u64 = 1 << 53;
+
+/// A length that has no equivalent in the unit it was being converted to. Each variant holds
+/// the value that couldn't be converted, in the unit it was given in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConversionError {
+    Negative {
+        unit: &'static str,
+        value: f64,
+    },
+    /// NaN or infinite.
+    NotFinite {
+        unit: &'static str,
+        value: f64,
+    },
+    /// Larger than the other unit can hold.
+    TooLarge {
+        unit: &'static str,
+        value: f64,
+    },
+    /// More than `MAX_EXACT_MILLIMETERS`.
+    Inexact(Millimeters),
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::Negative { unit, value } => write!(f, "{value} {unit} is negative"),
+            ConversionError::NotFinite { unit, value } => {
+                write!(f, "{value} {unit} is not a finite length")
+            }
+            ConversionError::TooLarge { unit, value } => write!(f, "{value} {unit} is too long"),
+            ConversionError::Inexact(Millimeters(mm)) => write!(
+                f,
+                "{mm} mm is more than the {MAX_EXACT_MILLIMETERS} mm that can be converted exactly"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
+
+impl From<Feet> for Meters {
+    fn from(feet: Feet) -> Self {
+        Meters(feet.0 * METERS_PER_FOOT)
+    }
+}
+
+impl TryFrom<Meters> for Feet {
+    type Error = ConversionError;
+
+    /// Fails only for finite lengths too long to be given in feet. NaN and infinity stay as
+    /// they are.
+    fn try_from(meters: Meters) -> Result<Self, Self::Error> {
+        let feet = meters.0 / METERS_PER_FOOT;
+        if feet.is_infinite() && meters.0.is_finite() {
+            return Err(ConversionError::TooLarge {
+                unit: "m",
+                value: meters.0,
+            });
+        }
+        Ok(Feet(feet))
+    }
+}
+
+impl TryFrom<Meters> for Millimeters {
+    type Error = ConversionError;
+
+    /// Rounds to the nearest millimeter.
+    fn try_from(meters: Meters) -> Result<Self, Self::Error> {
+        to_millimeters("m", meters.0, 1000.0)
+    }
+}
+
+impl TryFrom<Feet> for Millimeters {
+    type Error = ConversionError;
+
+    /// Rounds to the nearest millimeter.
+    fn try_from(feet: Feet) -> Result<Self, Self::Error> {
+        to_millimeters("ft", feet.0, METERS_PER_FOOT * 1000.0)
+    }
+}
+
+impl TryFrom<Millimeters> for Meters {
+    type Error = ConversionError;
+
+    fn try_from(mm: Millimeters) -> Result<Self, Self::Error> {
+        Ok(Meters(exact_f64(mm)? / 1000.0))
+    }
+}
+
+impl TryFrom<Millimeters> for Feet {
+    type Error = ConversionError;
+
+    fn try_from(mm: Millimeters) -> Result<Self, Self::Error> {
+        Ok(Feet(exact_f64(mm)? / (METERS_PER_FOOT * 1000.0)))
+    }
+}
+
+fn to_millimeters(
+    unit: &'static str,
+    value: f64,
+    mm_per_unit: f64,
+) -> Result<Millimeters, ConversionError> {
+    // 2^64, the first `f64` that a `u64` can't hold. `u64::MAX as f64` would round to the
+    // same number, but this says what it is.
+    const LIMIT: f64 = 18_446_744_073_709_551_616.0;
+    if !value.is_finite() {
+        return Err(ConversionError::NotFinite { unit, value });
+    }
+    if value < 0.0 {
+        return Err(ConversionError::Negative { unit, value });
+    }
+    let mm = round(value * mm_per_unit);
+    if mm >= LIMIT {
+        return Err(ConversionError::TooLarge { unit, value });
+    }
+    // In range, whole and not negative, so the cast changes nothing.
+    Ok(Millimeters(mm as u64))
+}
+
+fn exact_f64(mm: Millimeters) -> Result<f64, ConversionError> {
+    if mm.0 > MAX_EXACT_MILLIMETERS {
+        return Err(ConversionError::Inexact(mm));
+    }
+    // Exact, from the check above.
+    Ok(mm.0 as f64)
+}
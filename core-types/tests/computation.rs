@@ -0,0 +1,116 @@
+// Everything here works without `std`, and is checked against exact answers, so that both
+// float backends are held to the same ones: `cargo test -p core-types` tests `libm`, and
+// `cargo test --workspace` has the items turn `std` on.
+
+use core_types::{ConversionError, Feet, Meters, Millimeters, RgbColor, Shape, ShapeError};
+use std::f64::consts::PI;
+
+#[test]
+fn areas_and_perimeters() {
+    let rect = Shape::try_new_rectangle(3.0, 4.5).unwrap();
+    assert_eq!((rect.area(), rect.perimeter()), (13.5, 15.0));
+    let circle = Shape::try_new_circle(2.0).unwrap();
+    assert_eq!((circle.area(), circle.perimeter()), (4.0 * PI, 4.0 * PI));
+    let triangle = Shape::try_new_triangle(3.0, 4.0, 5.0).unwrap();
+    assert_eq!((triangle.area(), triangle.perimeter()), (6.0, 12.0));
+    // Heron's formula, through `sqrt`, on a triangle whose area isn't a whole number.
+    let equilateral = Shape::try_new_triangle(2.0, 2.0, 2.0).unwrap();
+    assert_eq!(equilateral.area(), 3.0f64.sqrt());
+}
+
+#[test]
+fn invalid_shapes_are_refused() {
+    assert_eq!(
+        Shape::try_new_circle(-1.0),
+        Err(ShapeError::InvalidLength(-1.0))
+    );
+    assert_eq!(
+        Shape::try_new_triangle(1.0, 2.0, 3.0),
+        Err(ShapeError::TriangleInequality {
+            a: 1.0,
+            b: 2.0,
+            c: 3.0
+        })
+    );
+}
+
+#[test]
+fn scales_and_bounds() {
+    let triangle = Shape::try_new_triangle(3.0, 4.0, 5.0).unwrap();
+    assert_eq!(
+        triangle.bounding_box(),
+        Shape::Rectangle {
+            width: 5.0,
+            height: 2.4
+        }
+    );
+    assert_eq!(
+        &triangle * 2.0,
+        Shape::Triangle {
+            a: 6.0,
+            b: 8.0,
+            c: 10.0
+        }
+    );
+}
+
+#[test]
+fn colors_mix_and_scale() {
+    assert_eq!(
+        RgbColor(200, 10, 0) + RgbColor(100, 10, 0),
+        RgbColor(255, 20, 0)
+    );
+    // 127.5 rounds away from zero.
+    assert_eq!(RgbColor(255, 3, 1) * 0.5, RgbColor(128, 2, 1));
+    assert_eq!(RgbColor(1, 2, 3) * f64::NAN, RgbColor(0, 0, 0));
+    assert_eq!(RgbColor::try_from((0, 136, 255)), Ok(RgbColor(0, 136, 255)));
+}
+
+#[test]
+fn lengths_convert() {
+    assert_eq!(Meters::from(Feet(10.0)), Meters(3.048));
+    // Rounded to the nearest millimeter, with halves going up.
+    assert_eq!(Millimeters::try_from(Meters(1.2345)), Ok(Millimeters(1235)));
+    assert_eq!(Millimeters::try_from(Meters(0.0005)), Ok(Millimeters(1)));
+    assert_eq!(Millimeters::try_from(Feet(1.0)), Ok(Millimeters(305)));
+    assert_eq!(
+        Millimeters::try_from(Meters(-0.1)),
+        Err(ConversionError::Negative {
+            unit: "m",
+            value: -0.1
+        })
+    );
+}
+
+#[test]
+fn formats_without_std() {
+    // `Display` only needs `core`, so it is always there; this writes into a fixed buffer, as
+    // an embedded program without `String` would.
+    struct Buffer([u8; 64], usize);
+
+    impl std::fmt::Write for Buffer {
+        fn write_str(&mut self, s: &str) -> std::fmt::Result {
+            let end = self.1 + s.len();
+            self.0
+                .get_mut(self.1..end)
+                .ok_or(std::fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.1 = end;
+            Ok(())
+        }
+    }
+
+    use std::fmt::Write;
+    let mut buffer = Buffer([0; 64], 0);
+    write!(
+        buffer,
+        "{} {:?}",
+        Shape::Circle { radius: 1.5 },
+        RgbColor(255, 136, 0)
+    )
+    .unwrap();
+    assert_eq!(
+        std::str::from_utf8(&buffer.0[..buffer.1]),
+        Ok("circle r=1.5 RgbColor(#ff8800)")
+    );
+}
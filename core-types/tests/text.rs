@@ -0,0 +1,51 @@
+// What the `std` feature adds: the text form of shapes, and `Error` impls.
+
+use core_types::{
+    parse_shapes, ColorRangeError, ConversionError, Millimeters, ParseShapeError, Shape, ShapeError,
+};
+use std::error::Error;
+
+#[test]
+fn shapes_read_back_what_they_write() {
+    let shapes = parse_shapes("rect 3.0x4.5\n\ncircle r=2.0\ntriangle 3.0,4.0,5.0\n").unwrap();
+    let written: Vec<String> = shapes.iter().map(Shape::to_string).collect();
+    assert_eq!(
+        written,
+        ["rect 3.0x4.5", "circle r=2.0", "triangle 3.0,4.0,5.0"]
+    );
+    for (shape, text) in shapes.iter().zip(&written) {
+        assert_eq!(&text.parse::<Shape>().unwrap(), shape);
+    }
+}
+
+#[test]
+fn parse_errors_keep_their_cause() {
+    let err = "triangle 1,2,3".parse::<Shape>().unwrap_err();
+    assert!(matches!(
+        err,
+        ParseShapeError::Invalid(ShapeError::TriangleInequality { .. })
+    ));
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "sides 1, 2 and 3 don't make a triangle"
+    );
+    assert_eq!(parse_shapes("circle 1\nsquare 2").unwrap_err().0, 2);
+}
+
+#[test]
+fn errors_are_errors() {
+    let errors: [Box<dyn Error>; 3] = [
+        Box::new(ShapeError::InvalidLength(0.0)),
+        Box::new(ColorRangeError(256)),
+        Box::new(ConversionError::Inexact(Millimeters(u64::MAX))),
+    ];
+    let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "length 0 is not a positive, finite number",
+            "color component 256 is not between 0 and 255",
+            "18446744073709551615 mm is more than the 9007199254740992 mm that can be converted exactly",
+        ]
+    );
+}
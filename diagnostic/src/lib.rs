@@ -0,0 +1,89 @@
+// Rendering of parse errors against the input they came from, pointing at the offending text:
+//
+// error: unclosed character class
+//  --> 1:3
+//   |
+// 1 | ab[cd
+//   |   ^^^
+//
+// Errors opt in by implementing `Spanned`, saying which bytes of the input they are about.
+// Columns and carets count `char`s rather than bytes, so multi-byte characters before or inside
+// the span don't throw the underline off. (Characters that display double-width, like most
+// emoji, will still skew it; that would need a Unicode width table.)
+
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+/// An error that knows which part of its input it is about.
+pub trait Spanned: Error {
+    /// Byte range into the input that the error refers to, if it refers to a particular place.
+    fn span(&self) -> Option<Range<usize>>;
+}
+
+pub struct SourceDiagnostic<'a, E: ?Sized> {
+    source: &'a str,
+    error: &'a E,
+}
+
+impl<'a, E: Spanned + ?Sized> SourceDiagnostic<'a, E> {
+    pub fn new(source: &'a str, error: &'a E) -> Self {
+        SourceDiagnostic { source, error }
+    }
+}
+
+/// Render `error` against `source` in one go.
+pub fn render<E: Spanned + ?Sized>(source: &str, error: &E) -> String {
+    SourceDiagnostic::new(source, error).to_string()
+}
+
+/// 1-based line and column (in `char`s) of the byte offset `pos` in `source`.
+pub fn line_col(source: &str, pos: usize) -> (usize, usize) {
+    let pos = floor_char_boundary(source, pos);
+    let line_start = source[..pos].rfind('\n').map_or(0, |i| i + 1);
+    let line = source[..line_start].matches('\n').count() + 1;
+    let col = source[line_start..pos].chars().count() + 1;
+    (line, col)
+}
+
+// Clamp `pos` into `source` and back it up to the start of the character it falls in, so a
+// slightly off span can't make slicing panic.
+fn floor_char_boundary(source: &str, pos: usize) -> usize {
+    let mut pos = pos.min(source.len());
+    while !source.is_char_boundary(pos) {
+        pos -= 1;
+    }
+    pos
+}
+
+impl<E: Spanned + ?Sized> fmt::Display for SourceDiagnostic<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "error: {}", self.error)?;
+        let Some(span) = self.error.span() else {
+            return Ok(());
+        };
+        let source = self.source;
+        let start = floor_char_boundary(source, span.start);
+        let end = floor_char_boundary(source, span.end.max(span.start));
+
+        let (line, col) = line_col(source, start);
+        let line_start = source[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[start..]
+            .find('\n')
+            .map_or(source.len(), |i| start + i);
+        let text = source[line_start..line_end].trim_end_matches('\r');
+
+        // Underline as much of the span as is on this line, and at least one character so
+        // that an empty span (such as "unexpected end of input") is still visible.
+        let width = source[start..end.min(line_start + text.len()).max(start)]
+            .chars()
+            .count()
+            .max(1);
+
+        let gutter = " ".repeat(line.to_string().len());
+        writeln!(f, "{gutter}--> {line}:{col}")?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line} | {text}")?;
+        writeln!(f, "{gutter} | {}{}", " ".repeat(col - 1), "^".repeat(width))
+    }
+}
@@ -0,0 +1,149 @@
+use diagnostic::{line_col, render, Spanned};
+use std::fmt;
+use std::ops::Range;
+
+#[derive(Debug)]
+struct TestError {
+    message: &'static str,
+    span: Option<Range<usize>>,
+}
+
+impl fmt::Display for TestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message)
+    }
+}
+
+impl std::error::Error for TestError {}
+
+impl Spanned for TestError {
+    fn span(&self) -> Option<Range<usize>> {
+        self.span.clone()
+    }
+}
+
+fn error(message: &'static str, span: Range<usize>) -> TestError {
+    TestError {
+        message,
+        span: Some(span),
+    }
+}
+
+#[test]
+fn mid_line_error() {
+    let source = "circle r=2\nrect 3xfour\ncircle r=1";
+    let start = source.find("four").unwrap();
+    assert_eq!(
+        render(source, &error("invalid number", start..start + 4)),
+        "error: invalid number
+ --> 2:8
+  |
+2 | rect 3xfour
+  |        ^^^^
+"
+    );
+}
+
+#[test]
+fn error_at_end_of_input() {
+    assert_eq!(
+        render("rect 3x", &error("missing height", 7..7)),
+        "error: missing height
+ --> 1:8
+  |
+1 | rect 3x
+  |        ^
+"
+    );
+}
+
+#[test]
+fn span_covering_several_characters() {
+    assert_eq!(
+        render("1 + foo * 2", &error("unknown variable", 4..7)),
+        "error: unknown variable
+ --> 1:5
+  |
+1 | 1 + foo * 2
+  |     ^^^
+"
+    );
+}
+
+#[test]
+fn multi_byte_characters_keep_carets_aligned() {
+    let source = "naïve ünïcödé [";
+    let start = source.find('[').unwrap();
+    assert_eq!(
+        render(source, &error("unclosed", start..source.len())),
+        "error: unclosed
+ --> 1:15
+  |
+1 | naïve ünïcödé [
+  |               ^
+"
+    );
+    // Three characters, six bytes: still three carets.
+    let start = source.find("ünï").unwrap();
+    let end = start + "ünï".len();
+    assert!(render(source, &error("x", start..end)).ends_with("  |       ^^^\n"));
+}
+
+#[test]
+fn span_running_past_the_line_is_cut_at_the_line_end() {
+    let source = "ab[cd\r\nnext line";
+    assert_eq!(
+        render(source, &error("unclosed", 2..source.len())),
+        "error: unclosed
+ --> 1:3
+  |
+1 | ab[cd
+  |   ^^^
+"
+    );
+}
+
+#[test]
+fn gutter_widens_with_the_line_number() {
+    let source = "x\n".repeat(11) + "bad";
+    let start = source.find("bad").unwrap();
+    assert_eq!(
+        render(&source, &error("oops", start..start + 3)),
+        "error: oops
+  --> 12:1
+   |
+12 | bad
+   | ^^^
+"
+    );
+}
+
+#[test]
+fn no_span_renders_just_the_message() {
+    let error = TestError {
+        message: "something went wrong",
+        span: None,
+    };
+    assert_eq!(render("input", &error), "error: something went wrong\n");
+}
+
+#[test]
+fn bad_spans_do_not_panic() {
+    let source = "é";
+    let _ = render(source, &error("inside a char", 1..2));
+    let _ = render(source, &error("past the end", 10..20));
+    #[allow(clippy::reversed_empty_ranges)]
+    let _ = render(source, &error("backwards", 2..0));
+}
+
+#[test]
+fn line_and_column() {
+    let source = "ab\ncdé\r\nf";
+    assert_eq!(line_col(source, 0), (1, 1));
+    assert_eq!(line_col(source, 2), (1, 3));
+    assert_eq!(line_col(source, 3), (2, 1));
+    assert_eq!(line_col(source, 7), (2, 4));
+    assert_eq!(line_col(source, source.len()), (3, 2));
+    // Inside `é` counts as its start.
+    assert_eq!(line_col(source, 6), (2, 3));
+}
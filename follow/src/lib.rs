@@ -0,0 +1,155 @@
+// A `tail -f` style reader: yields complete lines as they are appended to a file.
+//
+// Each poll reopens the file, seeks to where the previous poll stopped and reads whatever is
+// new. Only complete lines are emitted; a trailing fragment without its `\n` is kept back until
+// the rest of it arrives. Both `\n` and `\r\n` endings are stripped.
+//
+// If the file is found to be shorter than the saved offset it has been truncated or replaced
+// (log rotation), so reading restarts from the beginning and a `FollowEvent::Rotated` is
+// emitted first. A file that is replaced by a *longer* one between two polls can't be told
+// apart from one that was appended to; polling often enough makes that unlikely in practice.
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FollowEvent {
+    Line(String),
+    /// The file was truncated or replaced; lines after this come from its start.
+    Rotated,
+}
+
+/// How the reader waits between polls. Tests substitute one that doesn't really sleep.
+pub trait Sleeper {
+    fn sleep(&mut self, duration: Duration);
+}
+
+/// Sleeps the current thread.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ThreadSleeper;
+
+impl Sleeper for ThreadSleeper {
+    fn sleep(&mut self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+impl<F: FnMut(Duration)> Sleeper for F {
+    fn sleep(&mut self, duration: Duration) {
+        self(duration)
+    }
+}
+
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+pub struct FollowReader<S = ThreadSleeper> {
+    path: PathBuf,
+    offset: u64,
+    // Bytes after the last `\n` seen, waiting for the rest of their line.
+    partial: Vec<u8>,
+    pending: VecDeque<FollowEvent>,
+    poll_interval: Duration,
+    sleeper: S,
+}
+
+impl FollowReader {
+    /// Follow `path` from its beginning, polling every [`DEFAULT_POLL_INTERVAL`].
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        FollowReader::with_sleeper(path, DEFAULT_POLL_INTERVAL, ThreadSleeper)
+    }
+}
+
+impl<S: Sleeper> FollowReader<S> {
+    pub fn with_sleeper(path: impl AsRef<Path>, poll_interval: Duration, sleeper: S) -> Self {
+        FollowReader {
+            path: path.as_ref().to_owned(),
+            offset: 0,
+            partial: Vec::new(),
+            pending: VecDeque::new(),
+            poll_interval,
+            sleeper,
+        }
+    }
+
+    /// Check the file once, without waiting, and return any new events.
+    ///
+    /// A missing file is treated as empty, since during rotation there is often a moment when
+    /// the old file has been moved away and the new one not yet created.
+    pub fn poll(&mut self) -> io::Result<Vec<FollowEvent>> {
+        self.fill()?;
+        Ok(self.pending.drain(..).collect())
+    }
+
+    /// Feed events to `callback` as they arrive, until it returns `true`.
+    pub fn follow_until(
+        &mut self,
+        mut callback: impl FnMut(&FollowEvent) -> bool,
+    ) -> io::Result<()> {
+        loop {
+            let event = self.next_event()?;
+            if callback(&event) {
+                return Ok(());
+            }
+        }
+    }
+
+    // Block until there is an event to hand out.
+    fn next_event(&mut self) -> io::Result<FollowEvent> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Ok(event);
+            }
+            self.fill()?;
+            if self.pending.is_empty() {
+                self.sleeper.sleep(self.poll_interval);
+            }
+        }
+    }
+
+    // Read whatever is new in the file into `pending`.
+    fn fill(&mut self) -> io::Result<()> {
+        let mut file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e),
+        };
+        if file.metadata()?.len() < self.offset {
+            self.offset = 0;
+            self.partial.clear();
+            self.pending.push_back(FollowEvent::Rotated);
+        }
+        file.seek(SeekFrom::Start(self.offset))?;
+        let before = self.partial.len();
+        if let Err(e) = file.read_to_end(&mut self.partial) {
+            // Forget a half-finished read so the next poll doesn't see those bytes twice.
+            self.partial.truncate(before);
+            return Err(e);
+        }
+        self.offset += (self.partial.len() - before) as u64;
+
+        let mut start = 0;
+        while let Some(len) = self.partial[start..].iter().position(|&b| b == b'\n') {
+            let mut line = &self.partial[start..start + len];
+            if let Some(stripped) = line.strip_suffix(b"\r") {
+                line = stripped;
+            }
+            let line = String::from_utf8_lossy(line).into_owned();
+            self.pending.push_back(FollowEvent::Line(line));
+            start += len + 1;
+        }
+        self.partial.drain(..start);
+        Ok(())
+    }
+}
+
+/// Blocks until the next event; never ends on its own.
+impl<S: Sleeper> Iterator for FollowReader<S> {
+    type Item = io::Result<FollowEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_event())
+    }
+}
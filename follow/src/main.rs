@@ -0,0 +1,42 @@
+use follow::{FollowEvent, FollowReader, ThreadSleeper};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::thread;
+use std::time::Duration;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::env::temp_dir().join(format!("follow-demo-{}.log", std::process::id()));
+    std::fs::write(&path, "")?;
+
+    // A writer appending to the "log" in the background, including a line that arrives in
+    // two pieces and a rotation halfway through.
+    let writer_path = path.clone();
+    let writer = thread::spawn(move || -> std::io::Result<()> {
+        let pause = || thread::sleep(Duration::from_millis(20));
+        let mut log = OpenOptions::new().append(true).open(&writer_path)?;
+        log.write_all(b"starting up\n")?;
+        pause();
+        log.write_all(b"half a ")?;
+        pause();
+        log.write_all(b"line\r\n")?;
+        pause();
+        std::fs::write(&writer_path, "rotated\n")?;
+        pause();
+        let mut log = OpenOptions::new().append(true).open(&writer_path)?;
+        log.write_all(b"done\n")
+    });
+
+    // The callback form: stop as soon as the writer says it's finished.
+    let mut reader = FollowReader::with_sleeper(&path, Duration::from_millis(5), ThreadSleeper);
+    reader.follow_until(|event| {
+        match event {
+            FollowEvent::Line(line) => println!("line: {line}"),
+            FollowEvent::Rotated => println!("-- file rotated --"),
+        }
+        *event == FollowEvent::Line("done".to_owned())
+    })?;
+
+    writer.join().expect("writer panicked")?;
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
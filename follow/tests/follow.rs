@@ -0,0 +1,120 @@
+use follow::{FollowEvent, FollowReader, ThreadSleeper};
+use std::cell::RefCell;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::rc::Rc;
+use std::thread;
+use std::time::Duration;
+use test_support::temp_file::TempFile;
+
+fn line(s: &str) -> FollowEvent {
+    FollowEvent::Line(s.to_owned())
+}
+
+#[test]
+fn reads_existing_and_appended_lines() {
+    let file = TempFile::new("one\ntwo\n");
+    let mut reader = FollowReader::new(file.path());
+    assert_eq!(reader.poll().unwrap(), [line("one"), line("two")]);
+    assert_eq!(reader.poll().unwrap(), []);
+    file.append("three\n");
+    assert_eq!(reader.poll().unwrap(), [line("three")]);
+}
+
+#[test]
+fn partial_line_is_held_back_until_complete() {
+    let file = TempFile::new("hel");
+    let mut reader = FollowReader::new(file.path());
+    assert_eq!(reader.poll().unwrap(), []);
+    file.append("lo");
+    assert_eq!(reader.poll().unwrap(), []);
+    file.append(" world\nnext");
+    assert_eq!(reader.poll().unwrap(), [line("hello world")]);
+    file.append("\n");
+    assert_eq!(reader.poll().unwrap(), [line("next")]);
+}
+
+#[test]
+fn crlf_endings_are_stripped() {
+    let file = TempFile::new("a\r\nb\r");
+    let mut reader = FollowReader::new(file.path());
+    assert_eq!(reader.poll().unwrap(), [line("a")]);
+    // The `\r` on its own isn't a line ending; it's kept until the `\n` arrives.
+    file.append("\nc\r\r\n");
+    assert_eq!(reader.poll().unwrap(), [line("b"), line("c\r")]);
+}
+
+#[test]
+fn rotation_restarts_from_the_beginning() {
+    let file = TempFile::new("old line one\nold line two\nunfinished");
+    let mut reader = FollowReader::new(file.path());
+    assert_eq!(
+        reader.poll().unwrap(),
+        [line("old line one"), line("old line two")]
+    );
+
+    std::fs::write(file.path(), "new\n").unwrap();
+    // The unfinished fragment from the old file is dropped, not glued onto the new content.
+    assert_eq!(reader.poll().unwrap(), [FollowEvent::Rotated, line("new")]);
+    file.append("more\n");
+    assert_eq!(reader.poll().unwrap(), [line("more")]);
+}
+
+#[test]
+fn missing_file_is_empty_until_created() {
+    let file = TempFile::new("");
+    std::fs::remove_file(file.path()).unwrap();
+    let mut reader = FollowReader::new(file.path());
+    assert_eq!(reader.poll().unwrap(), []);
+    std::fs::write(file.path(), "hello\n").unwrap();
+    assert_eq!(reader.poll().unwrap(), [line("hello")]);
+}
+
+#[test]
+fn iterator_waits_using_the_injected_sleeper() {
+    let file = Rc::new(TempFile::new("first\n"));
+    let sleeps = Rc::new(RefCell::new(Vec::new()));
+
+    // Each "sleep" appends the next piece of input instead of actually waiting.
+    let mut script = vec!["sec", "ond\n", "", "third\n"].into_iter();
+    let (f, s) = (file.clone(), sleeps.clone());
+    let sleeper = move |d: Duration| {
+        s.borrow_mut().push(d);
+        f.append(script.next().expect("reader slept more than expected"));
+    };
+
+    let reader = FollowReader::with_sleeper(file.path(), Duration::from_millis(40), sleeper);
+    let events: Vec<_> = reader.take(3).map(Result::unwrap).collect();
+    assert_eq!(events, [line("first"), line("second"), line("third")]);
+    assert_eq!(*sleeps.borrow(), [Duration::from_millis(40); 4]);
+}
+
+#[test]
+fn follow_until_with_a_writer_thread() {
+    let file = TempFile::new("");
+    let path = file.path().to_owned();
+    let writer = thread::spawn(move || {
+        let mut log = OpenOptions::new().append(true).open(&path).unwrap();
+        for i in 0..20 {
+            write!(log, "line {i}").unwrap();
+            thread::sleep(Duration::from_millis(1));
+            writeln!(log).unwrap();
+        }
+        writeln!(log, "stop").unwrap();
+    });
+
+    let mut seen = Vec::new();
+    let mut reader =
+        FollowReader::with_sleeper(file.path(), Duration::from_millis(1), ThreadSleeper);
+    reader
+        .follow_until(|event| {
+            seen.push(event.clone());
+            *event == line("stop")
+        })
+        .unwrap();
+    writer.join().unwrap();
+
+    let mut expected: Vec<_> = (0..20).map(|i| line(&format!("line {i}"))).collect();
+    expected.push(line("stop"));
+    assert_eq!(seen, expected);
+}
@@ -0,0 +1,243 @@
+// Wildcard glob matching, as used by shells for file names:
+//
+//   *       any run of characters, including none
+//   ?       exactly one character
+//   [abc]   one of the listed characters; ranges like [a-z] are allowed
+//   [!abc]  any one character except those listed
+//
+// Matching works on `char`s rather than bytes, so `?` matches "é" as a single character.
+//
+// The naive recursive implementation of `*` backtracks exponentially on patterns like
+// `a*a*a*a*b`. Instead this uses the classic two-pointer algorithm: remember only the most
+// recent `*` and where in the input it started matching, and on a mismatch let that star
+// absorb one more character. Earlier stars never need revisiting, because anything they could
+// have absorbed the latest star can absorb too, so the whole match is O(pattern × input).
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Literal(char),
+    AnyChar,
+    AnyRun,
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+impl Token {
+    // Whether this single-character token matches `c`. Never called for `AnyRun`.
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Token::Literal(l) => *l == c,
+            Token::AnyChar => true,
+            Token::AnyRun => false,
+            Token::Class { negated, ranges } => {
+                ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi) != *negated
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlobErrorKind {
+    /// A `[` with no matching `]`.
+    UnclosedClass,
+    /// A range whose start comes after its end, such as `[z-a]`.
+    InvalidRange,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlobError {
+    pub pattern: String,
+    /// Byte offset into `pattern` where the problem starts.
+    pub position: usize,
+    pub kind: GlobErrorKind,
+    // Byte offset just past the offending text.
+    end: usize,
+}
+
+impl diagnostic::Spanned for GlobError {
+    /// From the opening `[` to the end of the bad range, or to the end of the pattern for
+    /// an unclosed class.
+    fn span(&self) -> Option<std::ops::Range<usize>> {
+        Some(self.position..self.end)
+    }
+}
+
+impl fmt::Display for GlobError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let what = match self.kind {
+            GlobErrorKind::UnclosedClass => "unclosed character class",
+            GlobErrorKind::InvalidRange => "character range is out of order",
+        };
+        write!(
+            f,
+            "invalid glob pattern {:?} at byte {}: {what}",
+            self.pattern, self.position
+        )
+    }
+}
+
+impl std::error::Error for GlobError {}
+
+/// A compiled glob pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Glob {
+    tokens: Vec<Token>,
+}
+
+impl Glob {
+    pub fn new(pattern: &str) -> Result<Glob, GlobError> {
+        Glob::parse(pattern, false)
+    }
+
+    // With `lenient` set, a malformed `[` is taken as a literal character instead of an error.
+    fn parse(pattern: &str, lenient: bool) -> Result<Glob, GlobError> {
+        let chars: Vec<(usize, char)> = pattern.char_indices().collect();
+        let mut tokens = Vec::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let (pos, c) = chars[i];
+            i += 1;
+            let token = match c {
+                '*' => {
+                    // Consecutive stars mean the same as one.
+                    if tokens.last() == Some(&Token::AnyRun) {
+                        continue;
+                    }
+                    Token::AnyRun
+                }
+                '?' => Token::AnyChar,
+                '[' => match parse_class(&chars[i..]) {
+                    Ok((token, used)) => {
+                        i += used;
+                        token
+                    }
+                    Err(_) if lenient => Token::Literal('['),
+                    Err((kind, used)) => {
+                        let end = chars.get(i + used).map_or(pattern.len(), |&(end, _)| end);
+                        return Err(GlobError {
+                            pattern: pattern.to_owned(),
+                            position: pos,
+                            kind,
+                            end,
+                        });
+                    }
+                },
+                c => Token::Literal(c),
+            };
+            tokens.push(token);
+        }
+        Ok(Glob { tokens })
+    }
+
+    pub fn matches(&self, input: &str) -> bool {
+        let input: Vec<char> = input.chars().collect();
+        let tokens = &self.tokens;
+        let (mut t, mut i) = (0, 0);
+        // Position of the last `*` seen, and the input index it's currently matched up to.
+        let mut star: Option<(usize, usize)> = None;
+
+        while i < input.len() {
+            match tokens.get(t) {
+                Some(Token::AnyRun) => {
+                    star = Some((t, i));
+                    t += 1;
+                }
+                Some(token) if token.matches(input[i]) => {
+                    t += 1;
+                    i += 1;
+                }
+                _ => match star {
+                    // Let the last star swallow one more character and retry from there.
+                    Some((star_t, star_i)) => {
+                        star = Some((star_t, star_i + 1));
+                        t = star_t + 1;
+                        i = star_i + 1;
+                    }
+                    None => return false,
+                },
+            }
+        }
+        // Input is used up; only stars (matching nothing) may remain in the pattern.
+        tokens[t..].iter().all(|token| *token == Token::AnyRun)
+    }
+}
+
+// Parse the body of a character class, starting just after its `[`. Returns the token and the
+// number of characters consumed, including the closing `]`, or on error the number of
+// characters up to the end of the problem.
+fn parse_class(chars: &[(usize, char)]) -> Result<(Token, usize), (GlobErrorKind, usize)> {
+    let at = |i: usize| chars.get(i).map(|&(_, c)| c);
+    let mut i = 0;
+    let negated = at(i) == Some('!');
+    if negated {
+        i += 1;
+    }
+    let mut ranges = Vec::new();
+    loop {
+        let lo = at(i).ok_or((GlobErrorKind::UnclosedClass, i))?;
+        i += 1;
+        // A `]` straight after the `[` (or `[!`) is a literal, not the end of the class.
+        if lo == ']' && !ranges.is_empty() {
+            return Ok((Token::Class { negated, ranges }, i));
+        }
+        let hi = match (at(i), at(i + 1)) {
+            // A `-` just before the `]` is a literal dash, left for the next time round.
+            (Some('-'), Some(hi)) if hi != ']' => {
+                i += 2;
+                hi
+            }
+            _ => lo,
+        };
+        if lo > hi {
+            return Err((GlobErrorKind::InvalidRange, i));
+        }
+        ranges.push((lo, hi));
+    }
+}
+
+/// Match `input` against `pattern` in one go.
+///
+/// A malformed class is treated the way shells do: its `[` stands for itself, so
+/// `glob_match("[a*", "[abc")` is true. Use [`Glob::new`] to have such patterns rejected instead.
+pub fn glob_match(pattern: &str, input: &str) -> bool {
+    match Glob::parse(pattern, true) {
+        Ok(glob) => glob.matches(input),
+        Err(_) => unreachable!("lenient parsing never fails"),
+    }
+}
+
+/// A set of patterns, matching any input that at least one of them matches.
+#[derive(Debug, Clone, Default)]
+pub struct GlobSet {
+    globs: Vec<Glob>,
+}
+
+impl GlobSet {
+    pub fn new<I, S>(patterns: I) -> Result<GlobSet, GlobError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let globs = patterns
+            .into_iter()
+            .map(|p| Glob::new(p.as_ref()))
+            .collect::<Result<_, _>>()?;
+        Ok(GlobSet { globs })
+    }
+
+    pub fn matches_any(&self, input: &str) -> bool {
+        self.globs.iter().any(|glob| glob.matches(input))
+    }
+
+    pub fn len(&self) -> usize {
+        self.globs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.globs.is_empty()
+    }
+}
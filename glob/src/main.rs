@@ -0,0 +1,31 @@
+use glob::{glob_match, GlobSet};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // WILDCARDS
+    // `*` matches any run of characters and `?` exactly one.
+    assert!(glob_match("1.*", "1.3"));
+    assert!(glob_match("?.?", "1.2"));
+    assert!(!glob_match("1.?", "2.1"));
+
+    // CHARACTER CLASSES
+    // `[...]` matches one of a set of characters, `[!...]` anything else.
+    assert!(glob_match("1.[1-3]", "1.2"));
+    assert!(glob_match("1.[!1-3]", "1.4"));
+
+    // Matching is per character rather than per byte, so `?` is happy with "é".
+    assert!(glob_match("caf?", "café"));
+
+    // SETS OF PATTERNS
+    // Compiling a pattern up front reports mistakes instead of silently matching literally.
+    let sources = GlobSet::new(["*.rs", "Cargo.*"])?;
+    let files = ["main.rs", "Cargo.toml", "README.md", "lib.rs"];
+    for file in files {
+        println!("{file:>12}: {}", sources.matches_any(file));
+    }
+
+    // A bad pattern reports where it went wrong, which `diagnostic` can point at.
+    let err = GlobSet::new(["*.rs", "[a-z"]).unwrap_err();
+    print!("{}", diagnostic::render(&err.pattern, &err));
+
+    Ok(())
+}
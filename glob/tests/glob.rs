@@ -0,0 +1,138 @@
+use glob::{glob_match, Glob, GlobErrorKind, GlobSet};
+use std::time::{Duration, Instant};
+
+#[test]
+fn literals_and_wildcards() {
+    assert!(glob_match("abc", "abc"));
+    assert!(!glob_match("abc", "abd"));
+    assert!(glob_match("a?c", "abc"));
+    assert!(!glob_match("a?c", "ac"));
+    assert!(glob_match("a*c", "ac"));
+    assert!(glob_match("a*c", "abbbc"));
+    assert!(!glob_match("a*c", "abbbd"));
+    assert!(glob_match("*.rs", "main.rs"));
+    assert!(!glob_match("*.rs", "main.rs.bak"));
+    assert!(glob_match("1.*", "1.3"));
+}
+
+#[test]
+fn empty_pattern_and_input() {
+    assert!(glob_match("", ""));
+    assert!(!glob_match("", "a"));
+    assert!(glob_match("*", ""));
+    assert!(glob_match("**", ""));
+    assert!(!glob_match("?", ""));
+    assert!(!glob_match("a", ""));
+    assert!(!glob_match("[a]", ""));
+}
+
+#[test]
+fn character_classes() {
+    assert!(glob_match("[abc]", "b"));
+    assert!(!glob_match("[abc]", "d"));
+    assert!(glob_match("[a-z]x", "qx"));
+    assert!(!glob_match("[a-z]x", "Qx"));
+    assert!(glob_match("[a-cx-z]", "y"));
+    // `]` first in the class and `-` last in it are literals.
+    assert!(glob_match("[]a]", "]"));
+    assert!(glob_match("[a-]", "-"));
+}
+
+#[test]
+fn negated_classes() {
+    assert!(glob_match("[!abc]", "d"));
+    assert!(!glob_match("[!abc]", "a"));
+    assert!(!glob_match("[!abc]", ""));
+    assert!(glob_match("[!a-z]*", "Zebra"));
+    assert!(!glob_match("[!a-z]*", "zebra"));
+    assert!(glob_match("[!]]", "a"));
+    assert!(!glob_match("[!]]", "]"));
+}
+
+#[test]
+fn unicode_is_matched_per_char() {
+    assert!(glob_match("caf?", "café"));
+    assert!(!glob_match("caf??", "café"));
+    assert!(glob_match("*ï*", "naïve"));
+    assert!(glob_match("[α-ω]", "λ"));
+    assert!(glob_match("?", "🦀"));
+}
+
+#[test]
+fn pathological_pattern_completes_quickly() {
+    let input = "a".repeat(10_000);
+    let start = Instant::now();
+    assert!(!glob_match("a*a*a*a*a*a*a*a*b", &input));
+    assert!(glob_match("a*a*a*a*a*a*a*a*", &input));
+    // Exponential backtracking would take far longer than this on a 10k-character input.
+    assert!(
+        start.elapsed() < Duration::from_secs(1),
+        "{:?}",
+        start.elapsed()
+    );
+}
+
+#[test]
+fn construction_errors_report_position() {
+    let err = Glob::new("ab[cd").unwrap_err();
+    assert_eq!(err.kind, GlobErrorKind::UnclosedClass);
+    assert_eq!(err.position, 2);
+
+    // Positions are byte offsets, so multi-byte characters before the `[` count fully.
+    let err = Glob::new("é[").unwrap_err();
+    assert_eq!(err.position, 2);
+
+    let err = Glob::new("x[z-a]").unwrap_err();
+    assert_eq!(err.kind, GlobErrorKind::InvalidRange);
+    assert_eq!(err.position, 1);
+
+    assert_eq!(
+        err.to_string(),
+        "invalid glob pattern \"x[z-a]\" at byte 1: character range is out of order"
+    );
+}
+
+#[test]
+fn lenient_matching_of_invalid_patterns() {
+    assert!(glob_match("[a", "[a"));
+    assert!(glob_match("[a*", "[abc"));
+    assert!(!glob_match("[a", "a"));
+}
+
+#[test]
+fn glob_set() {
+    let set = GlobSet::new(["*.rs", "Cargo.*"]).unwrap();
+    assert_eq!(set.len(), 2);
+    assert!(set.matches_any("lib.rs"));
+    assert!(set.matches_any("Cargo.toml"));
+    assert!(!set.matches_any("README.md"));
+
+    let empty = GlobSet::new(Vec::<String>::new()).unwrap();
+    assert!(empty.is_empty());
+    assert!(!empty.matches_any(""));
+
+    let err = GlobSet::new(["ok", "bad["]).unwrap_err();
+    assert_eq!(err.pattern, "bad[");
+    assert_eq!(err.position, 3);
+}
+
+#[test]
+fn errors_render_with_their_span() {
+    use diagnostic::{render, Spanned};
+
+    let err = Glob::new("*.[rs").unwrap_err();
+    assert_eq!(err.span(), Some(2..5));
+    assert_eq!(
+        render("*.[rs", &err),
+        "error: invalid glob pattern \"*.[rs\" at byte 2: unclosed character class
+ --> 1:3
+  |
+1 | *.[rs
+  |   ^^^
+"
+    );
+
+    let err = Glob::new("é[z-a]x").unwrap_err();
+    assert_eq!(err.span(), Some(2..6));
+    assert!(render("é[z-a]x", &err).ends_with("1 | é[z-a]x\n  |  ^^^^\n"));
+}
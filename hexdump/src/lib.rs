@@ -0,0 +1,103 @@
+// The classic offset / hex / ASCII dump, handy for looking at binary payloads:
+//
+// 00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a 00 ff  |Hello, world!...|
+//
+// Bytes outside printable ASCII show up as `.` in the right-hand column. A short final line is
+// padded so that its ASCII column still lines up with the lines above it.
+
+use std::fmt::Write as _;
+use std::io::{self, Read, Write};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HexDumpOpts {
+    /// How many bytes each line shows. Must be at least 1.
+    pub bytes_per_line: usize,
+    pub uppercase: bool,
+    /// The offset printed for the first byte, for dumping a slice from the middle of something.
+    pub offset_base: u64,
+    /// Put an extra space after every `group` bytes; 0 disables grouping.
+    pub group: usize,
+}
+
+impl Default for HexDumpOpts {
+    fn default() -> Self {
+        HexDumpOpts {
+            bytes_per_line: 16,
+            uppercase: false,
+            offset_base: 0,
+            group: 8,
+        }
+    }
+}
+
+pub fn hex_dump(bytes: &[u8], opts: &HexDumpOpts) -> String {
+    assert!(opts.bytes_per_line > 0, "bytes_per_line must be at least 1");
+    let mut out = String::new();
+    let mut offset = opts.offset_base;
+    for chunk in bytes.chunks(opts.bytes_per_line) {
+        format_line(&mut out, offset, chunk, opts);
+        offset += chunk.len() as u64;
+    }
+    out
+}
+
+/// Dump everything `reader` produces to `out`, one line at a time, so the input never has to
+/// fit in memory. The output is identical to [`hex_dump`] on the same bytes.
+pub fn hex_dump_to(
+    mut reader: impl Read,
+    out: &mut impl Write,
+    opts: &HexDumpOpts,
+) -> io::Result<()> {
+    assert!(opts.bytes_per_line > 0, "bytes_per_line must be at least 1");
+    let mut chunk = vec![0; opts.bytes_per_line];
+    let mut line = String::new();
+    let mut offset = opts.offset_base;
+    loop {
+        // `read` may return less than asked for; keep going until the line is full or the input
+        // runs out, so line breaks don't depend on how the reader happens to chunk its data.
+        let mut filled = 0;
+        while filled < chunk.len() {
+            match reader.read(&mut chunk[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        if filled == 0 {
+            return Ok(());
+        }
+        line.clear();
+        format_line(&mut line, offset, &chunk[..filled], opts);
+        out.write_all(line.as_bytes())?;
+        offset += filled as u64;
+    }
+}
+
+fn format_line(out: &mut String, offset: u64, chunk: &[u8], opts: &HexDumpOpts) {
+    let _ = if opts.uppercase {
+        write!(out, "{offset:08X} ")
+    } else {
+        write!(out, "{offset:08x} ")
+    };
+    for i in 0..opts.bytes_per_line {
+        if i == 0 || (opts.group > 0 && i % opts.group == 0) {
+            out.push(' ');
+        }
+        // Missing bytes on a short last line become blanks of the same width.
+        let _ = match chunk.get(i) {
+            Some(b) if opts.uppercase => write!(out, "{b:02X} "),
+            Some(b) => write!(out, "{b:02x} "),
+            None => write!(out, "   "),
+        };
+    }
+    out.push_str(" |");
+    out.extend(chunk.iter().map(|&b| {
+        if b.is_ascii_graphic() || b == b' ' {
+            b as char
+        } else {
+            '.'
+        }
+    }));
+    out.push_str("|\n");
+}
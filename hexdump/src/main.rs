@@ -0,0 +1,29 @@
+use hexdump::{hex_dump, hex_dump_to, HexDumpOpts};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Printable ASCII shows up on the right, everything else as `.`.
+    let plaintext = b"Hello, world!\n\x00\xff and then some more";
+    print!("{}", hex_dump(plaintext, &HexDumpOpts::default()));
+    println!();
+
+    // The same bytes XOR-ed with a key: the structure of the text disappears from the
+    // ASCII column, which is exactly what a dump is useful for spotting.
+    let ciphertext: Vec<u8> = plaintext.iter().map(|b| b ^ 0x5a).collect();
+    let opts = HexDumpOpts {
+        bytes_per_line: 8,
+        uppercase: true,
+        group: 4,
+        ..HexDumpOpts::default()
+    };
+    print!("{}", hex_dump(&ciphertext, &opts));
+    println!();
+
+    // The streaming version reads from anything that implements `Read`, a line at a time.
+    hex_dump_to(
+        &b"streamed"[..],
+        &mut std::io::stdout(),
+        &HexDumpOpts::default(),
+    )?;
+
+    Ok(())
+}
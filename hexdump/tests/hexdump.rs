@@ -0,0 +1,157 @@
+use hexdump::{hex_dump, hex_dump_to, HexDumpOpts};
+use std::io::{self, Read};
+
+fn dump(bytes: &[u8]) -> String {
+    hex_dump(bytes, &HexDumpOpts::default())
+}
+
+fn counting(n: u8) -> Vec<u8> {
+    (0..n).map(|i| b'a' + i).collect()
+}
+
+#[test]
+fn empty_input() {
+    assert_eq!(dump(&[]), "");
+}
+
+#[test]
+fn fifteen_bytes() {
+    assert_eq!(
+        dump(&counting(15)),
+        "00000000  61 62 63 64 65 66 67 68  69 6a 6b 6c 6d 6e 6f     |abcdefghijklmno|\n"
+    );
+}
+
+#[test]
+fn sixteen_bytes() {
+    assert_eq!(
+        dump(&counting(16)),
+        "00000000  61 62 63 64 65 66 67 68  69 6a 6b 6c 6d 6e 6f 70  |abcdefghijklmnop|\n"
+    );
+}
+
+#[test]
+fn seventeen_bytes() {
+    assert_eq!(
+        dump(&counting(17)),
+        "00000000  61 62 63 64 65 66 67 68  69 6a 6b 6c 6d 6e 6f 70  |abcdefghijklmnop|\n\
+         00000010  71                                                |q|\n"
+    );
+}
+
+#[test]
+fn non_printable_bytes_are_dots() {
+    assert_eq!(
+        dump(b"\x00\x1f \x7e\x7f\x80\xff\t\n"),
+        "00000000  00 1f 20 7e 7f 80 ff 09  0a                       |.. ~.....|\n"
+    );
+}
+
+#[test]
+fn ascii_column_aligns_on_every_line() {
+    let out = dump(&(0..=255).collect::<Vec<u8>>()[..40]);
+    let columns: Vec<_> = out.lines().map(|line| line.find('|').unwrap()).collect();
+    assert_eq!(columns, [60, 60, 60]);
+}
+
+#[test]
+fn options() {
+    let bytes = [0xab, 0xcd, 0xef, 0x01, 0x23];
+    let opts = |bytes_per_line, uppercase, offset_base, group| HexDumpOpts {
+        bytes_per_line,
+        uppercase,
+        offset_base,
+        group,
+    };
+
+    assert_eq!(
+        hex_dump(&bytes, &opts(4, false, 0, 0)),
+        "00000000  ab cd ef 01  |....|\n00000004  23           |#|\n"
+    );
+    assert_eq!(
+        hex_dump(&bytes, &opts(4, true, 0, 0)),
+        "00000000  AB CD EF 01  |....|\n00000004  23           |#|\n"
+    );
+    assert_eq!(
+        hex_dump(&bytes, &opts(4, false, 0xfffa, 2)),
+        "0000fffa  ab cd  ef 01  |....|\n0000fffe  23            |#|\n"
+    );
+    assert_eq!(
+        hex_dump(&bytes, &opts(4, true, 0xabc, 1)),
+        "00000ABC  AB  CD  EF  01  |....|\n00000AC0  23              |#|\n"
+    );
+    assert_eq!(
+        hex_dump(&bytes, &opts(1, false, 0, 8)),
+        "00000000  ab  |.|\n00000001  cd  |.|\n00000002  ef  |.|\n\
+         00000003  01  |.|\n00000004  23  |#|\n"
+    );
+}
+
+#[test]
+#[should_panic(expected = "bytes_per_line")]
+fn zero_bytes_per_line_is_rejected() {
+    hex_dump(
+        b"x",
+        &HexDumpOpts {
+            bytes_per_line: 0,
+            ..HexDumpOpts::default()
+        },
+    );
+}
+
+// A reader that hands out at most `step` bytes per call, to make sure the streaming dump
+// doesn't let short reads break lines early.
+struct Trickle<'a> {
+    data: &'a [u8],
+    step: usize,
+}
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.step.min(buf.len()).min(self.data.len());
+        buf[..n].copy_from_slice(&self.data[..n]);
+        self.data = &self.data[n..];
+        Ok(n)
+    }
+}
+
+#[test]
+fn streaming_matches_in_memory() {
+    let data: Vec<u8> = (0..1000u32).map(|i| (i * 7 % 256) as u8).collect();
+    for opts in [
+        HexDumpOpts::default(),
+        HexDumpOpts {
+            bytes_per_line: 7,
+            uppercase: true,
+            offset_base: 3,
+            group: 3,
+        },
+    ] {
+        for len in [0, 1, 15, 16, 17, 1000] {
+            for step in [1, 3, 16, 4096] {
+                let mut out = Vec::new();
+                let reader = Trickle {
+                    data: &data[..len],
+                    step,
+                };
+                hex_dump_to(reader, &mut out, &opts).unwrap();
+                assert_eq!(
+                    String::from_utf8(out).unwrap(),
+                    hex_dump(&data[..len], &opts)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn streaming_reports_read_errors() {
+    struct Broken;
+    impl Read for Broken {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("disk on fire"))
+        }
+    }
+    let err = hex_dump_to(Broken, &mut Vec::new(), &HexDumpOpts::default()).unwrap_err();
+    assert_eq!(err.to_string(), "disk on fire");
+}
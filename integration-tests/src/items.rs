@@ -0,0 +1,260 @@
+// Finding every item crate, and checking that each one builds and its binary runs.
+//
+// Items live two levels down, in a chapter directory such as `1. Types` and then one named
+// for the item, such as `1.1`. Nothing needs registering: a new item directory with a
+// `Cargo.toml` is checked as soon as it exists.
+//
+// An item whose point is that its code doesn't compile says so in its manifest:
+//
+//     [package.metadata.effective-rust]
+//     compile_fail = true
+//
+// and passes only if building it fails with a compile error.
+
+use crate::{run, scrubbed};
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+/// An item crate, as found on disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item {
+    /// The item's number, from its directory name, such as `"1.1"`.
+    pub id: String,
+    pub package: String,
+    pub dir: PathBuf,
+    /// Whether the item is expected not to build.
+    pub compile_fail: bool,
+}
+
+/// Every item under `root`, in order of id.
+pub fn discover(root: &Path) -> io::Result<Vec<Item>> {
+    let mut items = Vec::new();
+    for chapter in subdirectories(root)? {
+        if !is_chapter(&file_name(&chapter)) {
+            continue;
+        }
+        for dir in subdirectories(&chapter)? {
+            let id = file_name(&dir);
+            let manifest = dir.join("Cargo.toml");
+            if is_item_id(&id) && manifest.is_file() {
+                items.push(read_item(id, dir, &manifest)?);
+            }
+        }
+    }
+    items.sort_by_key(|item| id_key(&item.id));
+    Ok(items)
+}
+
+fn subdirectories(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut dirs = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            dirs.push(entry.path());
+        }
+    }
+    Ok(dirs)
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+// "1. Types": a number, a dot and a space, then the chapter's name.
+fn is_chapter(name: &str) -> bool {
+    name.split_once(". ")
+        .is_some_and(|(number, _)| !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()))
+}
+
+// "1.1": two numbers and a dot.
+fn is_item_id(name: &str) -> bool {
+    name.split_once('.').is_some_and(|(chapter, item)| {
+        [chapter, item]
+            .iter()
+            .all(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+    })
+}
+
+// So that 1.10 sorts after 1.9.
+fn id_key(id: &str) -> Vec<u32> {
+    id.split('.')
+        .map(|n| n.parse().unwrap_or(u32::MAX))
+        .collect()
+}
+
+fn read_item(id: String, dir: PathBuf, manifest: &Path) -> io::Result<Item> {
+    let invalid = |message: String| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {message}", manifest.display()),
+        )
+    };
+    let text = fs::read_to_string(manifest)?;
+    let manifest: toml::Table = text.parse().map_err(|e| invalid(format!("{e}")))?;
+    let package = manifest
+        .get("package")
+        .and_then(toml::Value::as_table)
+        .ok_or_else(|| invalid("no [package] table".to_owned()))?;
+    let name = package
+        .get("name")
+        .and_then(toml::Value::as_str)
+        .ok_or_else(|| invalid("no package name".to_owned()))?;
+    let compile_fail = match package
+        .get("metadata")
+        .and_then(|metadata| metadata.get("effective-rust"))
+        .and_then(|metadata| metadata.get("compile_fail"))
+    {
+        None => false,
+        Some(toml::Value::Boolean(flag)) => *flag,
+        Some(other) => return Err(invalid(format!("compile_fail is {other}, not a boolean"))),
+    };
+    Ok(Item {
+        id,
+        package: name.to_owned(),
+        dir,
+        compile_fail,
+    })
+}
+
+/// How checking an item went wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// The item should have built, and didn't.
+    Build { stderr: String },
+    /// The item is marked `compile_fail`, and built anyway.
+    UnexpectedBuild,
+    /// The item is marked `compile_fail`, and failed for some reason other than its code not
+    /// compiling, such as a broken manifest.
+    WrongBuildFailure { stderr: String },
+    /// The binary failed, or wrote to stderr.
+    Run {
+        status: String,
+        stdout: String,
+        stderr: String,
+    },
+}
+
+impl Failure {
+    /// A word or two for the results table.
+    pub fn summary(&self) -> &'static str {
+        match self {
+            Failure::Build { .. } => "FAILED to build",
+            Failure::UnexpectedBuild => "FAILED: built, but is compile_fail",
+            Failure::WrongBuildFailure { .. } => "FAILED: build broke without a compile error",
+            Failure::Run { .. } => "FAILED to run",
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.summary())?;
+        match self {
+            Failure::Build { stderr } | Failure::WrongBuildFailure { stderr } => {
+                write!(f, "\n--- cargo stderr\n{stderr}")
+            }
+            Failure::UnexpectedBuild => Ok(()),
+            Failure::Run {
+                status,
+                stdout,
+                stderr,
+            } => write!(f, " ({status})\n--- stdout\n{stdout}--- stderr\n{stderr}"),
+        }
+    }
+}
+
+/// What checking one item came to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub item: Item,
+    pub result: Result<(), Failure>,
+}
+
+/// Build `item` into `target_dir`, then, unless it is `compile_fail`, run its binary, which
+/// must succeed within `timeout` without writing to stderr.
+pub fn check(item: &Item, target_dir: &Path, timeout: Duration) -> Outcome {
+    let result = build_and_run(item, target_dir, timeout);
+    Outcome {
+        item: item.clone(),
+        result,
+    }
+}
+
+fn build_and_run(item: &Item, target_dir: &Path, timeout: Duration) -> Result<(), Failure> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned());
+    let mut build = Command::new(cargo);
+    build
+        .args(["build", "--quiet", "--bins", "--manifest-path"])
+        .arg(item.dir.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(target_dir);
+    let output = build.output().expect("failed to run cargo");
+    let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+    match (item.compile_fail, output.status.success()) {
+        (false, false) => return Err(Failure::Build { stderr }),
+        (true, true) => return Err(Failure::UnexpectedBuild),
+        (true, false) if stderr.contains("could not compile") => return Ok(()),
+        (true, false) => return Err(Failure::WrongBuildFailure { stderr }),
+        (false, true) => {}
+    }
+
+    let binary =
+        target_dir
+            .join("debug")
+            .join(format!("{}{}", item.package, std::env::consts::EXE_SUFFIX));
+    let output = run(scrubbed(binary), timeout);
+    if output.status.success() && output.stderr.is_empty() {
+        Ok(())
+    } else {
+        Err(Failure::Run {
+            status: output.status.to_string(),
+            stdout: output.stdout,
+            stderr: output.stderr,
+        })
+    }
+}
+
+/// One line per item, saying how it went, then the details of every failure.
+pub fn report(outcomes: &[Outcome]) -> String {
+    let width = |column: fn(&Item) -> &str, heading: &str| {
+        outcomes
+            .iter()
+            .map(|outcome| column(&outcome.item).len())
+            .chain([heading.len()])
+            .max()
+            .unwrap_or_default()
+    };
+    let id_width = width(|item| &item.id, "item");
+    let package_width = width(|item| &item.package, "package");
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{:id_width$}  {:package_width$}  result",
+        "item", "package"
+    );
+    for Outcome { item, result } in outcomes {
+        let result = match result {
+            Ok(()) if item.compile_fail => "ok (fails to compile, as it should)",
+            Ok(()) => "ok",
+            Err(failure) => failure.summary(),
+        };
+        let _ = writeln!(
+            out,
+            "{:id_width$}  {:package_width$}  {result}",
+            item.id, item.package
+        );
+    }
+    for Outcome { item, result } in outcomes {
+        if let Err(failure) = result {
+            let _ = write!(out, "\n=== {} ({})\n{failure}", item.id, item.package);
+        }
+    }
+    out
+}
@@ -0,0 +1,210 @@
+// Helpers for running the workspace binaries the way a user would: as separate processes,
+// with nothing inherited from the developer's environment.
+//
+// Cargo only exposes `CARGO_BIN_EXE_*` for binaries of the package under test, so the binaries
+// of the other packages are built here on first use. They go into a target directory of their
+// own, because the outer `cargo test` may still hold the lock on the main one.
+//
+// `items` finds the item crates on disk, and checks each one in turn.
+
+pub mod items;
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long a binary may run before it's considered hung.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("integration-tests lives inside the workspace")
+}
+
+/// Where the binaries built here go, away from the main target directory.
+pub fn target_dir() -> PathBuf {
+    workspace_root().join("target").join("integration-tests")
+}
+
+/// Build the binary `bin` of `package` (once per test process) and return its path.
+pub fn build(package: &str, bin: &str) -> PathBuf {
+    build_with_features(package, bin, &[])
+}
+
+/// [`build`], with `features` of `package` turned on, for a binary that requires them.
+pub fn build_with_features(package: &str, bin: &str, features: &[&str]) -> PathBuf {
+    static BUILT: Mutex<Option<HashSet<String>>> = Mutex::new(None);
+
+    let mut built = BUILT.lock().unwrap_or_else(|e| e.into_inner());
+    let built = built.get_or_insert_with(HashSet::new);
+    if !built.contains(bin) {
+        let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned());
+        let output = Command::new(cargo)
+            .args(["build", "--quiet", "--package", package, "--bin", bin])
+            .args(features.iter().flat_map(|feature| ["--features", feature]))
+            .arg("--target-dir")
+            .arg(target_dir())
+            .current_dir(workspace_root())
+            .output()
+            .expect("failed to run cargo");
+        assert!(
+            output.status.success(),
+            "building `{bin}` from `{package}` failed:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        built.insert(bin.to_owned());
+    }
+    target_dir()
+        .join("debug")
+        .join(format!("{bin}{}", std::env::consts::EXE_SUFFIX))
+}
+
+/// A command for `bin` with a scrubbed environment (see [`scrubbed`]).
+pub fn command(package: &str, bin: &str) -> Command {
+    scrubbed(build(package, bin))
+}
+
+/// [`command`] for a binary built with `features` turned on.
+pub fn command_with_features(package: &str, bin: &str, features: &[&str]) -> Command {
+    scrubbed(build_with_features(package, bin, features))
+}
+
+/// A command for `program` with no `HOME`, no user settings, and a fixed locale, so output
+/// can't depend on the machine the tests run on.
+pub fn scrubbed(program: impl AsRef<std::ffi::OsStr>) -> Command {
+    let mut cmd = Command::new(program);
+    cmd.env_clear()
+        .env("LC_ALL", "C")
+        .env("LANG", "C")
+        .current_dir(workspace_root())
+        .stdin(Stdio::null());
+    cmd
+}
+
+#[derive(Debug)]
+pub struct Output {
+    pub status: ExitStatus,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Run `cmd` to completion, killing it and panicking if it takes longer than `timeout`.
+pub fn run(mut cmd: Command, timeout: Duration) -> Output {
+    let mut child = cmd
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap_or_else(|e| panic!("failed to spawn {cmd:?}: {e}"));
+
+    // Drain both pipes on their own threads so a chatty child can't block on a full pipe
+    // while we're waiting for it to exit.
+    let drain = |mut pipe: Box<dyn Read + Send>| {
+        thread::spawn(move || {
+            let mut buf = Vec::new();
+            let _ = pipe.read_to_end(&mut buf);
+            String::from_utf8_lossy(&buf).into_owned()
+        })
+    };
+    let stdout = drain(Box::new(child.stdout.take().unwrap()));
+    let stderr = drain(Box::new(child.stderr.take().unwrap()));
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait().expect("failed to wait for child") {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            panic!("{cmd:?} did not finish within {timeout:?}");
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+
+    Output {
+        status,
+        stdout: stdout.join().unwrap(),
+        stderr: stderr.join().unwrap(),
+    }
+}
+
+impl Output {
+    /// Assert a zero exit code with nothing written to stderr.
+    pub fn assert_success(&self) -> &Self {
+        assert!(
+            self.status.success() && self.stderr.is_empty(),
+            "expected success with empty stderr, got {}\n--- stdout\n{}--- stderr\n{}",
+            self.status,
+            self.stdout,
+            self.stderr
+        );
+        self
+    }
+
+    /// Assert that every one of `markers` appears somewhere in stdout.
+    pub fn assert_stdout_contains(&self, markers: &[&str]) -> &Self {
+        let missing: Vec<_> = markers
+            .iter()
+            .filter(|marker| !self.stdout.contains(*marker))
+            .collect();
+        assert!(
+            missing.is_empty(),
+            "stdout is missing {missing:?}\n--- stdout\n{}",
+            self.stdout
+        );
+        self
+    }
+
+    /// Assert that stdout is exactly `expected`, showing a line diff if not.
+    pub fn assert_stdout_eq(&self, expected: &str) -> &Self {
+        assert!(
+            self.stdout == expected,
+            "stdout differs (-expected +actual):\n{}",
+            diff(expected, &self.stdout)
+        );
+        self
+    }
+}
+
+/// A minimal line diff: lines only in `expected` are prefixed with `-`, lines only in
+/// `actual` with `+`, and common lines with a space. Good enough to spot what changed in a
+/// short program transcript.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    // Longest-common-subsequence table, filled from the end.
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            let _ = writeln!(out, "  {}", old[i]);
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            let _ = writeln!(out, "+ {}", new[j]);
+            j += 1;
+        } else {
+            let _ = writeln!(out, "- {}", old[i]);
+            i += 1;
+        }
+    }
+    out
+}
@@ -0,0 +1,257 @@
+use integration_tests::{
+    command, command_with_features, run, scrubbed, workspace_root, DEFAULT_TIMEOUT,
+};
+
+// Every binary in the workspace, as (package, binary, features, arguments).
+const BINARIES: &[(&str, &str, &[&str], &[&str])] = &[
+    ("item-1-1", "item-1-1", &[], &[]),
+    ("item-1-2", "item-1-2", &[], &[]),
+    ("item-1-3", "item-1-3", &[], &[]),
+    ("item-1-3", "find-users-async", &["async"], &[]),
+    ("item-1-4", "item-1-4", &[], &[]),
+    ("item-1-8", "item-1-8", &[], &[]),
+    ("item-1-9", "item-1-9", &[], &[]),
+    ("item-2-1", "item-2-1", &[], &[]),
+    ("item-2-2", "item-2-2", &[], &[]),
+    ("item-2-3", "item-2-3", &[], &[]),
+    ("item-3-1", "item-3-1", &[], &[]),
+    ("item-3-4", "item-3-4", &[], &[]),
+    ("item-5-2", "item-5-2", &[], &[]),
+    ("item-5-4", "item-5-4", &[], &[]),
+    ("item-6-2", "item-6-2", &[], &[]),
+    // One call of each of the cheap benchmarks; the others have inputs of millions of values,
+    // which a debug build takes a long time to make.
+    (
+        "bench-lite",
+        "bench-lite",
+        &[],
+        &[
+            "--warmup",
+            "0",
+            "--samples",
+            "1",
+            "--iterations",
+            "1",
+            "glob/",
+        ],
+    ),
+    ("codec", "codec", &[], &[]),
+    ("follow", "follow", &[], &[]),
+    ("glob", "glob", &[], &[]),
+    ("hexdump", "hexdump", &[], &[]),
+    ("runner", "runner", &[], &["--list"]),
+];
+
+#[test]
+fn every_binary_succeeds_with_output() {
+    for &(package, bin, features, args) in BINARIES {
+        let mut cmd = command_with_features(package, bin, features);
+        cmd.args(args);
+        let output = run(cmd, DEFAULT_TIMEOUT);
+        output.assert_success();
+        assert!(!output.stdout.trim().is_empty(), "`{bin}` printed nothing");
+    }
+}
+
+// Tiny counts: these check the plumbing, not the timings.
+fn bench_lite(args: &[&str]) -> integration_tests::Output {
+    let mut cmd = command("bench-lite", "bench-lite");
+    cmd.args(["--warmup", "1", "--samples", "3", "--iterations", "2"])
+        .args(args);
+    run(cmd, DEFAULT_TIMEOUT)
+}
+
+#[test]
+fn bench_lite_table_and_json() {
+    bench_lite(&["glob/"])
+        .assert_success()
+        .assert_stdout_contains(&["vs group", "glob/glob_match", "glob/compiled"]);
+
+    let output = bench_lite(&["--json", "glob/"]);
+    output.assert_success();
+    assert!(output
+        .stdout
+        .starts_with("[\n  {\"name\": \"glob/glob_match\", \"median_ns\": "));
+    assert!(!output.stdout.contains("hexdump"));
+}
+
+#[test]
+fn bench_lite_baseline() {
+    let dir = workspace_root().join("target").join("integration-tests");
+    std::fs::create_dir_all(&dir).unwrap();
+    let baseline = |name: &str, median_ns: &str| {
+        let path = dir.join(name);
+        let json =
+            format!(r#"[{{"name": "glob/compiled", "median_ns": {median_ns}, "mad_ns": 0}}]"#);
+        std::fs::write(&path, json).unwrap();
+        path.to_str().unwrap().to_owned()
+    };
+
+    // Nothing takes a whole second, so nothing can have regressed.
+    let slow = baseline("bench-lite-slow.json", "1e9");
+    bench_lite(&["--baseline", &slow, "glob/"])
+        .assert_success()
+        .assert_stdout_contains(&["vs baseline", "glob/glob_match", "new"]);
+
+    // Everything takes longer than a thousandth of a nanosecond.
+    let fast = baseline("bench-lite-fast.json", "0.001");
+    let output = bench_lite(&["--baseline", &fast, "glob/"]);
+    assert_eq!(output.status.code(), Some(1), "{output:?}");
+    assert!(
+        output.stderr.starts_with("regression: glob/compiled is "),
+        "{output:?}"
+    );
+
+    let broken = baseline("bench-lite-broken.json", "\"fast\"");
+    let output = bench_lite(&["--baseline", &broken]);
+    assert_eq!(output.status.code(), Some(2), "{output:?}");
+    assert!(
+        output
+            .stderr
+            .contains("error: \"median_ns\" must be a number"),
+        "{output:?}"
+    );
+}
+
+#[test]
+fn codec() {
+    run(command("codec", "codec"), DEFAULT_TIMEOUT)
+        .assert_success()
+        .assert_stdout_contains(&[
+            "Circle { radius: 1.5 } => [02, 00, 00, 00, 00, 00, 00, f8, 3f]",
+            "Job { id: 7, name: \"backup\" } => [10, 07,",
+            "rejected 1 trailing byte(s)",
+            "decoding a short circle: input truncated: needed 8 bytes, 1 available",
+            "8,\"say \"\"hi\"\", then leave\"",
+        ]);
+}
+
+#[test]
+fn follow() {
+    run(command("follow", "follow"), DEFAULT_TIMEOUT)
+        .assert_success()
+        .assert_stdout_eq(
+            "line: starting up
+line: half a line
+-- file rotated --
+line: rotated
+line: done
+",
+        );
+}
+
+#[test]
+fn glob() {
+    run(command("glob", "glob"), DEFAULT_TIMEOUT)
+        .assert_success()
+        .assert_stdout_eq(
+            "     main.rs: true
+  Cargo.toml: true
+   README.md: false
+      lib.rs: true
+error: invalid glob pattern \"[a-z\" at byte 0: unclosed character class
+ --> 1:1
+  |
+1 | [a-z
+  | ^^^^
+",
+        );
+}
+
+#[test]
+fn hexdump() {
+    run(command("hexdump", "hexdump"), DEFAULT_TIMEOUT)
+        .assert_success()
+        .assert_stdout_contains(&[
+            "00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a 00 ff  |Hello, world!...|",
+            "00000020  35 28 3F                  |5(?|",
+            "00000000  73 74 72 65 61 6d 65 64                           |streamed|",
+        ]);
+}
+
+fn runner(args: &[&str]) -> integration_tests::Output {
+    let mut cmd = command("runner", "runner");
+    cmd.args(args);
+    run(cmd, DEFAULT_TIMEOUT)
+}
+
+#[test]
+fn runner_lists_and_runs_items() {
+    runner(&["--list"]).assert_success().assert_stdout_eq(
+        "\
+1.1  Use the type system to express your data structures
+1.2  Use the type system to express common behavior
+1.3  Prefer Option and Result transforms over explicit match expressions
+1.4  Prefer idiomatic Error types
+1.8  Familiarize yourself with reference and pointer types
+1.9  Consider using iterator transforms instead of explicit loops
+2.1  Familiarize yourself with standard traits
+2.2  Implement the Drop trait for RAII patterns
+2.3  Understand the trade-offs between generics and trait objects
+3.1  Understand lifetimes
+3.4  Be wary of shared-state parallelism
+5.2  Use macros judiciously
+5.4  Write more than unit tests
+6.2  Control what crosses FFI boundaries
+",
+    );
+
+    // The same output as the item's own binary.
+    let item = run(command("item-1-2", "item-1-2"), DEFAULT_TIMEOUT);
+    item.assert_success();
+    runner(&["1.2"])
+        .assert_success()
+        .assert_stdout_eq(&item.stdout);
+}
+
+#[test]
+fn runner_picks_items_by_glob() {
+    runner(&["--list", "--item", "1.*", "--item", "6.?"])
+        .assert_success()
+        .assert_stdout_eq(
+            "\
+1.1  Use the type system to express your data structures
+1.2  Use the type system to express common behavior
+1.3  Prefer Option and Result transforms over explicit match expressions
+1.4  Prefer idiomatic Error types
+1.8  Familiarize yourself with reference and pointer types
+1.9  Consider using iterator transforms instead of explicit loops
+6.2  Control what crosses FFI boundaries
+",
+        );
+
+    // A pattern matching one item runs it just as naming it would.
+    let item = runner(&["1.2"]);
+    item.assert_success();
+    runner(&["--item", "1.[2]"])
+        .assert_success()
+        .assert_stdout_eq(&item.stdout);
+
+    let output = runner(&["--item", "7.*"]);
+    assert_eq!(output.status.code(), Some(2), "{output:?}");
+    assert!(
+        output.stderr.starts_with("error: no item matches 7.*;"),
+        "{output:?}"
+    );
+}
+
+#[test]
+fn runner_rejects_unknown_items() {
+    let output = runner(&["7.7"]);
+    assert_eq!(output.status.code(), Some(2), "{output:?}");
+    assert_eq!(
+        output.stderr,
+        "error: unknown item \"7.7\"; available items: 1.1, 1.2, 1.3, 1.4, 1.8, 1.9, 2.1, 2.2, 2.3, 3.1, 3.4, 5.2, 5.4, 6.2\n"
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn environment_is_scrubbed() {
+    // `env` isn't one of ours, but it shows exactly what a spawned binary gets to see.
+    let output = run(scrubbed("env"), DEFAULT_TIMEOUT);
+    output.assert_success();
+    let mut vars: Vec<_> = output.stdout.lines().collect();
+    vars.sort();
+    assert_eq!(vars, ["LANG=C", "LC_ALL=C"]);
+}
@@ -0,0 +1,18 @@
+use integration_tests::diff;
+
+#[test]
+fn identical_text_has_no_changes() {
+    assert_eq!(diff("a\nb\n", "a\nb\n"), "  a\n  b\n");
+}
+
+#[test]
+fn changed_line() {
+    assert_eq!(diff("a\nb\nc\n", "a\nx\nc\n"), "  a\n+ x\n- b\n  c\n");
+}
+
+#[test]
+fn added_and_removed_lines() {
+    assert_eq!(diff("a\nb\n", "a\nb\nc\n"), "  a\n  b\n+ c\n");
+    assert_eq!(diff("a\nb\nc\n", "b\nc\n"), "- a\n  b\n  c\n");
+    assert_eq!(diff("", "a\n"), "+ a\n");
+}
@@ -0,0 +1,17 @@
+// The passing companions to the `tests/ui` cases: a closure that captures nothing coerces to a
+// function pointer just fine.
+
+fn apply(op: fn(i32, i32) -> i32, x: i32, y: i32) -> i32 {
+    op(x, y)
+}
+
+#[test]
+fn non_capturing_closure_coerces_to_fn_pointer() {
+    let fun: fn(i32, i32) -> i32 = |x, y| x + y;
+    assert_eq!(fun(23, 3), 26);
+}
+
+#[test]
+fn non_capturing_closure_passed_as_fn_argument() {
+    assert_eq!(apply(|x, y| x * y, 6, 7), 42);
+}
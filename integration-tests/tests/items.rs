@@ -0,0 +1,74 @@
+use integration_tests::items::{check, discover, report, Failure};
+use integration_tests::{target_dir, workspace_root, DEFAULT_TIMEOUT};
+use std::path::Path;
+
+// Every item in the repository, by number.
+const ITEMS: &[&str] = &[
+    "1.1", "1.2", "1.3", "1.4", "1.8", "1.9", "2.1", "2.2", "2.3", "3.1", "3.4", "5.2", "5.4",
+    "6.2",
+];
+
+#[test]
+fn discovers_every_item() {
+    let items = discover(workspace_root()).unwrap();
+    let ids: Vec<_> = items.iter().map(|item| item.id.as_str()).collect();
+    assert_eq!(ids, ITEMS);
+    for item in &items {
+        assert_eq!(item.package, format!("item-{}", item.id.replace('.', "-")));
+        assert!(!item.compile_fail, "{} isn't expected to fail", item.id);
+    }
+}
+
+#[test]
+fn every_item_builds_and_runs() {
+    let outcomes: Vec<_> = discover(workspace_root())
+        .unwrap()
+        .iter()
+        .map(|item| check(item, &target_dir(), DEFAULT_TIMEOUT))
+        .collect();
+    let table = report(&outcomes);
+    println!("{table}");
+    assert!(
+        outcomes.iter().all(|outcome| outcome.result.is_ok()),
+        "some items failed:\n{table}"
+    );
+}
+
+fn fixtures() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/items"))
+}
+
+#[test]
+fn discovery_skips_directories_that_are_not_numbered() {
+    let items = discover(fixtures()).unwrap();
+    let ids: Vec<_> = items.iter().map(|item| item.id.as_str()).collect();
+    assert_eq!(ids, ["9.1", "9.2"]);
+    assert!(items[0].compile_fail);
+    assert!(!items[1].compile_fail);
+}
+
+#[test]
+fn compile_fail_items_pass_only_by_failing_to_compile() {
+    let outcomes: Vec<_> = discover(fixtures())
+        .unwrap()
+        .iter()
+        .map(|item| check(item, &target_dir(), DEFAULT_TIMEOUT))
+        .collect();
+    assert_eq!(outcomes[0].result, Ok(()));
+    let Err(Failure::Build { stderr }) = &outcomes[1].result else {
+        panic!("expected a build failure, got {:?}", outcomes[1].result);
+    };
+    assert!(stderr.contains("mismatched types"), "{stderr}");
+
+    let table = report(&outcomes);
+    let lines: Vec<_> = table.lines().take(3).collect();
+    assert_eq!(
+        lines,
+        [
+            "item  package   result",
+            "9.1   item-9-1  ok (fails to compile, as it should)",
+            "9.2   item-9-2  FAILED to build",
+        ]
+    );
+    assert!(table.contains("\n=== 9.2 (item-9-2)\nFAILED to build\n--- cargo stderr\n"));
+}
@@ -0,0 +1,4 @@
+fn main() {
+    let greeting: u32 = "hello";
+    println!("{greeting}");
+}
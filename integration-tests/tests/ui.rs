@@ -0,0 +1,10 @@
+// Compile-fail checks for claims made in the item commentary. The expected compiler output
+// lives next to each case in a `.stderr` file; when a toolchain update changes the wording,
+// regenerate them with `TRYBUILD=overwrite cargo test -p integration-tests --test ui` and
+// review the diff.
+
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
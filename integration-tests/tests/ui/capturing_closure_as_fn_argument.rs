@@ -0,0 +1,11 @@
+// Item 1.2: code that takes a bare `fn` can't be handed a capturing closure; it would need
+// to accept one of the `Fn*` traits instead.
+
+fn apply(op: fn(i32, i32) -> i32, x: i32, y: i32) -> i32 {
+    op(x, y)
+}
+
+fn main() {
+    let amount = 3;
+    let _ = apply(|x, y| x + y + amount, 1, 2);
+}
@@ -0,0 +1,12 @@
+// Item 1.2: a closure that captures its environment is not a function pointer.
+
+fn sum(x: i32, y: i32) -> i32 {
+    x + y
+}
+
+fn main() {
+    let fun: fn(i32, i32) -> i32 = sum;
+    let amount = 3;
+    let fun2: fn(i32, i32) -> i32 = |x, y| x + y + amount;
+    let _ = (fun, fun2);
+}
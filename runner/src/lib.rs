@@ -0,0 +1,213 @@
+// The registry of item examples, so that any of them can be run by number from one binary.
+//
+// Every item crate exposes the same entry point, `run() -> Result<(), Box<dyn Error>>`, and a
+// one-line `DESCRIPTION` taken from its manifest. Adding an item means adding it as a
+// dependency and listing it in `ITEMS`.
+//
+// Items can also be picked by pattern, with `--item 1.*` say, using the `glob` crate. The
+// option may be given more than once; an item is picked if any of the patterns match its id.
+
+use glob::GlobSet;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Write};
+
+pub struct Item {
+    /// The item's number in the book, such as `"1.2"`.
+    pub id: &'static str,
+    pub description: &'static str,
+    pub run: fn() -> Result<(), Box<dyn Error>>,
+}
+
+pub const ITEMS: &[Item] = &[
+    Item {
+        id: "1.1",
+        description: item_1_1::DESCRIPTION,
+        run: item_1_1::run,
+    },
+    Item {
+        id: "1.2",
+        description: item_1_2::DESCRIPTION,
+        run: item_1_2::run,
+    },
+    Item {
+        id: "1.3",
+        description: item_1_3::DESCRIPTION,
+        run: item_1_3::run,
+    },
+    Item {
+        id: "1.4",
+        description: item_1_4::DESCRIPTION,
+        run: item_1_4::run,
+    },
+    Item {
+        id: "1.8",
+        description: item_1_8::DESCRIPTION,
+        run: item_1_8::run,
+    },
+    Item {
+        id: "1.9",
+        description: item_1_9::DESCRIPTION,
+        run: item_1_9::run,
+    },
+    Item {
+        id: "2.1",
+        description: item_2_1::DESCRIPTION,
+        run: item_2_1::run,
+    },
+    Item {
+        id: "2.2",
+        description: item_2_2::DESCRIPTION,
+        run: item_2_2::run,
+    },
+    Item {
+        id: "2.3",
+        description: item_2_3::DESCRIPTION,
+        run: item_2_3::run,
+    },
+    Item {
+        id: "3.1",
+        description: item_3_1::DESCRIPTION,
+        run: item_3_1::run,
+    },
+    Item {
+        id: "3.4",
+        description: item_3_4::DESCRIPTION,
+        run: item_3_4::run,
+    },
+    Item {
+        id: "5.2",
+        description: item_5_2::DESCRIPTION,
+        run: item_5_2::run,
+    },
+    Item {
+        id: "5.4",
+        description: item_5_4::DESCRIPTION,
+        run: item_5_4::run,
+    },
+    Item {
+        id: "6.2",
+        description: item_6_2::DESCRIPTION,
+        run: item_6_2::run,
+    },
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownItem {
+    pub id: String,
+    pub known: Vec<&'static str>,
+}
+
+impl fmt::Display for UnknownItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unknown item {:?}; available items: {}",
+            self.id,
+            self.known.join(", ")
+        )
+    }
+}
+
+impl Error for UnknownItem {}
+
+pub fn find<'a>(items: &'a [Item], id: &str) -> Result<&'a Item, UnknownItem> {
+    items
+        .iter()
+        .find(|item| item.id == id)
+        .ok_or_else(|| UnknownItem {
+            id: id.to_owned(),
+            known: items.iter().map(|item| item.id).collect(),
+        })
+}
+
+/// The items whose ids match any pattern in `patterns`, in the order of `items`.
+pub fn select<'a>(items: &'a [Item], patterns: &GlobSet) -> Vec<&'a Item> {
+    items
+        .iter()
+        .filter(|item| patterns.matches_any(item.id))
+        .collect()
+}
+
+/// One line per item: its number and description.
+pub fn list<'a>(items: impl IntoIterator<Item = &'a Item>, out: &mut impl Write) -> io::Result<()> {
+    let items: Vec<&Item> = items.into_iter().collect();
+    let width = items.iter().map(|item| item.id.len()).max().unwrap_or(0);
+    for item in items {
+        writeln!(out, "{:width$}  {}", item.id, item.description)?;
+    }
+    Ok(())
+}
+
+const USAGE: &str = "usage: runner <ITEM> | --item <GLOB>... | --list [--item <GLOB>...]";
+
+/// The whole command line: run the item named in `args`, or those matching its `--item`
+/// patterns, or list them. Returns the exit code: 0 on success, 1 if an item's example failed,
+/// 2 for bad usage, an unknown item, or patterns that are malformed or match nothing.
+pub fn cli(items: &[Item], args: &[String], out: &mut impl Write, err: &mut impl Write) -> u8 {
+    let Some((patterns, args)) = split_item_patterns(args) else {
+        return writeln!(err, "{USAGE}").map_or(1, |()| 2);
+    };
+    let selected = if patterns.is_empty() {
+        None
+    } else {
+        match selection(items, &patterns) {
+            Ok(selected) => Some(selected),
+            Err(e) => return writeln!(err, "error: {e}").map_or(1, |()| 2),
+        }
+    };
+    let result = match (&args[..], selected) {
+        (["--list"], None) => list(items, out).map(|()| 0),
+        (["--list"], Some(selected)) => list(selected, out).map(|()| 0),
+        (["-h" | "--help"], None) => writeln!(out, "{USAGE}").map(|()| 0),
+        ([], Some(selected)) => run_all(&selected, err),
+        ([id], None) if !id.starts_with('-') => match find(items, id) {
+            Ok(item) => run_all(&[item], err),
+            Err(e) => writeln!(err, "error: {e}").map(|()| 2),
+        },
+        _ => writeln!(err, "{USAGE}").map(|()| 2),
+    };
+    // The output is gone (a closed pipe, say), so there is nobody left to tell.
+    result.unwrap_or(1)
+}
+
+// The items matching `patterns`, or why there aren't any.
+fn selection<'a>(items: &'a [Item], patterns: &[&str]) -> Result<Vec<&'a Item>, String> {
+    let set = GlobSet::new(patterns).map_err(|e| e.to_string())?;
+    let selected = select(items, &set);
+    if selected.is_empty() {
+        let known: Vec<&str> = items.iter().map(|item| item.id).collect();
+        return Err(format!(
+            "no item matches {}; available items: {}",
+            patterns.join(", "),
+            known.join(", ")
+        ));
+    }
+    Ok(selected)
+}
+
+// Run each item in turn, carrying on past failures, and return the exit code for the lot.
+fn run_all(items: &[&Item], err: &mut impl Write) -> io::Result<u8> {
+    let mut code = 0;
+    for item in items {
+        if let Err(e) = (item.run)() {
+            writeln!(err, "item {} failed: {e}", item.id)?;
+            code = 1;
+        }
+    }
+    Ok(code)
+}
+
+// Take every `--item <GLOB>` out of `args`, returning the patterns and the arguments left over,
+// or `None` if an `--item` has no pattern after it.
+fn split_item_patterns(args: &[String]) -> Option<(Vec<&str>, Vec<&str>)> {
+    let (mut patterns, mut rest) = (Vec::new(), Vec::new());
+    let mut args = args.iter().map(String::as_str);
+    while let Some(arg) = args.next() {
+        match arg {
+            "--item" => patterns.push(args.next()?),
+            _ => rest.push(arg),
+        }
+    }
+    Some((patterns, rest))
+}
@@ -0,0 +1,12 @@
+use runner::{cli, ITEMS};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    ExitCode::from(cli(
+        ITEMS,
+        &args,
+        &mut std::io::stdout(),
+        &mut std::io::stderr(),
+    ))
+}
@@ -0,0 +1,157 @@
+use glob::GlobSet;
+use runner::{cli, find, list, select, Item, ITEMS};
+use std::error::Error;
+
+fn ok() -> Result<(), Box<dyn Error>> {
+    Ok(())
+}
+
+fn fails() -> Result<(), Box<dyn Error>> {
+    Err("the example went wrong".into())
+}
+
+const FAKE: &[Item] = &[
+    Item {
+        id: "9.1",
+        description: "Always works",
+        run: ok,
+    },
+    Item {
+        id: "9.10",
+        description: "Never works",
+        run: fails,
+    },
+];
+
+// Run the CLI against `items`, returning the exit code, stdout and stderr.
+fn run(items: &[Item], args: &[&str]) -> (u8, String, String) {
+    let args: Vec<String> = args.iter().map(|&a| a.to_owned()).collect();
+    let (mut out, mut err) = (Vec::new(), Vec::new());
+    let code = cli(items, &args, &mut out, &mut err);
+    (
+        code,
+        String::from_utf8(out).unwrap(),
+        String::from_utf8(err).unwrap(),
+    )
+}
+
+#[test]
+fn every_item_is_registered_once_with_a_description() {
+    let ids: Vec<&str> = ITEMS.iter().map(|item| item.id).collect();
+    assert_eq!(
+        ids,
+        [
+            "1.1", "1.2", "1.3", "1.4", "1.8", "1.9", "2.1", "2.2", "2.3", "3.1", "3.4", "5.2",
+            "5.4", "6.2"
+        ]
+    );
+    for item in ITEMS {
+        assert!(
+            !item.description.is_empty(),
+            "{} has no description",
+            item.id
+        );
+    }
+}
+
+#[test]
+fn list_aligns_descriptions() {
+    let mut out = Vec::new();
+    list(FAKE, &mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "9.1   Always works\n9.10  Never works\n"
+    );
+}
+
+#[test]
+fn unknown_item_lists_the_known_ones() {
+    let err = find(FAKE, "9.2").err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "unknown item \"9.2\"; available items: 9.1, 9.10"
+    );
+    assert_eq!(
+        run(FAKE, &["9.2"]),
+        (
+            2,
+            String::new(),
+            "error: unknown item \"9.2\"; available items: 9.1, 9.10\n".to_owned()
+        )
+    );
+}
+
+#[test]
+fn exit_codes() {
+    assert_eq!(run(FAKE, &["9.1"]), (0, String::new(), String::new()));
+    assert_eq!(
+        run(FAKE, &["9.10"]),
+        (
+            1,
+            String::new(),
+            "item 9.10 failed: the example went wrong\n".to_owned()
+        )
+    );
+    assert_eq!(run(FAKE, &["--list"]).0, 0);
+    assert_eq!(run(FAKE, &[]).0, 2);
+    assert_eq!(run(FAKE, &["9.1", "9.10"]).0, 2);
+    assert_eq!(run(FAKE, &["--bogus"]).0, 2);
+}
+
+#[test]
+fn item_patterns_select_by_id() {
+    let set = GlobSet::new(["9.?"]).unwrap();
+    let ids: Vec<&str> = select(FAKE, &set).iter().map(|item| item.id).collect();
+    assert_eq!(ids, ["9.1"]);
+
+    assert_eq!(
+        run(FAKE, &["--list", "--item", "9.1?"]),
+        (0, "9.10  Never works\n".to_owned(), String::new())
+    );
+    assert_eq!(
+        run(FAKE, &["--item", "9.?", "--list", "--item", "*0"]),
+        (
+            0,
+            "9.1   Always works\n9.10  Never works\n".to_owned(),
+            String::new()
+        )
+    );
+    assert_eq!(
+        run(FAKE, &["--item", "9.?"]),
+        (0, String::new(), String::new())
+    );
+}
+
+#[test]
+fn item_patterns_run_every_match_and_report_failures() {
+    assert_eq!(
+        run(FAKE, &["--item", "9.*"]),
+        (
+            1,
+            String::new(),
+            "item 9.10 failed: the example went wrong\n".to_owned()
+        )
+    );
+}
+
+#[test]
+fn item_patterns_that_pick_nothing_are_errors() {
+    assert_eq!(
+        run(FAKE, &["--item", "7.*"]),
+        (
+            2,
+            String::new(),
+            "error: no item matches 7.*; available items: 9.1, 9.10\n".to_owned()
+        )
+    );
+    assert_eq!(
+        run(FAKE, &["--list", "--item", "[9"]),
+        (
+            2,
+            String::new(),
+            "error: invalid glob pattern \"[9\" at byte 0: unclosed character class\n".to_owned()
+        )
+    );
+    assert_eq!(run(FAKE, &["--item"]).0, 2);
+    assert_eq!(run(FAKE, &["9.1", "--item", "9.*"]).0, 2);
+}
@@ -0,0 +1,99 @@
+// A global allocator that counts allocations, for pinning down claims like "this doesn't
+// allocate" in tests.
+//
+// It is installed per test binary:
+//
+//     #[global_allocator]
+//     static ALLOCATOR: CountingAllocator = CountingAllocator;
+//
+// so only do that in a binary set aside for allocation tests, where nothing else cares which
+// allocator is in use. The counts are per thread, since the test harness runs tests in
+// parallel and one test's allocations mustn't show up in another's numbers.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+thread_local! {
+    // A `const` initialiser and no destructor, so using it can't allocate in turn.
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// The system allocator, counting every allocation and reallocation.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    /// How many allocations the current thread has made.
+    pub fn allocations() -> usize {
+        ALLOCATIONS.with(Cell::get)
+    }
+
+    /// Start counting afresh; the guard reports the allocations made since.
+    pub fn reset() -> AllocationCount {
+        AllocationCount {
+            start: CountingAllocator::allocations(),
+        }
+    }
+}
+
+fn count() {
+    // `try_with` because a thread that is shutting down may still free (and allocate) after
+    // its thread-locals are gone.
+    let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count();
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count();
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count();
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+/// Returned by [`CountingAllocator::reset`].
+#[derive(Debug)]
+pub struct AllocationCount {
+    start: usize,
+}
+
+impl AllocationCount {
+    pub fn allocations(&self) -> usize {
+        CountingAllocator::allocations() - self.start
+    }
+}
+
+/// Assert that `f` makes exactly `expected` allocations on this thread, naming `path` (the code
+/// being checked) if it doesn't. Whatever `f` returns is dropped outside the count.
+///
+/// Panics if [`CountingAllocator`] isn't the global allocator, rather than letting every claim
+/// pass with a count of zero.
+pub fn assert_allocations<R>(path: &str, expected: usize, f: impl FnOnce() -> R) {
+    let probe = CountingAllocator::reset();
+    drop(std::hint::black_box(Box::new(0u8)));
+    assert!(
+        probe.allocations() > 0,
+        "CountingAllocator is not the global allocator of this test binary"
+    );
+
+    let count = CountingAllocator::reset();
+    let result = std::hint::black_box(f());
+    let actual = count.allocations();
+    drop(result);
+    assert!(
+        actual == expected,
+        "{path}: expected {expected} allocation(s), got {actual}"
+    );
+}
@@ -0,0 +1,95 @@
+// Shared helpers for the randomized tests in the workspace.
+//
+// Randomized tests are only useful if a failure can be reproduced, so every source of
+// randomness here is an explicitly seeded generator. [`seeded`] picks the seed (from
+// `TEST_SEED` if set, otherwise a fixed value derived from the test's name) and prints it if
+// the test panics, so a failing run can be replayed with `TEST_SEED=<seed> cargo test <name>`.
+//
+// The modules hold fixtures for other kinds of test: counting allocations (`alloc`),
+// capturing log records (`logger`), checking encodings (`roundtrip`) and files to read from
+// (`temp_file`).
+
+pub mod alloc;
+#[cfg(feature = "logging")]
+pub mod logger;
+pub mod roundtrip;
+pub mod temp_file;
+
+use std::ops::Range;
+use std::panic::{self, AssertUnwindSafe};
+
+/// A source of pseudo-random numbers.
+pub trait Rng {
+    fn next_u64(&mut self) -> u64;
+
+    /// A value uniformly distributed in `range`, which must not be empty.
+    fn gen_range(&mut self, range: Range<u64>) -> u64 {
+        assert!(range.start < range.end, "empty range {range:?}");
+        let span = range.end - range.start;
+        // Reject the top sliver of values that would make some results more likely than others.
+        let zone = u64::MAX - (u64::MAX - span + 1) % span;
+        loop {
+            let x = self.next_u64();
+            if x <= zone {
+                return range.start + x % span;
+            }
+        }
+    }
+}
+
+/// The SplitMix64 generator: tiny, fast and statistically decent, which is all tests need.
+#[derive(Debug, Clone)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+}
+
+impl Rng for SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+/// The environment variable that overrides the seed of every [`seeded`] test.
+pub const SEED_VAR: &str = "TEST_SEED";
+
+/// The seed [`seeded`] would use for the test called `name`.
+pub fn seed_for(name: &str) -> u64 {
+    match std::env::var(SEED_VAR) {
+        Ok(seed) => parse_seed(&seed).unwrap_or_else(|| panic!("{SEED_VAR}={seed:?} is not a u64")),
+        // FNV-1a of the name, so each test gets its own stable default.
+        Err(_) => name.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+            (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+        }),
+    }
+}
+
+// Accepts decimal or `0x`-prefixed hex.
+fn parse_seed(s: &str) -> Option<u64> {
+    match s.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => s.parse().ok(),
+    }
+}
+
+/// Run `test` with a generator seeded for `name`, reporting the seed if it panics.
+pub fn seeded<T>(name: &str, test: impl FnOnce(&mut SplitMix64) -> T) -> T {
+    let seed = seed_for(name);
+    let mut rng = SplitMix64::new(seed);
+    match panic::catch_unwind(AssertUnwindSafe(|| test(&mut rng))) {
+        Ok(result) => result,
+        Err(payload) => {
+            eprintln!("{name} failed; replay with {SEED_VAR}=0x{seed:016x}");
+            panic::resume_unwind(payload)
+        }
+    }
+}
@@ -0,0 +1,72 @@
+// A `log::Log` implementation that keeps what it is given, so that tests can check what was
+// logged. Only the first call to [`init_test_logger`] installs it; the `log` facade takes one
+// logger per process, and every test binary gets its own process.
+//
+// The test harness runs tests on several threads at once, so each record remembers the thread
+// that logged it, and [`CaptureLogger::take`] only hands back the current thread's. A test that
+// logs from threads of its own won't see those records.
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::sync::{Mutex, OnceLock};
+use std::thread::{self, ThreadId};
+
+/// What was logged, copied out of a `log::Record`, which only borrows its parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapturedRecord {
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+}
+
+#[derive(Debug, Default)]
+pub struct CaptureLogger {
+    records: Mutex<Vec<(ThreadId, CapturedRecord)>>,
+}
+
+impl CaptureLogger {
+    /// Remove and return the records logged so far by the current thread, oldest first.
+    pub fn take(&self) -> Vec<CapturedRecord> {
+        let current = thread::current().id();
+        let mut records = self.records.lock().unwrap_or_else(|e| e.into_inner());
+        let (mine, others) = records.drain(..).partition(|(id, _)| *id == current);
+        *records = others;
+        mine.into_iter().map(|(_, record)| record).collect()
+    }
+}
+
+impl Log for CaptureLogger {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        let captured = CapturedRecord {
+            level: record.level(),
+            target: record.target().to_owned(),
+            message: record.args().to_string(),
+        };
+        self.records
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push((thread::current().id(), captured));
+    }
+
+    fn flush(&self) {}
+}
+
+/// The logger every record in this process goes to, installed on first use, at every level.
+///
+/// Panics if some other logger was installed first.
+pub fn init_test_logger() -> &'static CaptureLogger {
+    static LOGGER: OnceLock<CaptureLogger> = OnceLock::new();
+    let mut installed = false;
+    let logger = LOGGER.get_or_init(|| {
+        installed = true;
+        CaptureLogger::default()
+    });
+    if installed {
+        log::set_logger(logger).expect("another logger is already installed");
+        log::set_max_level(LevelFilter::Trace);
+    }
+    logger
+}
@@ -0,0 +1,121 @@
+// Property checks shared by every encoding in the workspace.
+//
+// A format registers itself by implementing `Codec` for the type it encodes, and
+// `check_roundtrip` then runs it against randomly generated values:
+//
+// - decoding an encoding gives back the original value;
+// - decoding any strict prefix of an encoding fails cleanly, for formats where that holds;
+// - bytes that decode at all re-encode to exactly the same bytes, for canonical formats. The
+//   bytes tried are valid encodings with one byte changed, which is where a sloppy decoder is
+//   most likely to accept something it shouldn't.
+//
+// All of it runs under `seeded`, so a failure prints the seed to replay it with.
+
+use crate::{seeded, Rng, SplitMix64};
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+
+/// How many values [`check_roundtrip`] generates.
+pub const CASES: usize = 256;
+
+/// A way of turning `T` into bytes and back.
+pub trait Codec<T> {
+    type Error: Debug;
+
+    fn encode(&self, value: &T) -> Vec<u8>;
+
+    /// Decode all of `bytes`; leftover input is an error.
+    fn decode(&self, bytes: &[u8]) -> Result<T, Self::Error>;
+
+    /// Whether every strict prefix of an encoding fails to decode. True of length-prefixed
+    /// formats, but not of ones like CSV, where a prefix can be a shorter valid document.
+    fn prefixes_are_errors(&self) -> bool {
+        true
+    }
+
+    /// Whether a value has only one encoding, so that any bytes that decode are exactly what
+    /// encoding the result gives back.
+    fn canonical(&self) -> bool {
+        true
+    }
+}
+
+/// Check the properties above for [`CASES`] values from `gen`, panicking with the seed, the
+/// property and the offending value on the first failure.
+pub fn check_roundtrip<T, C>(name: &str, codec: &C, mut gen: impl FnMut(&mut SplitMix64) -> T)
+where
+    T: PartialEq + Debug,
+    C: Codec<T>,
+{
+    seeded(name, |rng| {
+        for _ in 0..CASES {
+            let value = gen(rng);
+            let bytes = codec.encode(&value);
+            match decode(codec, &bytes) {
+                Decoded::Ok(decoded) if decoded == value => {}
+                Decoded::Ok(decoded) => panic!(
+                    "{name}: round trip changed the value\n  \
+                     original: {value:?}\n  decoded:  {decoded:?}\n  bytes: {bytes:02x?}"
+                ),
+                Decoded::Err(e) | Decoded::Panicked(e) => panic!(
+                    "{name}: couldn't decode an encoding\n  \
+                     value: {value:?}\n  error: {e}\n  bytes: {bytes:02x?}"
+                ),
+            }
+
+            for len in 0..bytes.len() {
+                let prefix = &bytes[..len];
+                match decode(codec, prefix) {
+                    Decoded::Ok(decoded) if codec.prefixes_are_errors() => panic!(
+                        "{name}: a {len}-byte prefix of the encoding decoded\n  \
+                         value: {value:?}\n  prefix decoded as: {decoded:?}\n  \
+                         prefix: {prefix:02x?}"
+                    ),
+                    Decoded::Panicked(e) => panic!(
+                        "{name}: decoding a {len}-byte prefix panicked: {e}\n  \
+                         value: {value:?}\n  prefix: {prefix:02x?}"
+                    ),
+                    _ => {}
+                }
+            }
+
+            if codec.canonical() && !bytes.is_empty() {
+                let mut mutated = bytes.clone();
+                let i = rng.gen_range(0..bytes.len() as u64) as usize;
+                mutated[i] ^= rng.gen_range(1..256) as u8;
+                match decode(codec, &mutated) {
+                    Decoded::Ok(decoded) if codec.encode(&decoded) != mutated => panic!(
+                        "{name}: bytes that decoded don't re-encode the same\n  \
+                         decoded: {decoded:?}\n  bytes:     {mutated:02x?}\n  \
+                         re-encoded: {:02x?}",
+                        codec.encode(&decoded)
+                    ),
+                    Decoded::Panicked(e) => panic!("{name}: decoding {mutated:02x?} panicked: {e}"),
+                    _ => {}
+                }
+            }
+        }
+    });
+}
+
+enum Decoded<T> {
+    Ok(T),
+    Err(String),
+    Panicked(String),
+}
+
+// Decode, catching panics so the caller can say which input caused them.
+fn decode<T, C: Codec<T>>(codec: &C, bytes: &[u8]) -> Decoded<T> {
+    match panic::catch_unwind(AssertUnwindSafe(|| codec.decode(bytes))) {
+        Ok(Ok(value)) => Decoded::Ok(value),
+        Ok(Err(e)) => Decoded::Err(format!("{e:?}")),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("<non-string panic>");
+            Decoded::Panicked(message.to_owned())
+        }
+    }
+}
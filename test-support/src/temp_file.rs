@@ -0,0 +1,41 @@
+// Files for tests of code that reads from a path.
+//
+// Each `TempFile` gets a name of its own in the temp directory, made from the process id and a
+// counter, so tests running in parallel, in one test binary or several, never share one. The
+// file is removed again when the `TempFile` is dropped, including when the test panics.
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A file holding the contents it was created with, deleted when dropped.
+#[derive(Debug)]
+pub struct TempFile(PathBuf);
+
+impl TempFile {
+    pub fn new(contents: &str) -> TempFile {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let n = COUNT.fetch_add(1, Ordering::Relaxed);
+        let name = format!("test-support-{}-{n}", std::process::id());
+        let path = std::env::temp_dir().join(name);
+        std::fs::write(&path, contents).unwrap();
+        TempFile(path)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+
+    /// Add `text` to the end of the file, as a process writing a log would.
+    pub fn append(&self, text: &str) {
+        let mut file = OpenOptions::new().append(true).open(&self.0).unwrap();
+        file.write_all(text.as_bytes()).unwrap();
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
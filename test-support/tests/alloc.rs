@@ -0,0 +1,55 @@
+// The allocator is only installed in this binary, so the other tests in the crate don't pay
+// for the counting.
+
+use std::panic;
+use test_support::alloc::{assert_allocations, CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn counts_a_deliberate_allocation() {
+    let count = CountingAllocator::reset();
+    let boxed = std::hint::black_box(Box::new(42u64));
+    assert_eq!(count.allocations(), 1);
+    drop(boxed);
+    // Freeing isn't an allocation.
+    assert_eq!(count.allocations(), 1);
+}
+
+#[test]
+fn counts_reallocations() {
+    let mut v: Vec<u8> = Vec::with_capacity(1);
+    let count = CountingAllocator::reset();
+    v.extend_from_slice(&[0; 1024]);
+    assert_eq!(count.allocations(), 1);
+}
+
+#[test]
+fn counts_are_per_thread() {
+    let count = CountingAllocator::reset();
+    std::thread::spawn(|| {
+        let v: Vec<String> = (0..100).map(|i| i.to_string()).collect();
+        assert!(CountingAllocator::allocations() >= 100);
+        v
+    })
+    .join()
+    .unwrap();
+    // Only the spawning itself, not the other thread's 100 strings.
+    assert!(count.allocations() < 100, "{}", count.allocations());
+}
+
+#[test]
+fn assert_allocations_names_the_offending_path() {
+    assert_allocations("nothing", 0, || 1 + 1);
+    assert_allocations("a box", 1, || Box::new(1));
+
+    let payload = panic::catch_unwind(|| {
+        assert_allocations("formatting a number", 0, || 12345.to_string());
+    })
+    .unwrap_err();
+    assert_eq!(
+        payload.downcast_ref::<String>().unwrap(),
+        "formatting a number: expected 0 allocation(s), got 1"
+    );
+}
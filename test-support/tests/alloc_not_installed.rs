@@ -0,0 +1,8 @@
+use test_support::alloc::assert_allocations;
+
+// Without `#[global_allocator]` nothing is counted, which must not look like success.
+#[test]
+#[should_panic(expected = "CountingAllocator is not the global allocator of this test binary")]
+fn refuses_to_run_without_the_allocator() {
+    assert_allocations("anything", 0, || ());
+}
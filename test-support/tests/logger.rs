@@ -0,0 +1,45 @@
+use log::Level;
+use test_support::logger::{init_test_logger, CapturedRecord};
+
+#[test]
+fn captures_records_in_order() {
+    let logger = init_test_logger();
+    log::warn!("first {}", 1);
+    log::debug!(target: "custom", "second");
+    assert_eq!(
+        logger.take(),
+        [
+            CapturedRecord {
+                level: Level::Warn,
+                target: "logger".to_owned(),
+                message: "first 1".to_owned(),
+            },
+            CapturedRecord {
+                level: Level::Debug,
+                target: "custom".to_owned(),
+                message: "second".to_owned(),
+            },
+        ]
+    );
+    // Taken records are gone.
+    assert!(logger.take().is_empty());
+}
+
+#[test]
+fn initializing_twice_is_fine() {
+    let logger = init_test_logger();
+    assert!(std::ptr::eq(logger, init_test_logger()));
+    log::trace!("kept");
+    assert_eq!(logger.take().len(), 1);
+}
+
+#[test]
+fn other_threads_records_are_kept_apart() {
+    let logger = init_test_logger();
+    std::thread::spawn(|| log::info!("elsewhere"))
+        .join()
+        .unwrap();
+    log::info!("here");
+    let messages: Vec<_> = logger.take().into_iter().map(|r| r.message).collect();
+    assert_eq!(messages, ["here"]);
+}
@@ -0,0 +1,121 @@
+use std::panic;
+use test_support::roundtrip::{check_roundtrip, Codec};
+use test_support::Rng;
+
+// A `u32` as four little-endian bytes, with a switch for each way of getting it wrong.
+#[derive(Default)]
+struct U32Codec {
+    // Drops the top byte, so large values don't survive.
+    lossy: bool,
+    // Pads short input with zeros instead of rejecting it.
+    accepts_prefixes: bool,
+    // Ignores the top bit of the last byte, so two encodings decode to the same value.
+    sloppy: bool,
+}
+
+impl Codec<u32> for U32Codec {
+    type Error = String;
+
+    fn encode(&self, value: &u32) -> Vec<u8> {
+        let mut bytes = value.to_le_bytes().to_vec();
+        if self.lossy {
+            bytes.pop();
+        }
+        bytes
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<u32, String> {
+        let len = if self.lossy { 3 } else { 4 };
+        if bytes.len() > len || (bytes.len() < len && !self.accepts_prefixes) {
+            return Err(format!("expected {len} bytes, got {}", bytes.len()));
+        }
+        let mut buf = [0; 4];
+        buf[..bytes.len()].copy_from_slice(bytes);
+        if self.sloppy {
+            buf[3] &= 0x7f;
+        }
+        Ok(u32::from_le_bytes(buf))
+    }
+}
+
+// Run `check` and return its panic message, which it must produce.
+fn failure(check: impl FnOnce() + panic::UnwindSafe) -> String {
+    let payload = panic::catch_unwind(check).expect_err("the broken codec was not caught");
+    payload
+        .downcast_ref::<String>()
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn small(rng: &mut impl Rng) -> u32 {
+    rng.gen_range(0..0x8000_0000) as u32
+}
+
+#[test]
+fn correct_codec_passes() {
+    check_roundtrip("correct_codec_passes", &U32Codec::default(), |rng| {
+        rng.next_u64() as u32
+    });
+}
+
+#[test]
+fn lossy_codec_is_caught() {
+    let codec = U32Codec {
+        lossy: true,
+        ..U32Codec::default()
+    };
+    let message =
+        failure(|| check_roundtrip("lossy_codec_is_caught", &codec, |rng| rng.next_u64() as u32));
+    assert!(
+        message.contains("round trip changed the value"),
+        "{message}"
+    );
+}
+
+#[test]
+fn codec_accepting_prefixes_is_caught() {
+    let codec = U32Codec {
+        accepts_prefixes: true,
+        ..U32Codec::default()
+    };
+    let message = failure(|| check_roundtrip("codec_accepting_prefixes_is_caught", &codec, small));
+    assert!(
+        message.contains("-byte prefix of the encoding decoded"),
+        "{message}"
+    );
+}
+
+#[test]
+fn non_canonical_codec_is_caught() {
+    let codec = U32Codec {
+        sloppy: true,
+        ..U32Codec::default()
+    };
+    // Values with the top bit clear round-trip fine; only a mutated encoding shows the problem.
+    let message = failure(|| check_roundtrip("non_canonical_codec_is_caught", &codec, small));
+    assert!(message.contains("don't re-encode the same"), "{message}");
+}
+
+#[test]
+fn panicking_decoder_is_reported_as_such() {
+    struct Panics;
+    impl Codec<u8> for Panics {
+        type Error = ();
+        fn encode(&self, value: &u8) -> Vec<u8> {
+            vec![*value, 0]
+        }
+        fn decode(&self, bytes: &[u8]) -> Result<u8, ()> {
+            // Indexes without checking the length.
+            if bytes.len() > 2 {
+                return Err(());
+            }
+            Ok(bytes[0] + bytes[1])
+        }
+    }
+    let message = failure(|| {
+        check_roundtrip("panicking_decoder_is_reported_as_such", &Panics, |rng| {
+            rng.gen_range(0..128) as u8
+        })
+    });
+    assert!(message.contains("prefix panicked"), "{message}");
+}
@@ -0,0 +1,25 @@
+use test_support::temp_file::TempFile;
+
+#[test]
+fn holds_its_contents_until_dropped() {
+    let file = TempFile::new("one\n");
+    let path = file.path().to_owned();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "one\n");
+    file.append("two\n");
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "one\ntwo\n");
+    drop(file);
+    assert!(!path.exists());
+}
+
+#[test]
+fn every_file_has_a_path_of_its_own() {
+    let files: Vec<_> = (0..3).map(|_| TempFile::new("")).collect();
+    assert_ne!(files[0].path(), files[1].path());
+    assert_ne!(files[1].path(), files[2].path());
+}
+
+#[test]
+fn a_removed_file_is_no_problem() {
+    let file = TempFile::new("gone");
+    std::fs::remove_file(file.path()).unwrap();
+}
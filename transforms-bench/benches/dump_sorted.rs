@@ -0,0 +1,36 @@
+// Item 1.2's generic `write_sorted` against `write_sorted_dyn`, which calls through a
+// `&mut dyn SortAndIter`, on a million unsorted numbers written to a sink. Both sort their
+// input, so each iteration gets a fresh copy, made outside the timing.
+// `cargo bench -p transforms-bench --bench dump_sorted` runs them.
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use item_1_2::{write_sorted, write_sorted_dyn};
+use std::hint::black_box;
+
+const LEN: u64 = 1_000_000;
+
+fn dump_sorted(c: &mut Criterion) {
+    let numbers: Vec<u64> = (0..LEN)
+        .map(|i| i.wrapping_mul(0x9e37_79b9_7f4a_7c15))
+        .collect();
+    let mut group = c.benchmark_group("dump_sorted");
+    group.sample_size(20);
+    group.bench_function("generic", |b| {
+        b.iter_batched(
+            || numbers.clone(),
+            |numbers| write_sorted(black_box(numbers), &mut std::io::sink()),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("dyn", |b| {
+        b.iter_batched(
+            || numbers.clone(),
+            |mut numbers| write_sorted_dyn(black_box(&mut numbers), &mut std::io::sink()),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, dump_sorted);
+criterion_main!(benches);
@@ -0,0 +1,41 @@
+// Item 1.2's `modify_all` against `par_modify_all`, which spreads the same update over rayon's
+// thread pool, on fifty million floats. Both update in place, so each iteration starts from a
+// fresh copy of the input, made outside the timing; at 400MB a copy, one is made at a time.
+// `cargo bench -p transforms-bench --bench modify_all` runs them.
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use item_1_2::{modify_all, par_modify_all};
+use std::hint::black_box;
+
+const LEN: u32 = 50_000_000;
+
+fn modify_all_floats(c: &mut Criterion) {
+    let floats: Vec<f64> = (0..LEN).map(f64::from).collect();
+    let mut group = c.benchmark_group("modify_all");
+    // The fewest criterion allows; each iteration is a pass over 400MB.
+    group.sample_size(10);
+    group.bench_function("sequential", |b| {
+        b.iter_batched(
+            || floats.clone(),
+            |mut floats| {
+                modify_all(black_box(&mut floats), |x| x.sqrt() + 1.0);
+                floats
+            },
+            BatchSize::PerIteration,
+        )
+    });
+    group.bench_function("parallel", |b| {
+        b.iter_batched(
+            || floats.clone(),
+            |mut floats| {
+                par_modify_all(black_box(&mut floats), |x| x.sqrt() + 1.0);
+                floats
+            },
+            BatchSize::PerIteration,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, modify_all_floats);
+criterion_main!(benches);
@@ -0,0 +1,21 @@
+// Item 2.3's enum `match` against calls through `Box<dyn Area>`, on the same ten million
+// mixed shapes. `cargo bench -p transforms-bench --bench total_area` runs them.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use item_2_3::dispatch::{boxed, mixed_shapes, total_area_dyn, total_area_enum, Area};
+use std::hint::black_box;
+
+const LEN: usize = 10_000_000;
+
+fn total_area(c: &mut Criterion) {
+    let shapes = mixed_shapes(LEN);
+    let boxes: Vec<Box<dyn Area>> = shapes.iter().map(boxed).collect();
+    let mut group = c.benchmark_group("total_area");
+    group.sample_size(20);
+    group.bench_function("enum", |b| b.iter(|| total_area_enum(black_box(&shapes))));
+    group.bench_function("dyn", |b| b.iter(|| total_area_dyn(black_box(&boxes))));
+    group.finish();
+}
+
+criterion_group!(benches, total_area);
+criterion_main!(benches);
@@ -0,0 +1,45 @@
+// Match against combinators, and combinators that can't be inlined, on ten million values.
+// `cargo bench -p transforms-bench` runs them all; add a filter, such as `option_sum`, to run
+// one group. The loops themselves are tested in Item 1.3's `tests/hot_loops.rs`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use item_1_3::hot_loops::{
+    pipeline_combinator, pipeline_inline_never, pipeline_match, sum_some_combinator,
+    sum_some_inline_never, sum_some_match,
+};
+use std::hint::black_box;
+use transforms_bench::{option_values, pipeline_numbers};
+
+const LEN: u64 = 10_000_000;
+
+fn option_sum(c: &mut Criterion) {
+    let values = option_values(LEN);
+    let mut group = c.benchmark_group("option_sum");
+    // Each call takes milliseconds, so fewer samples than the default hundred will do.
+    group.sample_size(20);
+    group.bench_function("match", |b| b.iter(|| sum_some_match(black_box(&values))));
+    group.bench_function("combinator", |b| {
+        b.iter(|| sum_some_combinator(black_box(&values)))
+    });
+    group.bench_function("inline_never", |b| {
+        b.iter(|| sum_some_inline_never(black_box(&values)))
+    });
+    group.finish();
+}
+
+fn result_pipeline(c: &mut Criterion) {
+    let numbers = pipeline_numbers(LEN);
+    let mut group = c.benchmark_group("result_pipeline");
+    group.sample_size(20);
+    group.bench_function("match", |b| b.iter(|| pipeline_match(black_box(&numbers))));
+    group.bench_function("combinator", |b| {
+        b.iter(|| pipeline_combinator(black_box(&numbers)))
+    });
+    group.bench_function("inline_never", |b| {
+        b.iter(|| pipeline_inline_never(black_box(&numbers)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, option_sum, result_pipeline);
+criterion_main!(benches);
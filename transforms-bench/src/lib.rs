@@ -0,0 +1,20 @@
+// Inputs for the benchmarks of Item 1.3's `hot_loops`, shared by the criterion benches here
+// (`cargo bench -p transforms-bench`) and bench-lite's `option_sum/` and `result_pipeline/`
+// groups, so that both time the same work.
+//
+// The other benches here are for comparisons that only criterion runs: `dump_sorted`, for
+// Item 1.2's generic and trait-object functions, `modify_all`, for its sequential and
+// parallel updates, and `total_area`, for Item 2.3's enum and trait-object dispatch.
+
+/// `n` values counting up from 0, with every seventh missing.
+pub fn option_values(n: u64) -> Vec<Option<u64>> {
+    (0..n).map(|i| (i % 7 != 0).then_some(i)).collect()
+}
+
+/// `n` numbers counting up from 0, except that every eighth is close enough to `u64::MAX` to
+/// overflow the pipeline's first stage.
+pub fn pipeline_numbers(n: u64) -> Vec<u64> {
+    (0..n)
+        .map(|i| if i % 8 == 0 { u64::MAX - i } else { i })
+        .collect()
+}